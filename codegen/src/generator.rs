@@ -0,0 +1,1227 @@
+//! Emits serde models from parsed CDDL rules.
+//!
+//! Every rule becomes an item in the module named after its prefix
+//! (`browsingContext.Navigate` goes to `browsing_context.rs`, unprefixed rules to
+//! `common.rs`):
+//!
+//! * maps and keyed groups become structs with a positional `new` constructor,
+//! * choices of text literals become enums with one renamed unit variant per literal,
+//! * other choices become enums with one variant per alternative,
+//! * everything else becomes a type alias.
+//!
+//! Fields with a single text literal value (`type: "string"`, `method: "session.new"`)
+//! are wire tags rather than data. When every struct of a choice carries such a tag
+//! under the same key, the enum is internally tagged on that key and the field is
+//! dropped from the structs. Structs that are also used on their own keep the tag at
+//! the struct level instead, and their variant is untagged.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::cddl::{Entry, EntryValue, Group, Key, Occurrence, Rule, RuleValue, Type, Type1};
+use crate::naming::{field_ident, module_name, pascal_case, type_name};
+use crate::overrides::Overrides;
+
+// --------------------------------------------------
+
+/// The generated source files, keyed by file name (e.g. `script.rs`, `mod.rs`).
+#[derive(Debug, Default)]
+pub struct Output {
+    pub files: BTreeMap<String, String>,
+    /// Names that are referenced but neither defined nor built in.
+    pub warnings: Vec<String>,
+}
+
+/// Generates the models for `rules`. `source` is the CDDL file name, mentioned in
+/// the header of every generated file.
+pub fn generate(rules: &[Rule], overrides: &Overrides, source: &str) -> Output {
+    let mut generator = Generator::new(rules, overrides);
+    generator.analyze();
+    for rule in rules {
+        generator.rule(rule);
+    }
+    generator.finish(source)
+}
+
+// --------------------------------------------------
+
+#[derive(Default)]
+struct Module {
+    items: Vec<String>,
+    common_imports: BTreeSet<String>,
+    module_imports: BTreeSet<String>,
+    names: HashSet<String>,
+}
+
+struct Field {
+    ident: String,
+    wire: String,
+    ty: String,
+    optional: bool,
+    flatten: bool,
+}
+
+struct Variant {
+    name: String,
+    rename: Option<String>,
+    payload: Option<String>,
+    untagged: bool,
+}
+
+enum Alt<'a> {
+    Ref(&'a str),
+    Text(&'a str),
+    Other(&'a Type1),
+}
+
+struct Generator<'a> {
+    rules: HashMap<&'a str, &'a RuleValue>,
+    overrides: &'a Overrides,
+    modules: BTreeMap<String, Module>,
+    // Rules referenced somewhere other than as an alternative of a choice.
+    standalone: HashSet<&'a str>,
+    // Rules whose tag field is consumed by an internally tagged enum, with the tag key.
+    enum_tagged: HashMap<&'a str, String>,
+    // The rules each rule contains directly, i.e. not through an array or a map.
+    reachable: HashMap<&'a str, HashSet<&'a str>>,
+    // The rule being generated.
+    current: Option<&'a str>,
+    warnings: BTreeSet<String>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(rules: &'a [Rule], overrides: &'a Overrides) -> Self {
+        let mut modules: BTreeMap<String, Module> = BTreeMap::new();
+        for rule in rules {
+            let module = modules.entry(module_name(&rule.name)).or_default();
+            module.names.insert(Self::rust_name(overrides, &rule.name));
+        }
+        Self {
+            rules: rules.iter().map(|r| (r.name.as_str(), &r.value)).collect(),
+            overrides,
+            modules,
+            standalone: HashSet::new(),
+            enum_tagged: HashMap::new(),
+            reachable: HashMap::new(),
+            current: None,
+            warnings: BTreeSet::new(),
+        }
+    }
+
+    fn rust_name(overrides: &Overrides, rule: &str) -> String {
+        if let Some(rename) = overrides.renames.get(rule) {
+            return rename.clone();
+        }
+        if let Some(path) = overrides.uses.get(rule) {
+            return path.rsplit("::").next().unwrap_or(path).to_string();
+        }
+        type_name(rule)
+    }
+
+    fn finish(self, source: &str) -> Output {
+        let mut files = BTreeMap::new();
+        for (name, module) in &self.modules {
+            if module.items.is_empty() {
+                continue;
+            }
+            let mut code = format!(
+                "//! Generated by `webdriverbidi-codegen` from `{}`. Do not edit by hand.\n\n\
+                 #![allow(clippy::all)]\n\nuse serde::{{Deserialize, Serialize}};\n",
+                source
+            );
+            if !module.common_imports.is_empty() {
+                let names: Vec<_> = module.common_imports.iter().cloned().collect();
+                code.push_str(&format!("use super::common::{{{}}};\n", names.join(", ")));
+            }
+            for import in &module.module_imports {
+                code.push_str(&format!("use super::{};\n", import));
+            }
+            for item in &module.items {
+                code.push('\n');
+                code.push_str(item);
+            }
+            files.insert(format!("{}.rs", name), code);
+        }
+
+        let mut mod_rs = format!(
+            "//! Generated by `webdriverbidi-codegen` from `{}`. Do not edit by hand.\n\n",
+            source
+        );
+        for name in files.keys() {
+            mod_rs.push_str(&format!("pub mod {};\n", name.trim_end_matches(".rs")));
+        }
+        if files.contains_key("common.rs") {
+            mod_rs.push_str("\npub use common::*;\n");
+        }
+        files.insert("mod.rs".to_string(), mod_rs);
+
+        Output {
+            files,
+            warnings: self.warnings.into_iter().collect(),
+        }
+    }
+
+    // --------------------------------------------------
+    // Analysis
+
+    fn analyze(&mut self) {
+        let rules: Vec<_> = self.rules.values().copied().collect();
+        for value in &rules {
+            self.mark_rule(value);
+        }
+        for value in &rules {
+            self.walk_rule_choices(value);
+        }
+
+        let edges: HashMap<&'a str, Vec<&'a str>> = self
+            .rules
+            .iter()
+            .map(|(name, value)| {
+                let mut refs = Vec::new();
+                match value {
+                    RuleValue::Type(ty) => direct_refs_type(ty, &mut refs),
+                    RuleValue::Group(group) => direct_refs_group(group, &mut refs),
+                }
+                (*name, refs)
+            })
+            .collect();
+        for name in self.rules.keys() {
+            let mut seen = HashSet::new();
+            let mut stack = edges.get(name).cloned().unwrap_or_default();
+            while let Some(next) = stack.pop() {
+                if seen.insert(next) {
+                    stack.extend(edges.get(next).into_iter().flatten());
+                }
+            }
+            self.reachable.insert(name, seen);
+        }
+    }
+
+    // Whether a field of type `target` in the current rule makes the type recursive,
+    // in which case it has to be boxed.
+    fn is_recursive(&self, target: &str) -> bool {
+        let Some(current) = self.current else {
+            return false;
+        };
+        let reaches = |from: &str, to: &str| {
+            self.reachable
+                .get(from)
+                .is_some_and(|reachable| reachable.contains(to))
+        };
+        reaches(current, target) && reaches(target, current)
+    }
+
+    fn mark_rule(&mut self, value: &'a RuleValue) {
+        match value {
+            RuleValue::Type(ty) => self.mark_type(ty),
+            RuleValue::Group(group) => self.mark_group(group),
+        }
+    }
+
+    fn mark_type(&mut self, ty: &'a Type) {
+        let alts = alternatives(ty).0;
+        let in_choice = alts.len() > 1;
+        for alt in alts {
+            match alt {
+                Alt::Ref(name) if !in_choice => {
+                    self.standalone.insert(name);
+                }
+                Alt::Other(t1) => self.mark_type1(t1),
+                _ => {}
+            }
+        }
+    }
+
+    fn mark_type1(&mut self, t1: &'a Type1) {
+        match t1 {
+            Type1::Map(group) => self.mark_group(group),
+            // Array elements are always used as types of their own.
+            Type1::Array(group) => {
+                for entry in group.0.iter().flatten() {
+                    match &entry.value {
+                        EntryValue::Type(ty) => self.mark_type(ty),
+                        EntryValue::Group(group) => self.mark_group(group),
+                    }
+                }
+            }
+            Type1::Paren(ty) => self.mark_type(ty),
+            Type1::Control { target, .. } => self.mark_type1(target),
+            _ => {}
+        }
+    }
+
+    fn mark_group(&mut self, group: &'a Group) {
+        let choice_of_refs = group.0.len() > 1;
+        for entries in &group.0 {
+            for entry in entries {
+                match (&entry.key, &entry.value) {
+                    // Bare references are either inlined or alternatives of a group choice.
+                    (None, EntryValue::Type(ty)) if bare_ref(ty).is_some() => {
+                        if !choice_of_refs {
+                            if let Some(name) = bare_ref(ty) {
+                                if self.is_choice(name) || self.is_splat(name) {
+                                    self.standalone.insert(name);
+                                }
+                            }
+                        }
+                    }
+                    (_, EntryValue::Type(ty)) => self.mark_type(ty),
+                    (_, EntryValue::Group(group)) => self.mark_group(group),
+                }
+            }
+        }
+    }
+
+    fn walk_rule_choices(&mut self, value: &'a RuleValue) {
+        match value {
+            RuleValue::Type(ty) => self.walk_type_choices(ty),
+            RuleValue::Group(group) => self.walk_group_choices(group),
+        }
+    }
+
+    fn walk_type_choices(&mut self, ty: &'a Type) {
+        let alts = alternatives(ty).0;
+        if alts.len() > 1 {
+            let refs: Vec<&str> = alts
+                .iter()
+                .filter_map(|alt| match alt {
+                    Alt::Ref(name) => Some(*name),
+                    _ => None,
+                })
+                .collect();
+            self.mark_tagged(&refs);
+        }
+        for alt in alts {
+            if let Alt::Other(t1) = alt {
+                self.walk_type1_choices(t1);
+            }
+        }
+    }
+
+    fn walk_type1_choices(&mut self, t1: &'a Type1) {
+        match t1 {
+            Type1::Map(group) | Type1::Array(group) => self.walk_group_choices(group),
+            Type1::Paren(ty) => self.walk_type_choices(ty),
+            Type1::Control { target, .. } => self.walk_type1_choices(target),
+            _ => {}
+        }
+    }
+
+    fn walk_group_choices(&mut self, group: &'a Group) {
+        if let Some(refs) = self.group_choice_refs(group) {
+            self.mark_tagged(&refs.1);
+        }
+        for entries in &group.0 {
+            for entry in entries {
+                match &entry.value {
+                    EntryValue::Type(ty) => self.walk_type_choices(ty),
+                    EntryValue::Group(group) => self.walk_group_choices(group),
+                }
+            }
+        }
+    }
+
+    fn mark_tagged(&mut self, refs: &[&'a str]) {
+        if let Some(key) = self.choice_tag(refs) {
+            for name in refs {
+                if self.consumes_tag(name, &key) {
+                    self.enum_tagged.insert(name, key.clone());
+                }
+            }
+        }
+    }
+
+    // The key an enum over `refs` is tagged on: the literal field shared by the most
+    // alternatives that are not used on their own.
+    fn choice_tag(&self, refs: &[&str]) -> Option<String> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for name in refs {
+            if self.standalone.contains(name) {
+                continue;
+            }
+            for (key, _) in self.literals(name) {
+                *counts.entry(key).or_default() += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count >= 2)
+            .max_by_key(|(_, count)| *count)
+            .map(|(key, _)| key)
+    }
+
+    fn consumes_tag(&self, name: &str, key: &str) -> bool {
+        !self.standalone.contains(name) && self.literals(name).iter().any(|(k, _)| k == key)
+    }
+
+    // Returns the common entries and the referenced alternatives of a group choice
+    // such as `(A // B)` or `{ common: text, A // B }`.
+    fn group_choice_refs(&self, group: &'a Group) -> Option<(&'a [Entry], Vec<&'a str>)> {
+        if group.0.len() < 2 {
+            return None;
+        }
+        let (first, rest) = group.0.split_first()?;
+        let (last, common) = first.split_last()?;
+        let mut refs = vec![bare_entry_ref(last)?];
+        for entries in rest {
+            match entries.as_slice() {
+                [entry] => refs.push(bare_entry_ref(entry)?),
+                _ => return None,
+            }
+        }
+        Some((common, refs))
+    }
+
+    // --------------------------------------------------
+    // Rule classification
+
+    fn struct_entries(&self, name: &str) -> Option<(&'a [Entry], Option<&'a str>)> {
+        match self.rules.get(name)? {
+            RuleValue::Group(group) => match group.0.as_slice() {
+                [entries] if !is_splat_entries(entries) => Some((entries, None)),
+                _ => None,
+            },
+            RuleValue::Type(ty) => match ty.single()? {
+                Type1::Map(Group(choices)) if choices.len() == 1 => Some((&choices[0], None)),
+                Type1::Control { target, op, arg } if op == "and" => {
+                    match (target.as_ref(), arg.as_ref()) {
+                        (Type1::Map(Group(choices)), Type1::Name(other)) if choices.len() == 1 => {
+                            Some((&choices[0], Some(other.as_str())))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+        }
+    }
+
+    fn is_choice(&self, name: &str) -> bool {
+        match self.rules.get(name) {
+            Some(RuleValue::Type(ty)) => {
+                alternatives(ty).0.len() > 1
+                    || matches!(ty.single(), Some(Type1::Map(Group(choices))) if choices.len() > 1)
+            }
+            Some(RuleValue::Group(group)) => group.0.len() > 1,
+            None => false,
+        }
+    }
+
+    fn is_splat(&self, name: &str) -> bool {
+        match self.rules.get(name) {
+            Some(RuleValue::Group(Group(choices))) => {
+                matches!(choices.as_slice(), [entries] if is_splat_entries(entries))
+            }
+            _ => false,
+        }
+    }
+
+    // The single-literal fields of a struct-like rule, including inlined groups.
+    fn literals(&self, name: &str) -> Vec<(String, String)> {
+        let mut literals = Vec::new();
+        let mut seen = HashSet::new();
+        self.collect_literals(name, &mut literals, &mut seen);
+        literals
+    }
+
+    fn collect_literals(
+        &self,
+        name: &str,
+        literals: &mut Vec<(String, String)>,
+        seen: &mut HashSet<String>,
+    ) {
+        if !seen.insert(name.to_string()) {
+            return;
+        }
+        let Some((entries, and)) = self.struct_entries(name) else {
+            return;
+        };
+        for entry in entries {
+            match (&entry.key, &entry.value) {
+                (Some(Key::Bare(key)), EntryValue::Type(ty)) => {
+                    if let Some(text) = literal(ty) {
+                        literals.push((key.clone(), text.to_string()));
+                    }
+                }
+                (None, EntryValue::Type(ty)) => {
+                    if let Some(other) = bare_ref(ty) {
+                        self.collect_literals(other, literals, seen);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(other) = and {
+            self.collect_literals(other, literals, seen);
+        }
+    }
+
+    // --------------------------------------------------
+    // Generation
+
+    fn rule(&mut self, rule: &'a Rule) {
+        let name = rule.name.as_str();
+        let module = module_name(name);
+        if self.overrides.skips.contains(name) {
+            return;
+        }
+        if let Some(path) = self.overrides.uses.get(name) {
+            let item = format!("pub use {};\n", path);
+            self.module(&module).items.push(item);
+            return;
+        }
+
+        let rust_name = Self::rust_name(self.overrides, name);
+        self.current = Some(name);
+        match &rule.value {
+            RuleValue::Group(group) => {
+                if let [entries] = group.0.as_slice() {
+                    if is_splat_entries(entries) {
+                        let ty = self.splat_type(&entries[0], &rust_name, &module);
+                        self.alias(&module, &rust_name, &ty);
+                        return;
+                    }
+                }
+                self.group_item(Some(name), &rust_name, &module, group, None);
+            }
+            RuleValue::Type(ty) => {
+                let (alts, nullable) = alternatives(ty);
+                if alts.len() > 1 {
+                    self.choice_item(&rust_name, &module, &alts);
+                    return;
+                }
+                match ty.single().map(|t1| (t1, t1.strip_controls())) {
+                    Some((_, Type1::Map(group))) if !is_splat_group(group) => {
+                        let and = and_ref(ty);
+                        self.group_item(Some(name), &rust_name, &module, group, and);
+                    }
+                    _ => {
+                        let resolved = self.resolve(ty, &format!("{}Item", rust_name), &module);
+                        let resolved = if nullable {
+                            format!("Option<{}>", resolved.0)
+                        } else {
+                            resolved.0
+                        };
+                        self.alias(&module, &rust_name, &resolved);
+                    }
+                }
+            }
+        }
+    }
+
+    fn module(&mut self, module: &str) -> &mut Module {
+        self.modules.entry(module.to_string()).or_default()
+    }
+
+    fn unique_name(&mut self, module: &str, base: &str) -> String {
+        let base = self
+            .overrides
+            .renames
+            .get(base)
+            .cloned()
+            .unwrap_or_else(|| base.to_string());
+        let names = &mut self.module(module).names;
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while names.contains(&name) {
+            name = format!("{}{}", base, suffix);
+            suffix += 1;
+        }
+        names.insert(name.clone());
+        name
+    }
+
+    fn reserve(&mut self, module: &str) -> usize {
+        let items = &mut self.module(module).items;
+        items.push(String::new());
+        items.len() - 1
+    }
+
+    fn alias(&mut self, module: &str, name: &str, ty: &str) {
+        let item = format!("pub type {} = {};\n", name, ty);
+        self.module(module).items.push(item);
+    }
+
+    // A struct for a single-choice group, or an enum for a group choice.
+    fn group_item(
+        &mut self,
+        rule: Option<&str>,
+        name: &str,
+        module: &str,
+        group: &'a Group,
+        and: Option<&'a str>,
+    ) {
+        if group.0.len() <= 1 {
+            let entries = group.0.first().map(Vec::as_slice).unwrap_or_default();
+            self.struct_item(rule, name, module, entries, and);
+            return;
+        }
+
+        if let Some((common, refs)) = self.group_choice_refs(group) {
+            let alts: Vec<Alt> = refs.iter().map(|r| Alt::Ref(r)).collect();
+            if common.is_empty() {
+                self.choice_item(name, module, &alts);
+                return;
+            }
+            // `{ common, A // B }`: the common fields plus a flattened choice of A and B.
+            let slot = self.reserve(module);
+            let mut fields = Vec::new();
+            let mut literals = Vec::new();
+            self.fields(name, module, common, &mut fields, &mut literals);
+            let enum_name = self.unique_name(module, &or_name(&refs));
+            self.choice_item(&enum_name, module, &alts);
+            fields.push(Field {
+                ident: field_ident(&enum_name),
+                wire: String::new(),
+                ty: enum_name,
+                optional: false,
+                flatten: true,
+            });
+            let item = self.render_struct(rule, name, literals, fields);
+            self.module(module).items[slot] = item;
+            return;
+        }
+
+        // Any other group choice: one struct per choice.
+        let slot = self.reserve(module);
+        let mut variants = Vec::new();
+        for (index, entries) in group.0.iter().enumerate() {
+            let variant_name = self.unique_name(module, &format!("{}{}", name, index + 1));
+            self.struct_item(None, &variant_name, module, entries, None);
+            variants.push(Variant {
+                name: variant_name.clone(),
+                rename: None,
+                payload: Some(variant_name),
+                untagged: false,
+            });
+        }
+        let item = render_enum(name, Some("#[serde(untagged)]".to_string()), &variants);
+        self.module(module).items[slot] = item;
+    }
+
+    fn struct_item(
+        &mut self,
+        rule: Option<&str>,
+        name: &str,
+        module: &str,
+        entries: &'a [Entry],
+        and: Option<&'a str>,
+    ) {
+        let slot = self.reserve(module);
+        let mut fields = Vec::new();
+        let mut literals = Vec::new();
+        self.fields(name, module, entries, &mut fields, &mut literals);
+        if let Some(other) = and {
+            self.inline(name, module, other, &mut fields, &mut literals);
+        }
+        let item = self.render_struct(rule, name, literals, fields);
+        self.module(module).items[slot] = item;
+    }
+
+    fn fields(
+        &mut self,
+        struct_name: &str,
+        module: &str,
+        entries: &'a [Entry],
+        fields: &mut Vec<Field>,
+        literals: &mut Vec<(String, String)>,
+    ) {
+        for entry in entries {
+            match (&entry.key, &entry.value) {
+                (Some(Key::Bare(key)), EntryValue::Type(ty)) => {
+                    if let Some(text) = literal(ty) {
+                        literals.push((key.clone(), text.to_string()));
+                        continue;
+                    }
+                    let hint = format!("{}{}", struct_name, pascal_case(key));
+                    let recursive = bare_ref(ty).is_some_and(|target| self.is_recursive(target));
+                    let (ty, nullable) = self.resolve(ty, &hint, module);
+                    let ty = if recursive {
+                        format!("Box<{}>", ty)
+                    } else {
+                        ty
+                    };
+                    let optional = entry.occurrence != Occurrence::One;
+                    let ty = if optional || nullable {
+                        format!("Option<{}>", ty)
+                    } else {
+                        ty
+                    };
+                    push_field(
+                        fields,
+                        Field {
+                            ident: field_ident(key),
+                            wire: key.clone(),
+                            ty,
+                            optional,
+                            flatten: false,
+                        },
+                    );
+                }
+                (Some(Key::Type(_)), EntryValue::Type(_)) => {
+                    let ty = self.splat_type(entry, struct_name, module);
+                    push_field(
+                        fields,
+                        Field {
+                            ident: "extensible".to_string(),
+                            wire: String::new(),
+                            ty,
+                            optional: false,
+                            flatten: true,
+                        },
+                    );
+                }
+                (None, EntryValue::Type(ty)) => match bare_ref(ty) {
+                    Some(other) => self.inline(struct_name, module, other, fields, literals),
+                    None => {
+                        let hint = format!("{}Value", struct_name);
+                        let (ty, _) = self.resolve(ty, &hint, module);
+                        push_field(
+                            fields,
+                            Field {
+                                ident: field_ident(&ty),
+                                wire: String::new(),
+                                ty,
+                                optional: false,
+                                flatten: true,
+                            },
+                        );
+                    }
+                },
+                (None, EntryValue::Group(group)) => {
+                    if let [entries] = group.0.as_slice() {
+                        self.fields(struct_name, module, entries, fields, literals);
+                    } else {
+                        let name = self.unique_name(module, &format!("{}Choice", struct_name));
+                        self.group_item(None, &name, module, group, None);
+                        push_field(
+                            fields,
+                            Field {
+                                ident: field_ident(&name),
+                                wire: String::new(),
+                                ty: name,
+                                optional: false,
+                                flatten: true,
+                            },
+                        );
+                    }
+                }
+                (Some(_), EntryValue::Group(_)) => {}
+            }
+        }
+    }
+
+    // Adds the fields of the rule `other` referenced inside a map: struct-like rules
+    // are inlined, anything else becomes a flattened field.
+    fn inline(
+        &mut self,
+        struct_name: &str,
+        module: &str,
+        other: &'a str,
+        fields: &mut Vec<Field>,
+        literals: &mut Vec<(String, String)>,
+    ) {
+        if let Some((entries, and)) = self.struct_entries(other) {
+            self.fields(struct_name, module, entries, fields, literals);
+            if let Some(and) = and {
+                self.inline(struct_name, module, and, fields, literals);
+            }
+            return;
+        }
+        let ty = self.type_ref(other, module);
+        push_field(
+            fields,
+            Field {
+                ident: field_ident(&Self::rust_name(self.overrides, other)),
+                wire: String::new(),
+                ty,
+                optional: false,
+                flatten: true,
+            },
+        );
+    }
+
+    fn render_struct(
+        &mut self,
+        rule: Option<&str>,
+        name: &str,
+        literals: Vec<(String, String)>,
+        mut fields: Vec<Field>,
+    ) -> String {
+        let consumed = rule.and_then(|rule| self.enum_tagged.get(rule)).cloned();
+        let mut struct_tag = None;
+        for (key, value) in literals {
+            if consumed.as_deref() == Some(key.as_str()) {
+                continue;
+            }
+            if struct_tag.is_none() {
+                struct_tag = Some((key, value));
+            } else {
+                // A second wire tag is kept as plain data.
+                fields.insert(
+                    0,
+                    Field {
+                        ident: field_ident(&key),
+                        wire: key,
+                        ty: "String".to_string(),
+                        optional: false,
+                        flatten: false,
+                    },
+                );
+            }
+        }
+
+        let mut out = String::from("#[derive(Debug, Serialize, Deserialize)]\n");
+        if let Some((key, value)) = &struct_tag {
+            out.push_str(&format!(
+                "#[serde(tag = \"{}\", rename = \"{}\")]\n",
+                key, value
+            ));
+        }
+        out.push_str(&format!("pub struct {} {{\n", name));
+        for field in &fields {
+            let mut attrs = Vec::new();
+            if field.flatten {
+                attrs.push("flatten".to_string());
+            } else if field.ident.trim_start_matches("r#") != field.wire {
+                attrs.push(format!("rename = \"{}\"", field.wire));
+            }
+            if field.optional {
+                attrs.push("skip_serializing_if = \"Option::is_none\"".to_string());
+            }
+            if !attrs.is_empty() {
+                out.push_str(&format!("    #[serde({})]\n", attrs.join(", ")));
+            }
+            out.push_str(&format!("    pub {}: {},\n", field.ident, field.ty));
+        }
+        out.push_str("}\n");
+
+        if self.overrides.constructors {
+            let params: Vec<String> = fields
+                .iter()
+                .map(|f| format!("{}: {}", f.ident, f.ty))
+                .collect();
+            let idents: Vec<&str> = fields.iter().map(|f| f.ident.as_str()).collect();
+            out.push_str(&format!(
+                "\nimpl {} {{\n    pub fn new({}) -> Self {{\n        Self {{ {} }}\n    }}\n}}\n",
+                name,
+                params.join(", "),
+                idents.join(", ")
+            ));
+        }
+        out
+    }
+
+    fn choice_item(&mut self, name: &str, module: &str, alts: &[Alt<'a>]) {
+        let slot = self.reserve(module);
+
+        let refs: Vec<&str> = alts
+            .iter()
+            .filter_map(|alt| match alt {
+                Alt::Ref(name) => Some(*name),
+                _ => None,
+            })
+            .collect();
+        let tag = self.choice_tag(&refs);
+        let has_text = alts.iter().any(|alt| matches!(alt, Alt::Text(_)));
+        let all_text = alts.iter().all(|alt| matches!(alt, Alt::Text(_)));
+
+        let mut variants = Vec::new();
+        for (index, alt) in alts.iter().enumerate() {
+            let variant = match alt {
+                Alt::Text(text) => Variant {
+                    name: pascal_case(text),
+                    rename: Some(text.to_string()),
+                    payload: None,
+                    untagged: false,
+                },
+                Alt::Ref(other) => {
+                    let rename = tag.as_ref().and_then(|key| {
+                        if !self.consumes_tag(other, key) {
+                            return None;
+                        }
+                        self.literals(other)
+                            .into_iter()
+                            .find(|(k, _)| k == key)
+                            .map(|(_, value)| value)
+                    });
+                    Variant {
+                        name: Self::rust_name(self.overrides, other),
+                        untagged: (tag.is_some() || has_text) && rename.is_none(),
+                        rename,
+                        payload: Some(self.type_ref(other, module)),
+                    }
+                }
+                Alt::Other(t1) => {
+                    let hint = format!("{}{}", name, index + 1);
+                    let payload = self.resolve_type1(t1, &hint, module);
+                    Variant {
+                        name: variant_name(&payload),
+                        rename: None,
+                        payload: Some(payload),
+                        untagged: tag.is_some() || has_text,
+                    }
+                }
+            };
+            variants.push(variant);
+        }
+        // serde requires untagged variants to come last.
+        variants.sort_by_key(|variant| variant.untagged);
+        dedup_variant_names(&mut variants);
+
+        let attr = match (&tag, all_text, has_text) {
+            (Some(key), _, _) => Some(format!("#[serde(tag = \"{}\")]", key)),
+            (None, true, _) | (None, false, true) => None,
+            (None, false, false) => Some("#[serde(untagged)]".to_string()),
+        };
+        let item = render_enum(name, attr, &variants);
+        self.module(module).items[slot] = item;
+    }
+
+    // --------------------------------------------------
+    // Types
+
+    fn splat_type(&mut self, entry: &'a Entry, hint: &str, module: &str) -> String {
+        let value = match &entry.value {
+            EntryValue::Type(ty) => self.resolve(ty, &format!("{}Value", hint), module).0,
+            EntryValue::Group(_) => "serde_json::Value".to_string(),
+        };
+        format!("std::collections::HashMap<String, {}>", value)
+    }
+
+    fn type_ref(&mut self, name: &str, module: &str) -> String {
+        if let Some(prim) = primitive(name) {
+            return prim.to_string();
+        }
+        if !self.rules.contains_key(name) {
+            self.warnings.insert(format!("undefined name `{}`", name));
+            return "serde_json::Value".to_string();
+        }
+        let rust_name = Self::rust_name(self.overrides, name);
+        let target = module_name(name);
+        if target == module {
+            rust_name
+        } else if target == "common" {
+            self.module(module).common_imports.insert(rust_name.clone());
+            rust_name
+        } else {
+            self.module(module).module_imports.insert(target.clone());
+            format!("{}::{}", target, rust_name)
+        }
+    }
+
+    // Returns the Rust type of `ty` and whether `null` is one of its alternatives.
+    // Anonymous maps and choices are generated as items named `hint`.
+    fn resolve(&mut self, ty: &'a Type, hint: &str, module: &str) -> (String, bool) {
+        let (alts, nullable) = alternatives(ty);
+        let resolved = match alts.as_slice() {
+            [] => "()".to_string(),
+            [Alt::Ref(name)] => self.type_ref(name, module),
+            [Alt::Text(_)] => "String".to_string(),
+            [Alt::Other(t1)] => self.resolve_type1(t1, hint, module),
+            alts => {
+                let name = self.unique_name(module, hint);
+                self.choice_item(&name, module, alts);
+                name
+            }
+        };
+        (resolved, nullable)
+    }
+
+    fn resolve_type1(&mut self, t1: &'a Type1, hint: &str, module: &str) -> String {
+        match t1 {
+            Type1::Name(name) => self.type_ref(name, module),
+            Type1::Text(_) => "String".to_string(),
+            Type1::Number(number) if number.contains('.') => "f64".to_string(),
+            Type1::Number(_) => "i64".to_string(),
+            Type1::Range { lower, upper, .. } => range_type(lower, upper).to_string(),
+            Type1::Paren(ty) => {
+                let (ty, nullable) = self.resolve(ty, hint, module);
+                if nullable {
+                    format!("Option<{}>", ty)
+                } else {
+                    ty
+                }
+            }
+            Type1::Map(group) if is_splat_group(group) => {
+                self.splat_type(&group.0[0][0], hint, module)
+            }
+            Type1::Map(group) => {
+                let name = self.unique_name(module, hint);
+                self.group_item(None, &name, module, group, None);
+                name
+            }
+            Type1::Array(group) => self.array_type(group, hint, module),
+            Type1::Control {
+                target, op, arg, ..
+            } => match (target.as_ref(), op.as_str(), arg.as_ref()) {
+                (Type1::Map(group), "and", Type1::Name(other)) => {
+                    let name = self.unique_name(module, hint);
+                    self.group_item(None, &name, module, group, Some(other.as_str()));
+                    name
+                }
+                (target, _, _) => self.resolve_type1(target, hint, module),
+            },
+        }
+    }
+
+    fn array_type(&mut self, group: &'a Group, hint: &str, module: &str) -> String {
+        let entries = match group.0.as_slice() {
+            [entries] => entries.as_slice(),
+            _ => return "Vec<serde_json::Value>".to_string(),
+        };
+        let element = |generator: &mut Self, entry: &'a Entry, hint: &str| match &entry.value {
+            EntryValue::Type(ty) => {
+                let (ty, nullable) = generator.resolve(ty, hint, module);
+                if nullable {
+                    format!("Option<{}>", ty)
+                } else {
+                    ty
+                }
+            }
+            EntryValue::Group(_) => "serde_json::Value".to_string(),
+        };
+        match entries {
+            [entry] if entry.occurrence != Occurrence::One => {
+                format!("Vec<{}>", element(self, entry, hint))
+            }
+            [entry] => format!("({},)", element(self, entry, hint)),
+            entries => {
+                let suffixes: Vec<String> = if entries.len() == 2 {
+                    vec!["Key".to_string(), "Value".to_string()]
+                } else {
+                    (1..=entries.len()).map(|i| i.to_string()).collect()
+                };
+                let items: Vec<String> = entries
+                    .iter()
+                    .zip(suffixes)
+                    .map(|(entry, suffix)| element(self, entry, &format!("{}{}", hint, suffix)))
+                    .collect();
+                format!("({})", items.join(", "))
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+
+fn render_enum(name: &str, attr: Option<String>, variants: &[Variant]) -> String {
+    let mut out = String::from("#[derive(Debug, Serialize, Deserialize)]\n");
+    if let Some(attr) = attr {
+        out.push_str(&attr);
+        out.push('\n');
+    }
+    out.push_str(&format!("pub enum {} {{\n", name));
+    for variant in variants {
+        if variant.untagged {
+            out.push_str("    #[serde(untagged)]\n");
+        } else if let Some(rename) = &variant.rename {
+            out.push_str(&format!("    #[serde(rename = \"{}\")]\n", rename));
+        }
+        match &variant.payload {
+            Some(payload) => out.push_str(&format!("    {}({}),\n", variant.name, payload)),
+            None => out.push_str(&format!("    {},\n", variant.name)),
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn push_field(fields: &mut Vec<Field>, field: Field) {
+    if !fields.iter().any(|f| f.ident == field.ident) {
+        fields.push(field);
+    }
+}
+
+fn dedup_variant_names(variants: &mut [Variant]) {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for variant in variants.iter_mut() {
+        let count = seen.entry(variant.name.clone()).or_default();
+        *count += 1;
+        if *count > 1 {
+            variant.name = format!("{}{}", variant.name, count);
+        }
+    }
+}
+
+// `Vec<script::LocalValue>` -> `LocalValueList`, `JsUint` -> `JsUint`.
+fn variant_name(ty: &str) -> String {
+    if let Some(inner) = ty.strip_prefix("Vec<").and_then(|t| t.strip_suffix('>')) {
+        return format!("{}List", variant_name(inner));
+    }
+    let last = ty.rsplit("::").next().unwrap_or(ty);
+    match last {
+        "String" => "Text".to_string(),
+        "bool" => "Bool".to_string(),
+        "f64" => "Float".to_string(),
+        "i64" => "Int".to_string(),
+        "u64" => "Uint".to_string(),
+        "Value" => "Any".to_string(),
+        other => pascal_case(other),
+    }
+}
+
+// `ClientWindowNamedState` + `ClientWindowRectState` -> `ClientWindowNamedOrRectState`.
+fn or_name(refs: &[&str]) -> String {
+    let names: Vec<String> = refs.iter().map(|r| type_name(r)).collect();
+    let words: Vec<Vec<String>> = names.iter().map(|n| split_words(n)).collect();
+    let shortest = words.iter().map(Vec::len).min().unwrap_or(0);
+    let same = |index: &dyn Fn(&Vec<String>) -> usize| {
+        words
+            .iter()
+            .all(|w| w[index(w)] == words[0][index(&words[0])])
+    };
+    let mut prefix = 0;
+    while prefix < shortest && same(&|_| prefix) {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while prefix + suffix < shortest && same(&|w| w.len() - 1 - suffix) {
+        suffix += 1;
+    }
+    let middles: Vec<String> = words
+        .iter()
+        .map(|w| w[prefix..w.len() - suffix].concat())
+        .collect();
+    if middles.iter().any(String::is_empty) {
+        return names.join("Or");
+    }
+    format!(
+        "{}{}{}",
+        words[0][..prefix].concat(),
+        middles.join("Or"),
+        words[0][words[0].len() - suffix..].concat()
+    )
+}
+
+// `ClientWindowNamedState` -> `["Client", "Window", "Named", "State"]`.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    for c in name.chars() {
+        match words.last_mut() {
+            Some(word) if !c.is_ascii_uppercase() => word.push(c),
+            _ => words.push(c.to_string()),
+        }
+    }
+    words
+}
+
+fn primitive(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "text" | "tstr" => "String",
+        "bool" | "true" | "false" => "bool",
+        "float" | "float16" | "float32" | "float64" | "number" => "f64",
+        "int" | "nint" => "i64",
+        "uint" => "u64",
+        "any" => "serde_json::Value",
+        _ => return None,
+    })
+}
+
+fn range_type(lower: &str, upper: &str) -> &'static str {
+    if lower.contains('.') || upper.contains('.') {
+        "f64"
+    } else if lower.starts_with('-') {
+        "i64"
+    } else {
+        "u64"
+    }
+}
+
+// Splits a type choice into its alternatives, flattening parenthesized choices and
+// removing `null`, which is reported separately.
+fn alternatives(ty: &Type) -> (Vec<Alt<'_>>, bool) {
+    let mut alts = Vec::new();
+    let mut nullable = false;
+    for t1 in &ty.0 {
+        match t1.strip_controls() {
+            Type1::Name(name) if name == "null" || name == "nil" => nullable = true,
+            Type1::Name(name) => alts.push(Alt::Ref(name)),
+            Type1::Text(text) => alts.push(Alt::Text(text)),
+            Type1::Paren(inner) => {
+                let (inner_alts, inner_nullable) = alternatives(inner);
+                alts.extend(inner_alts);
+                nullable |= inner_nullable;
+            }
+            _ => alts.push(Alt::Other(t1)),
+        }
+    }
+    (alts, nullable)
+}
+
+fn direct_refs_type<'a>(ty: &'a Type, refs: &mut Vec<&'a str>) {
+    for t1 in &ty.0 {
+        direct_refs_type1(t1, refs);
+    }
+}
+
+fn direct_refs_type1<'a>(t1: &'a Type1, refs: &mut Vec<&'a str>) {
+    match t1 {
+        Type1::Name(name) => refs.push(name),
+        Type1::Map(group) if !is_splat_group(group) => direct_refs_group(group, refs),
+        Type1::Paren(ty) => direct_refs_type(ty, refs),
+        Type1::Control {
+            target, op, arg, ..
+        } => {
+            direct_refs_type1(target, refs);
+            if op == "and" {
+                direct_refs_type1(arg, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn direct_refs_group<'a>(group: &'a Group, refs: &mut Vec<&'a str>) {
+    for entry in group.0.iter().flatten() {
+        match (&entry.key, &entry.value) {
+            (Some(Key::Type(_)), _) => {}
+            (_, EntryValue::Type(ty)) => direct_refs_type(ty, refs),
+            (_, EntryValue::Group(group)) => direct_refs_group(group, refs),
+        }
+    }
+}
+
+fn literal(ty: &Type) -> Option<&str> {
+    match ty.single()?.strip_controls() {
+        Type1::Text(text) => Some(text),
+        _ => None,
+    }
+}
+
+fn bare_ref(ty: &Type) -> Option<&str> {
+    match ty.single()? {
+        Type1::Name(name) if primitive(name).is_none() => Some(name),
+        _ => None,
+    }
+}
+
+fn bare_entry_ref(entry: &Entry) -> Option<&str> {
+    match (&entry.key, &entry.value, entry.occurrence) {
+        (None, EntryValue::Type(ty), Occurrence::One) => bare_ref(ty),
+        _ => None,
+    }
+}
+
+fn and_ref(ty: &Type) -> Option<&str> {
+    match ty.single()? {
+        Type1::Control { op, arg, .. } if op == "and" => match arg.as_ref() {
+            Type1::Name(name) => Some(name),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_splat_entries(entries: &[Entry]) -> bool {
+    matches!(
+        entries,
+        [Entry {
+            key: Some(Key::Type(_)),
+            ..
+        }]
+    )
+}
+
+fn is_splat_group(group: &Group) -> bool {
+    matches!(group.0.as_slice(), [entries] if is_splat_entries(entries))
+}
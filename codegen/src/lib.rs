@@ -0,0 +1,18 @@
+//! Generates reference models from the CDDL definitions of the WebDriver BiDi
+//! specification, to check the hand-written `webdriverbidi` models against.
+//!
+//! The bundled `cddl/remote.cddl` and `cddl/local.cddl` files are parsed by
+//! [`webdriverbidi::cddl`] into [`cddl::Rule`]s, which [`generator::generate`]
+//! turns into one Rust module per CDDL prefix. [`overrides::Overrides`] re-export
+//! the ID newtypes and name anonymous types like their hand-written counterparts.
+//!
+//! None of the output is compiled into `webdriverbidi`. [`shape::compare`] checks
+//! that the hand-written models still have the JSON keys and string values of the
+//! generated ones, and [`shape::missing`] and [`shape::methods`] that they define
+//! every generated type and method. CI runs both as part of the codegen tests.
+
+pub use webdriverbidi::cddl;
+pub mod generator;
+mod naming;
+pub mod overrides;
+pub mod shape;
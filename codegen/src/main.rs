@@ -0,0 +1,85 @@
+//! Command-line entry point.
+//!
+//! ```text
+//! webdriverbidi-codegen <input.cddl> <output-dir> [--overrides <file>]
+//! ```
+//!
+//! Writes one `.rs` file per CDDL module plus a `mod.rs` into `<output-dir>`,
+//! then formats them with `rustfmt` when it is available.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use webdriverbidi_codegen::overrides::Overrides;
+use webdriverbidi_codegen::{cddl, generator};
+
+const USAGE: &str = "usage: webdriverbidi-codegen <input.cddl> <output-dir> [--overrides <file>]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let mut positional = Vec::new();
+    let mut overrides_path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--overrides" => overrides_path = Some(args.next().ok_or(USAGE)?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let [input, output] = <[String; 2]>::try_from(positional).map_err(|_| USAGE)?;
+
+    let source = fs::read_to_string(&input).map_err(|e| format!("{}: {}", input, e))?;
+    let rules = cddl::parse(&source).map_err(|e| format!("{}: {}", input, e))?;
+    let overrides = match &overrides_path {
+        Some(path) => {
+            let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+            Overrides::parse(&text).map_err(|e| format!("{}: {}", path, e))?
+        }
+        None => Overrides::new(),
+    };
+
+    let file_name = Path::new(&input)
+        .file_name()
+        .map_or(input.clone(), |name| name.to_string_lossy().into_owned());
+    let generated = generator::generate(&rules, &overrides, &file_name);
+    for warning in &generated.warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    let output = PathBuf::from(output);
+    fs::create_dir_all(&output)?;
+    let mut paths = Vec::new();
+    for (name, code) in &generated.files {
+        let path = output.join(name);
+        fs::write(&path, code)?;
+        paths.push(path);
+    }
+
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .args(&paths)
+        .status()
+        .is_ok_and(|status| status.success());
+    if !formatted {
+        eprintln!("warning: rustfmt failed, the output is left unformatted");
+    }
+
+    println!(
+        "Generated {} files from {} rules into {}",
+        paths.len(),
+        rules.len(),
+        output.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//! Conversions from CDDL names to Rust identifiers.
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "const", "continue", "crate", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self",
+    "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+/// `browsingContext.navigate` -> `BrowsingContextNavigate`, `-Infinity` -> `NegativeInfinity`.
+pub fn pascal_case(name: &str) -> String {
+    if let Some(rest) = name.strip_prefix('-') {
+        return format!("Negative{}", pascal_case(rest));
+    }
+    let mut out = String::new();
+    for word in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let word = match word {
+            "0" => "Zero",
+            word => word,
+        };
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            out.push(first.to_ascii_uppercase());
+            out.extend(chars);
+        }
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, 'V');
+    }
+    out
+}
+
+/// `clientWindow` -> `client_window`, `XPathLocator` -> `x_path_locator`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !out.ends_with('_') {
+                out.push('_');
+            }
+            continue;
+        }
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            let boundary = prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_is_lower);
+            if boundary && !out.ends_with('_') {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out.trim_matches('_').to_string()
+}
+
+/// Returns `name` as a valid Rust identifier, escaping keywords.
+pub fn field_ident(name: &str) -> String {
+    let ident = snake_case(name);
+    match ident.as_str() {
+        "self" | "super" | "crate" => format!("{}_", ident),
+        kw if KEYWORDS.contains(&kw) => format!("r#{}", ident),
+        _ => ident,
+    }
+}
+
+/// The module a rule belongs to: `browsingContext.Navigate` -> `browsing_context`.
+pub fn module_name(rule: &str) -> String {
+    match rule.split_once('.') {
+        Some((module, _)) => snake_case(module),
+        None => "common".to_string(),
+    }
+}
+
+/// The Rust type name of a rule: `browsingContext.Navigate` -> `Navigate`.
+pub fn type_name(rule: &str) -> String {
+    let name = rule.split_once('.').map_or(rule, |(_, name)| name);
+    pascal_case(name)
+}
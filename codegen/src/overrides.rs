@@ -0,0 +1,82 @@
+//! Hand-maintained adjustments applied on top of the generated models.
+//!
+//! Overrides are read from a line-based file. Blank lines and lines starting
+//! with `#` are ignored. Every other line is one directive:
+//!
+//! ```text
+//! # Give a generated type a different Rust name. Anonymous types, which are named
+//! # after their parent and field (e.g. `PrintParametersOrientation`), are renamed by
+//! # their generated name.
+//! rename browser.ClientWindowInfo ClientWindowInformation
+//! # Re-export an existing type instead of generating one.
+//! use browsingContext.BrowsingContext crate::models::ids::BrowsingContext
+//! # Do not generate a type; it is written by hand next to the generated code.
+//! skip script.RemoteValue
+//! # Do not generate `new` constructors (e.g. for types that are only deserialized).
+//! no-constructors
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+#[derive(Debug, Default, Clone)]
+pub struct Overrides {
+    pub renames: HashMap<String, String>,
+    pub uses: HashMap<String, String>,
+    pub skips: HashSet<String>,
+    pub constructors: bool,
+}
+
+#[derive(Debug)]
+pub struct OverridesError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for OverridesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for OverridesError {}
+
+impl Overrides {
+    pub fn new() -> Self {
+        Self {
+            constructors: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, OverridesError> {
+        let mut overrides = Self::new();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| OverridesError {
+                line: index + 1,
+                message,
+            };
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                ["rename", name, rust_name] => {
+                    overrides
+                        .renames
+                        .insert(name.to_string(), rust_name.to_string());
+                }
+                ["use", name, path] => {
+                    overrides.uses.insert(name.to_string(), path.to_string());
+                }
+                ["skip", name] => {
+                    overrides.skips.insert(name.to_string());
+                }
+                ["no-constructors"] => overrides.constructors = false,
+                _ => return Err(error(format!("invalid directive `{}`", line))),
+            }
+        }
+        Ok(overrides)
+    }
+}
@@ -0,0 +1,528 @@
+//! Compares the wire shape of generated and hand-written models.
+//!
+//! The shape of a struct is the set of its JSON keys, the shape of an enum of unit
+//! variants is the set of its string values. Other enums, flattened fields and
+//! `#[serde(untagged)]` fallbacks are not compared: their layout is a design choice of
+//! the hand-written models rather than something the CDDL dictates.
+//!
+//! Sources are scanned textually rather than parsed, which is enough for the
+//! `#[derive(Serialize, Deserialize)]` items of both the generator and the models but
+//! not for arbitrary Rust: items produced by macros, for instance, are not seen.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::naming::snake_case;
+
+// --------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    /// The JSON keys of a struct.
+    Struct(BTreeSet<String>),
+    /// The string values of an enum of unit variants.
+    Enum(BTreeSet<String>),
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, names) = match self {
+            Shape::Struct(keys) => ("keys", keys),
+            Shape::Enum(values) => ("values", values),
+        };
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        write!(f, "{} [{}]", kind, names.join(", "))
+    }
+}
+
+/// A type whose shape differs between the generated and the hand-written models.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// `module::Type`, e.g. `script::ChannelProperties`.
+    pub name: String,
+    pub generated: Shape,
+    pub models: Shape,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: generated {}, models {}",
+            self.name, self.generated, self.models
+        )
+    }
+}
+
+/// Returns the shapes of the serde items defined in `modules`, which maps module
+/// names (e.g. `script`) to their source. Shapes are keyed by `module::Type`.
+pub fn shapes(modules: &BTreeMap<String, String>) -> BTreeMap<String, Shape> {
+    let mut structs = BTreeMap::new();
+    let mut shapes = BTreeMap::new();
+    // The tag key of the structs wrapped by internally tagged enums.
+    let mut enum_tags = BTreeMap::new();
+    for (module, source) in modules {
+        for item in serde_items(source) {
+            let Some(body) = &item.body else {
+                continue;
+            };
+            let name = format!("{}::{}", module, item.name);
+            let rename_all = attr_value(&item.attrs, "rename_all");
+            let tag = attr_value(&item.attrs, "tag");
+            if item.kind == "struct" {
+                let mut fields = struct_item(module, body, rename_all.as_deref());
+                // A struct-level tag is a key like any other on the wire.
+                fields.keys.extend(tag);
+                structs.insert(name, fields);
+            } else if let Some(values) = unit_values(body, rename_all.as_deref()) {
+                shapes.insert(name, Shape::Enum(values));
+            } else if let Some(tag) = tag {
+                for payload in tagged_payloads(module, body) {
+                    enum_tags.insert(payload, tag.clone());
+                }
+            }
+        }
+    }
+
+    for name in structs.keys() {
+        let mut keys = struct_keys(&structs, name, 0);
+        keys.extend(enum_tags.get(name).cloned());
+        shapes.insert(name.clone(), Shape::Struct(keys));
+    }
+    shapes
+}
+
+/// Returns the names of all the serde items defined in `modules`, including tuple
+/// structs and enums with data, as `module::Type`.
+pub fn types(modules: &BTreeMap<String, String>) -> BTreeSet<String> {
+    modules
+        .iter()
+        .flat_map(|(module, source)| {
+            serde_items(source)
+                .into_iter()
+                .map(move |item| format!("{}::{}", module, item.name))
+        })
+        .collect()
+}
+
+/// Returns the command and event methods defined in `modules`, i.e. the variant names
+/// of the enums tagged by `method`.
+pub fn methods(modules: &BTreeMap<String, String>) -> BTreeSet<String> {
+    let mut methods = BTreeSet::new();
+    for item in modules.values().flat_map(|source| serde_items(source)) {
+        let Some(body) = item.body.filter(|_| item.kind == "enum") else {
+            continue;
+        };
+        if attr_value(&item.attrs, "tag").as_deref() != Some("method") {
+            continue;
+        }
+        for member in members(&body) {
+            let (attrs, _) = split_attributes(member);
+            methods.extend(attr_value(&attrs, "rename"));
+        }
+    }
+    methods
+}
+
+/// Compares the types defined in both `generated` and `models`, which map module
+/// names (e.g. `script`) to their source.
+pub fn compare(
+    generated: &BTreeMap<String, String>,
+    models: &BTreeMap<String, String>,
+) -> Vec<Mismatch> {
+    let models = shapes(models);
+    shapes(generated)
+        .into_iter()
+        .filter_map(|(name, generated)| {
+            let models = models.get(&name).filter(|models| **models != generated)?;
+            Some(Mismatch {
+                name,
+                generated,
+                models: models.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the types of `generated` that `models` do not define, which both map module
+/// names to their source. Types of the generator's `common` module may be defined in
+/// any module of the models.
+pub fn missing(
+    generated: &BTreeMap<String, String>,
+    models: &BTreeMap<String, String>,
+) -> Vec<String> {
+    let models = types(models);
+    let names: BTreeSet<&str> = models
+        .iter()
+        .filter_map(|name| name.rsplit("::").next())
+        .collect();
+    types(generated)
+        .into_iter()
+        .filter(|name| match name.strip_prefix("common::") {
+            Some(name) => !names.contains(name),
+            None => !models.contains(name),
+        })
+        .collect()
+}
+
+// --------------------------------------------------
+
+struct Item {
+    name: String,
+    kind: &'static str,
+    attrs: String,
+    // The text between the braces, `None` for tuple and unit structs.
+    body: Option<String>,
+}
+
+// The items of `source` deriving `Serialize` or `Deserialize`.
+fn serde_items(source: &str) -> Vec<Item> {
+    let source = strip_comments(source);
+    let mut items = Vec::new();
+    let mut rest = source.as_str();
+    while let Some((before, kind, after)) = next_item(rest) {
+        let attrs = attributes(before).join(" ");
+        let (name, body) = match item_body(after) {
+            Some((name, body, remaining)) => {
+                rest = remaining;
+                (name, Some(body.to_string()))
+            }
+            None => {
+                rest = after;
+                let name_end = after
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                (after[..name_end].to_string(), None)
+            }
+        };
+        if attrs.contains("Serialize") || attrs.contains("Deserialize") {
+            items.push(Item {
+                name,
+                kind,
+                attrs,
+                body,
+            });
+        }
+    }
+    items
+}
+
+// Removes line comments, including trailing ones. Doc comments may contain anything,
+// code only has `//` inside string literals.
+fn strip_comments(source: &str) -> String {
+    let mut out = String::new();
+    for line in source.lines() {
+        let mut in_string = false;
+        let mut escaped = false;
+        let mut end = line.len();
+        let mut chars = line.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if in_string {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_string = false,
+                    _ => {}
+                }
+            } else if c == '"' {
+                in_string = true;
+            } else if c == '/' && chars.peek().is_some_and(|(_, next)| *next == '/') {
+                end = i;
+                break;
+            }
+        }
+        out.push_str(&line[..end]);
+        out.push('\n');
+    }
+    out
+}
+
+// Finds the next `struct` or `enum` keyword and splits `source` around it.
+fn next_item(source: &str) -> Option<(&str, &'static str, &str)> {
+    let mut offset = 0;
+    while offset < source.len() {
+        let rest = &source[offset..];
+        let found = ["struct ", "enum "]
+            .iter()
+            .filter_map(|kw| rest.find(kw).map(|i| (i, *kw)))
+            .min_by_key(|(i, _)| *i)?;
+        let start = offset + found.0;
+        let at_word_start = source[..start]
+            .chars()
+            .last()
+            .is_none_or(|c| c.is_whitespace());
+        if at_word_start {
+            let kind = if found.1 == "struct " {
+                "struct"
+            } else {
+                "enum"
+            };
+            return Some((&source[..start], kind, &source[start + found.1.len()..]));
+        }
+        offset = start + found.1.len();
+    }
+    None
+}
+
+// The attributes directly preceding an item, i.e. after the previous `}` or `;`.
+fn attributes(before: &str) -> Vec<String> {
+    let start = before.rfind(['}', ';']).map_or(0, |i| i + 1);
+    let mut attrs = Vec::new();
+    let mut rest = &before[start..];
+    while let Some(i) = rest.find("#[") {
+        let Some(len) = balanced(&rest[i + 1..], '[', ']') else {
+            break;
+        };
+        attrs.push(rest[i + 2..i + len].to_string());
+        rest = &rest[i + 1 + len..];
+    }
+    attrs
+}
+
+// Returns the name, the body between braces, and the text after a braced item. Tuple
+// structs and unit structs have no shape and yield `None`.
+fn item_body(after: &str) -> Option<(String, &str, &str)> {
+    let name_end = after.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    let name = after[..name_end].to_string();
+    let rest = &after[name_end..];
+    let open = rest.find(['{', ';', '('])?;
+    if &rest[open..open + 1] != "{" {
+        return None;
+    }
+    let len = balanced(&rest[open..], '{', '}')?;
+    Some((name, &rest[open + 1..open + len - 1], &rest[open + len..]))
+}
+
+// The length of the bracketed text at the start of `text`, brackets included.
+fn balanced(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            c if c == open => depth += 1,
+            c if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// Splits a body into its comma-separated members, ignoring nested commas.
+fn members(body: &str) -> Vec<&str> {
+    let mut members = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in body.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                members.push(&body[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    members.push(&body[start..]);
+    members
+        .into_iter()
+        .filter(|m| !m.trim().is_empty())
+        .collect()
+}
+
+// Separates the leading attributes of a member from its declaration.
+fn split_attributes(member: &str) -> (String, &str) {
+    let mut attrs = String::new();
+    let mut rest = member.trim_start();
+    while rest.starts_with("#[") {
+        let Some(len) = balanced(&rest[1..], '[', ']') else {
+            break;
+        };
+        attrs.push_str(&rest[2..len]);
+        attrs.push(' ');
+        rest = rest[1 + len..].trim_start();
+    }
+    (attrs, rest)
+}
+
+// The string value of `key = "..."` in `attrs`.
+fn attr_value(attrs: &str, key: &str) -> Option<String> {
+    let mut rest = attrs;
+    while let Some(i) = rest.find(key) {
+        let before_ok = rest[..i]
+            .chars()
+            .last()
+            .is_none_or(|c| !(c.is_alphanumeric() || c == '_'));
+        let after = rest[i + key.len()..].trim_start();
+        if before_ok {
+            if let Some(value) = after.strip_prefix('=') {
+                let value = value.trim_start().strip_prefix('"')?;
+                return value.find('"').map(|end| value[..end].to_string());
+            }
+        }
+        rest = &rest[i + key.len()..];
+    }
+    None
+}
+
+// Whether `attrs` contain the bare word `flag`, outside of string literals.
+fn has_flag(attrs: &str, flag: &str) -> bool {
+    attrs
+        .split('"')
+        .step_by(2)
+        .flat_map(|code| code.split(|c: char| !(c.is_alphanumeric() || c == '_')))
+        .any(|word| word == flag)
+}
+
+fn apply_rename_all(name: &str, rule: Option<&str>) -> String {
+    let snake = snake_case(name);
+    match rule {
+        Some("lowercase") => name.to_lowercase(),
+        Some("UPPERCASE") => name.to_uppercase(),
+        Some("snake_case") => snake,
+        Some("kebab-case") => snake.replace('_', "-"),
+        Some("camelCase") => {
+            let mut out = String::new();
+            for (i, word) in snake.split('_').enumerate() {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    if i == 0 {
+                        out.push(first);
+                    } else {
+                        out.push(first.to_ascii_uppercase());
+                    }
+                    out.extend(chars);
+                }
+            }
+            out
+        }
+        _ => name.to_string(),
+    }
+}
+
+struct StructItem {
+    keys: BTreeSet<String>,
+    // The types of the `#[serde(flatten)]` fields.
+    flattened: Vec<String>,
+}
+
+fn struct_item(module: &str, body: &str, rename_all: Option<&str>) -> StructItem {
+    let mut item = StructItem {
+        keys: BTreeSet::new(),
+        flattened: Vec::new(),
+    };
+    for member in members(body) {
+        let (attrs, decl) = split_attributes(member);
+        if has_flag(&attrs, "skip") {
+            continue;
+        }
+        let decl = decl.strip_prefix("pub(crate)").unwrap_or(decl).trim_start();
+        let decl = decl.strip_prefix("pub").unwrap_or(decl).trim_start();
+        let Some((ident, ty)) = decl.split_once(':') else {
+            continue;
+        };
+        if has_flag(&attrs, "flatten") {
+            item.flattened.push(type_path(module, ty));
+            continue;
+        }
+        let ident = ident.trim().trim_start_matches("r#");
+        let key =
+            attr_value(&attrs, "rename").unwrap_or_else(|| apply_rename_all(ident, rename_all));
+        item.keys.insert(key);
+    }
+    item
+}
+
+// The keys of a struct, including those of its flattened fields. Flattened types
+// defined elsewhere (e.g. `Extensible`) contribute nothing.
+fn struct_keys(
+    structs: &BTreeMap<String, StructItem>,
+    name: &str,
+    depth: usize,
+) -> BTreeSet<String> {
+    let Some(item) = structs.get(name).filter(|_| depth < 8) else {
+        return BTreeSet::new();
+    };
+    let mut keys = item.keys.clone();
+    for flattened in &item.flattened {
+        keys.extend(struct_keys(structs, flattened, depth + 1));
+    }
+    keys
+}
+
+// `Option<super::script::BaseRealmInfo>` -> `script::BaseRealmInfo`, `Base` in
+// `module` -> `module::Base`.
+fn type_path(module: &str, ty: &str) -> String {
+    let ty = ty.trim();
+    let ty = ty
+        .strip_prefix("Option<")
+        .or_else(|| ty.strip_prefix("Box<"))
+        .map_or(ty, |inner| inner.trim_end_matches('>'))
+        .trim();
+    let mut segments = ty.rsplit("::");
+    let name = segments.next().unwrap_or(ty);
+    let module = segments.next().unwrap_or(module);
+    format!("{}::{}", module, name)
+}
+
+// The values of an enum of unit variants, `None` for enums with data.
+fn unit_values(body: &str, rename_all: Option<&str>) -> Option<BTreeSet<String>> {
+    let mut values = BTreeSet::new();
+    for member in members(body) {
+        let (attrs, decl) = split_attributes(member);
+        if has_flag(&attrs, "untagged") || has_flag(&attrs, "other") {
+            continue;
+        }
+        let decl = decl.trim();
+        if !decl.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        let value =
+            attr_value(&attrs, "rename").unwrap_or_else(|| apply_rename_all(decl, rename_all));
+        values.insert(value);
+    }
+    Some(values)
+}
+
+// The payload types of the tagged newtype variants of an enum.
+fn tagged_payloads(module: &str, body: &str) -> Vec<String> {
+    members(body)
+        .into_iter()
+        .filter_map(|member| {
+            let (attrs, decl) = split_attributes(member);
+            if has_flag(&attrs, "untagged") {
+                return None;
+            }
+            let (_, payload) = decl.split_once('(')?;
+            Some(type_path(module, payload.trim_end().trim_end_matches(')')))
+        })
+        .collect()
+}
@@ -0,0 +1,322 @@
+use webdriverbidi_codegen::cddl::{self, RuleValue};
+use webdriverbidi_codegen::generator::{self, Output};
+use webdriverbidi_codegen::overrides::Overrides;
+
+const REMOTE_CDDL: &str = include_str!("../../cddl/remote.cddl");
+const LOCAL_CDDL: &str = include_str!("../../cddl/local.cddl");
+const REMOTE_OVERRIDES: &str = include_str!("../overrides/remote.txt");
+const LOCAL_OVERRIDES: &str = include_str!("../overrides/local.txt");
+
+fn generate(cddl: &str, overrides: &str, source: &str) -> Output {
+    let rules = cddl::parse(cddl).unwrap();
+    let overrides = Overrides::parse(overrides).unwrap();
+    generator::generate(&rules, &overrides, source)
+}
+
+fn file<'a>(output: &'a Output, name: &str) -> &'a str {
+    output
+        .files
+        .get(name)
+        .unwrap_or_else(|| panic!("{} was not generated", name))
+}
+
+mod parse {
+    use super::*;
+
+    #[test]
+    fn test_bundled_cddl() {
+        for source in [REMOTE_CDDL, LOCAL_CDDL] {
+            let rules = cddl::parse(source).unwrap();
+            assert!(rules.len() > 150);
+        }
+    }
+
+    #[test]
+    fn test_group_and_type_rules() {
+        let rules = cddl::parse(
+            r#"
+            a.Command = (method: "a.command", params: a.Params)
+            a.Params = { ? flag: bool .default false, Extensible }
+            a.Mode = "on" / "off"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.len(), 3);
+        assert!(matches!(rules[0].value, RuleValue::Group(_)));
+        assert!(matches!(rules[1].value, RuleValue::Type(_)));
+        assert!(matches!(rules[2].value, RuleValue::Type(ref ty) if ty.0.len() == 2));
+    }
+
+    #[test]
+    fn test_error_line() {
+        let err = cddl::parse("a = text\nb = {\n").unwrap_err();
+        assert_eq!(err.line, 3);
+    }
+}
+
+mod generate {
+    use super::*;
+
+    #[test]
+    fn test_no_undefined_names() {
+        let remote = generate(REMOTE_CDDL, REMOTE_OVERRIDES, "remote.cddl");
+        let local = generate(LOCAL_CDDL, LOCAL_OVERRIDES, "local.cddl");
+        assert_eq!(remote.warnings, Vec::<String>::new());
+        assert_eq!(local.warnings, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_internally_tagged_choice() {
+        let output = generate(REMOTE_CDDL, REMOTE_OVERRIDES, "remote.cddl");
+        let code = file(&output, "browsing_context.rs");
+
+        assert!(code.contains(
+            "#[serde(tag = \"type\")]\npub enum Locator {\n    #[serde(rename = \"accessibility\")]"
+        ));
+        assert!(code.contains("pub struct CssLocator {\n    pub value: String,\n}"));
+    }
+
+    #[test]
+    fn test_standalone_struct_keeps_its_tag() {
+        let output = generate(REMOTE_CDDL, REMOTE_OVERRIDES, "remote.cddl");
+        let code = file(&output, "script.rs");
+
+        assert!(code
+            .contains("#[serde(tag = \"type\", rename = \"channel\")]\npub struct ChannelValue"));
+        assert!(code.contains("    #[serde(untagged)]\n    ChannelValue(ChannelValue),"));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let output = generate(REMOTE_CDDL, REMOTE_OVERRIDES, "remote.cddl");
+
+        assert!(file(&output, "browsing_context.rs")
+            .contains("pub use crate::models::ids::BrowsingContext;"));
+        assert!(file(&output, "browser.rs").contains("pub enum ClientWindowState {"));
+
+        let local = generate(LOCAL_CDDL, LOCAL_OVERRIDES, "local.cddl");
+        assert!(!file(&local, "browser.rs").contains("pub fn new("));
+    }
+
+    #[test]
+    fn test_recursive_fields_are_boxed() {
+        let output = generate(REMOTE_CDDL, REMOTE_OVERRIDES, "remote.cddl");
+        assert!(file(&output, "script.rs").contains("Option<Box<NodeProperties>>"));
+    }
+}
+
+mod models {
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::fs;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use webdriverbidi::events::EventType;
+    use webdriverbidi_codegen::shape;
+
+    use super::*;
+
+    // Generated types (`module::Type`) and methods deliberately left out of the models.
+    const OMITTED: &[&str] = &[];
+
+    // The hand-written models of `src/models/<dir>`, keyed by module name.
+    fn models(dir: &str) -> BTreeMap<String, String> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../src/models")
+            .join(dir);
+        fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .map(|path| {
+                let module = path.file_stem().unwrap().to_string_lossy().into_owned();
+                (module, fs::read_to_string(&path).unwrap())
+            })
+            .collect()
+    }
+
+    // The generated modules, keyed by module name.
+    fn generated(output: Output) -> BTreeMap<String, String> {
+        output
+            .files
+            .into_iter()
+            .map(|(name, code)| (name.trim_end_matches(".rs").to_string(), code))
+            .collect()
+    }
+
+    // The methods of the hand-written `BidiCommand` impls.
+    fn command_methods() -> BTreeSet<String> {
+        const PREFIX: &str = "const METHOD: &'static str = \"";
+        models("remote")
+            .values()
+            .flat_map(|source| source.lines())
+            .filter_map(|line| line.trim().strip_prefix(PREFIX))
+            .filter_map(|rest| rest.split_once('"'))
+            .map(|(method, _)| method.to_string())
+            .collect()
+    }
+
+    // Fails with the list of differences when the hand-written models of `dir` no longer
+    // have the shape of the models generated from `cddl`, or lack one of its types or
+    // methods.
+    fn check(
+        cddl: &str,
+        overrides: &str,
+        source: &str,
+        dir: &str,
+        has_method: impl Fn(&str) -> bool,
+    ) {
+        let generated = generated(generate(cddl, overrides, source));
+        let models = models(dir);
+
+        let missing: Vec<String> = shape::missing(&generated, &models)
+            .into_iter()
+            .chain(
+                shape::methods(&generated)
+                    .into_iter()
+                    .filter(|method| !has_method(method)),
+            )
+            .filter(|name| !OMITTED.contains(&name.as_str()))
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "src/models/{} lacks these items of {}:\n{}",
+            dir,
+            source,
+            missing.join("\n")
+        );
+
+        let model_shapes = shape::shapes(&models);
+        let compared = shape::shapes(&generated)
+            .keys()
+            .filter(|name| model_shapes.contains_key(*name))
+            .count();
+        assert!(compared > 150, "only {} types were compared", compared);
+
+        let mismatches: Vec<String> = shape::compare(&generated, &models)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert!(
+            mismatches.is_empty(),
+            "src/models/{} differs from {}:\n{}",
+            dir,
+            source,
+            mismatches.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_shapes() {
+        let source = r#"
+            #[derive(Serialize, Deserialize)]
+            #[serde(tag = "type")]
+            pub enum Value {
+                #[serde(rename = "a")]
+                A(A),
+            }
+
+            #[derive(Serialize, Deserialize)]
+            pub struct A {
+                #[serde(rename = "camelCase")]
+                pub camel_case: bool, // trailing comment
+                pub plain: u8,
+                #[serde(flatten)]
+                pub base: Base,
+                #[serde(flatten)]
+                pub extensible: Extensible,
+            }
+
+            #[derive(Serialize, Deserialize)]
+            pub struct Base {
+                pub r#type: u8,
+            }
+
+            #[derive(Serialize, Deserialize)]
+            #[serde(rename_all = "kebab-case")]
+            pub enum Mode {
+                OnOff,
+                #[serde(rename = "other")]
+                Other,
+                #[serde(untagged)]
+                Unknown(String),
+            }
+        "#;
+        let modules = BTreeMap::from([("m".to_string(), source.to_string())]);
+        let shapes = shape::shapes(&modules);
+
+        let set = |names: &[&str]| names.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            shapes["m::A"],
+            shape::Shape::Struct(set(&["camelCase", "plain", "type"]))
+        );
+        assert_eq!(
+            shapes["m::Mode"],
+            shape::Shape::Enum(set(&["on-off", "other"]))
+        );
+        assert!(!shapes.contains_key("m::Value"));
+    }
+
+    #[test]
+    fn test_missing_types_and_methods() {
+        let generated = BTreeMap::from([
+            (
+                "common".to_string(),
+                r#"
+                #[derive(Serialize, Deserialize)]
+                #[serde(tag = "method")]
+                pub enum ModCommand {
+                    #[serde(rename = "mod.run")]
+                    Run(m::Run),
+                }
+                "#
+                .to_string(),
+            ),
+            (
+                "m".to_string(),
+                r#"
+                #[derive(Serialize, Deserialize)]
+                pub struct Run {}
+
+                #[derive(Serialize, Deserialize)]
+                pub struct Id(pub String);
+                "#
+                .to_string(),
+            ),
+        ]);
+        let models = BTreeMap::from([(
+            "m".to_string(),
+            "#[derive(Serialize, Deserialize)]\npub enum ModCommand {}\n\n#[derive(Serialize)]\npub struct Run {}".to_string(),
+        )]);
+
+        assert_eq!(
+            shape::methods(&generated),
+            BTreeSet::from(["mod.run".to_string()])
+        );
+        assert_eq!(shape::missing(&generated, &models), vec!["m::Id"]);
+    }
+
+    #[test]
+    fn test_remote_models_match_generated() {
+        let methods = command_methods();
+        check(
+            REMOTE_CDDL,
+            REMOTE_OVERRIDES,
+            "remote.cddl",
+            "remote",
+            |method| methods.contains(method),
+        );
+    }
+
+    #[test]
+    fn test_local_models_match_generated() {
+        check(
+            LOCAL_CDDL,
+            LOCAL_OVERRIDES,
+            "local.cddl",
+            "local",
+            |method| EventType::from_str(method).is_ok(),
+        );
+    }
+}
@@ -3,6 +3,7 @@ use tokio::time;
 
 // --------------------------------------------------
 
+use webdriverbidi::ids::BrowsingContext;
 use webdriverbidi::remote::browsing_context::{
     GetTreeParameters, NavigateParameters, ReadinessState,
 };
@@ -29,7 +30,10 @@ pub async fn init_session() -> Result<WebDriverBiDiSession> {
 }
 
 /// Retrieves the browsing context at the specified index.
-pub async fn get_context(session: &mut WebDriverBiDiSession, idx: usize) -> Result<String> {
+pub async fn get_context(
+    session: &mut WebDriverBiDiSession,
+    idx: usize,
+) -> Result<BrowsingContext> {
     let get_tree_params = GetTreeParameters::new(None, None);
     let get_tree_rslt = session.browsing_context_get_tree(get_tree_params).await?;
     if let Some(context_entry) = get_tree_rslt.contexts.get(idx) {
@@ -44,7 +48,11 @@ pub async fn get_context(session: &mut WebDriverBiDiSession, idx: usize) -> Resu
 }
 
 /// Navigates to the specified URL and waits for the document to completely load.
-pub async fn navigate(session: &mut WebDriverBiDiSession, ctx: String, url: String) -> Result<()> {
+pub async fn navigate(
+    session: &mut WebDriverBiDiSession,
+    ctx: BrowsingContext,
+    url: String,
+) -> Result<()> {
     let navigate_params = NavigateParameters::new(ctx, url, Some(ReadinessState::Complete));
     session.browsing_context_navigate(navigate_params).await?;
     Ok(())
@@ -0,0 +1,166 @@
+//! The bridge behind `WebDriverBiDiSession::expose_function`.
+//!
+//! A preload script defines `globalThis[name]` in every document. Calling it posts
+//! `{ id, args }` on a channel and returns a Promise kept in a per-realm table. The
+//! session runs the Rust function and settles the Promise by calling back into the realm
+//! the message came from.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+use log::{debug, error};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+// --------------------------------------------------
+
+use crate::error::{CommandError, ValueError};
+use crate::ids::Realm;
+use crate::local::script::{MessageParameters, RemoteValue, RemoteValueOrText};
+use crate::remote::script::{CallFunctionParameters, LocalValue, RealmTarget, Target};
+use crate::session::WebDriverBiDiSession;
+
+// --------------------------------------------------
+
+// Settles the Promise of call `id` to the binding `name` in the current realm.
+const REPLY_FUNCTION: &str = r#"(name, id, error, value) => {
+    const bindings = globalThis[Symbol.for("webdriverbidi.bindings")];
+    bindings?.[name]?.(id, error, value);
+}"#;
+
+/// Returns the preload script defining the binding `name`, taking the channel to post
+/// calls on as its only argument.
+pub(crate) fn preload_function(name: &str) -> String {
+    let name = serde_json::to_string(name).expect("a string serializes to JSON");
+    format!(
+        r#"(send) => {{
+    const name = {};
+    const key = Symbol.for("webdriverbidi.bindings");
+    const bindings = (globalThis[key] ??= {{}});
+    const pending = new Map();
+    let nextId = 0;
+    bindings[name] = (id, error, value) => {{
+        const call = pending.get(id);
+        if (call === undefined) {{
+            return;
+        }}
+        pending.delete(id);
+        if (error === null) {{
+            call.resolve(value);
+        }} else {{
+            call.reject(new Error(error));
+        }}
+    }};
+    globalThis[name] = (...args) =>
+        new Promise((resolve, reject) => {{
+            const id = nextId++;
+            pending.set(id, {{ resolve, reject }});
+            send({{ id, args }});
+        }});
+}}"#,
+        name
+    )
+}
+
+/// Runs `function` for every call posted on the binding's channel, until the channel
+/// is closed.
+pub(crate) fn spawn_dispatcher<A, R, E, F, Fut>(
+    session: WebDriverBiDiSession,
+    name: String,
+    mut receiver: mpsc::UnboundedReceiver<MessageParameters>,
+    function: F,
+) where
+    A: DeserializeOwned + Send + 'static,
+    R: Serialize + Send + 'static,
+    E: fmt::Display + Send + 'static,
+    F: Fn(A) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<R, E>> + Send + 'static,
+{
+    let function = Arc::new(function);
+    tokio::spawn(async move {
+        while let Some(message) = receiver.recv().await {
+            // Calls are independent, a slow one must not hold back the others.
+            tokio::spawn(dispatch(
+                session.clone(),
+                name.clone(),
+                function.clone(),
+                message,
+            ));
+        }
+        debug!("Binding {} was removed", name);
+    });
+}
+
+async fn dispatch<A, R, E, F, Fut>(
+    mut session: WebDriverBiDiSession,
+    name: String,
+    function: Arc<F>,
+    message: MessageParameters,
+) where
+    A: DeserializeOwned,
+    R: Serialize,
+    E: fmt::Display,
+    F: Fn(A) -> Fut,
+    Fut: Future<Output = Result<R, E>>,
+{
+    let realm = message.source.realm;
+    let mut data = message.data;
+    let Some(id) = take_field(&mut data, "id").and_then(|id| id.into::<u32>().ok()) else {
+        error!("Ignoring a malformed call to binding {}", name);
+        return;
+    };
+
+    // `args` is always an array, even for a single argument, so `A` is a tuple.
+    let outcome = match take_field(&mut data, "args")
+        .ok_or_else(|| ValueError("the call has no arguments".into()))
+        .and_then(|args| args.into::<A>())
+    {
+        Ok(args) => match function(args).await {
+            Ok(value) => LocalValue::from_serialize(&value).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        },
+        Err(e) => Err(e.to_string()),
+    };
+    let (error, value) = match outcome {
+        Ok(value) => (None, Some(value)),
+        Err(error) => (Some(error), None),
+    };
+
+    if let Err(e) = reply(&mut session, &name, realm, id, error, value).await {
+        // The realm is gone, e.g. its document navigated away before the call returned.
+        debug!("Failed to reply to a call to binding {}: {}", name, e);
+    }
+}
+
+async fn reply(
+    session: &mut WebDriverBiDiSession,
+    name: &str,
+    realm: Realm,
+    id: u32,
+    error: Option<String>,
+    value: Option<LocalValue>,
+) -> Result<(), CommandError> {
+    let params = CallFunctionParameters::builder(
+        REPLY_FUNCTION.to_string(),
+        false,
+        Target::RealmTarget(RealmTarget::new(realm)),
+    )
+    .arguments(vec![name.into(), id.into(), error.into(), value.into()])
+    .build();
+    session.script_call_function(params).await?;
+    Ok(())
+}
+
+// Removes the property `name` from a serialized plain object.
+fn take_field(value: &mut RemoteValue, name: &str) -> Option<RemoteValue> {
+    let RemoteValue::ObjectRemoteValue(object) = value else {
+        return None;
+    };
+    let entries = object.value.as_mut()?;
+    let index = entries
+        .iter()
+        .position(|(key, _)| matches!(key, RemoteValueOrText::Text(key) if key == name))?;
+    Some(entries.remove(index).1)
+}
@@ -0,0 +1,526 @@
+//! A parser for the subset of CDDL (RFC 8610) used by the WebDriver BiDi specification.
+
+use std::fmt;
+
+// --------------------------------------------------
+
+/// A named rule, e.g. `browsingContext.BrowsingContext = text`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub name: String,
+    pub value: RuleValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleValue {
+    Type(Type),
+    Group(Group),
+}
+
+/// A type choice, e.g. `"portrait" / "landscape"`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Type(pub Vec<Type1>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type1 {
+    Name(String),
+    Text(String),
+    Number(String),
+    Range {
+        lower: String,
+        upper: String,
+        inclusive: bool,
+    },
+    Map(Group),
+    Array(Group),
+    Paren(Type),
+    Control {
+        target: Box<Type1>,
+        op: String,
+        arg: Box<Type1>,
+    },
+}
+
+/// A group choice, e.g. `(a: text // b: bool)`. Each choice is a sequence of entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group(pub Vec<Vec<Entry>>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub occurrence: Occurrence,
+    pub key: Option<Key>,
+    pub value: EntryValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occurrence {
+    One,
+    Optional,
+    ZeroOrMore,
+    OneOrMore,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Key {
+    /// `name: value`
+    Bare(String),
+    /// `type => value`
+    Type(Type),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntryValue {
+    Type(Type),
+    Group(Group),
+}
+
+impl Type {
+    /// Returns the only alternative of the choice, looking through parentheses.
+    pub fn single(&self) -> Option<&Type1> {
+        match self.0.as_slice() {
+            [Type1::Paren(inner)] => inner.single(),
+            [t] => Some(t),
+            _ => None,
+        }
+    }
+}
+
+impl Type1 {
+    /// Returns the type with its control operators (`.default`, `.ge`, ...) removed.
+    pub fn strip_controls(&self) -> &Type1 {
+        match self {
+            Type1::Control { target, .. } => target.strip_controls(),
+            other => other,
+        }
+    }
+}
+
+// --------------------------------------------------
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// --------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Text(String),
+    Number(String),
+    Control(String),
+    Assign,
+    Slash,
+    DoubleSlash,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Colon,
+    Comma,
+    Question,
+    Star,
+    Plus,
+    Arrow,
+    Range(bool),
+    Eof,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || matches!(c, '@' | '_' | '$')
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '@' | '_' | '$' | '-' | '.')
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Tok, usize)>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    let error = |line, message: String| ParseError { line, message };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            ';' => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '"' => {
+                let start = i + 1;
+                i = start;
+                while i < chars.len() && chars[i] != '"' {
+                    i += 1;
+                }
+                if i == chars.len() {
+                    return Err(error(line, "unterminated text literal".to_string()));
+                }
+                tokens.push((Tok::Text(chars[start..i].iter().collect()), line));
+                i += 1;
+            }
+            '.' if next == Some('.') => {
+                if chars.get(i + 2) == Some(&'.') {
+                    tokens.push((Tok::Range(false), line));
+                    i += 3;
+                } else {
+                    tokens.push((Tok::Range(true), line));
+                    i += 2;
+                }
+            }
+            '.' if next.is_some_and(|n| n.is_ascii_alphabetic()) => {
+                let start = i + 1;
+                i = start;
+                while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                    i += 1;
+                }
+                tokens.push((Tok::Control(chars[start..i].iter().collect()), line));
+            }
+            c if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                let start = i;
+                i += 1;
+                while i < chars.len() {
+                    let d = chars[i];
+                    let is_fraction =
+                        d == '.' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit());
+                    if d.is_ascii_digit() || is_fraction {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push((Tok::Number(chars[start..i].iter().collect()), line));
+            }
+            c if is_ident_start(c) => {
+                let start = i;
+                while i < chars.len() && is_ident_char(chars[i]) {
+                    i += 1;
+                }
+                // Identifiers cannot end with `.` or `-`.
+                while matches!(chars[i - 1], '.' | '-') {
+                    i -= 1;
+                }
+                tokens.push((Tok::Ident(chars[start..i].iter().collect()), line));
+            }
+            _ => {
+                let (tok, len) = match (c, next) {
+                    ('/', Some('/')) => (Tok::DoubleSlash, 2),
+                    ('=', Some('>')) => (Tok::Arrow, 2),
+                    ('/', _) => (Tok::Slash, 1),
+                    ('=', _) => (Tok::Assign, 1),
+                    ('(', _) => (Tok::LParen, 1),
+                    (')', _) => (Tok::RParen, 1),
+                    ('{', _) => (Tok::LBrace, 1),
+                    ('}', _) => (Tok::RBrace, 1),
+                    ('[', _) => (Tok::LBracket, 1),
+                    (']', _) => (Tok::RBracket, 1),
+                    (':', _) => (Tok::Colon, 1),
+                    (',', _) => (Tok::Comma, 1),
+                    ('?', _) => (Tok::Question, 1),
+                    ('*', _) => (Tok::Star, 1),
+                    ('+', _) => (Tok::Plus, 1),
+                    _ => return Err(error(line, format!("unexpected character `{}`", c))),
+                };
+                tokens.push((tok, line));
+                i += len;
+            }
+        }
+    }
+
+    tokens.push((Tok::Eof, line));
+    Ok(tokens)
+}
+
+// --------------------------------------------------
+
+/// Parses a CDDL document into its rules, in the order they are defined.
+pub fn parse(input: &str) -> Result<Vec<Rule>, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+    };
+    let mut rules = Vec::new();
+    while parser.peek() != &Tok::Eof {
+        rules.push(parser.rule()?);
+    }
+    Ok(rules)
+}
+
+struct Parser {
+    tokens: Vec<(Tok, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Tok {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> &Tok {
+        let index = (self.pos + offset).min(self.tokens.len() - 1);
+        &self.tokens[index].0
+    }
+
+    fn next(&mut self) -> Tok {
+        let tok = self.peek().clone();
+        if tok != Tok::Eof {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, ParseError> {
+        Err(ParseError {
+            line: self.tokens[self.pos.min(self.tokens.len() - 1)].1,
+            message: message.into(),
+        })
+    }
+
+    fn expect(&mut self, expected: Tok) -> Result<(), ParseError> {
+        if self.peek() == &expected {
+            self.next();
+            Ok(())
+        } else {
+            self.error(format!("expected {:?}, found {:?}", expected, self.peek()))
+        }
+    }
+
+    fn rule(&mut self) -> Result<Rule, ParseError> {
+        let name = match self.next() {
+            Tok::Ident(name) => name,
+            tok => return self.error(format!("expected a rule name, found {:?}", tok)),
+        };
+        self.expect(Tok::Assign)?;
+
+        let value = if self.peek() == &Tok::LParen {
+            self.next();
+            let group = self.group(&Tok::RParen)?;
+            self.expect(Tok::RParen)?;
+            match as_type(&group) {
+                // `X = (a / b)` is a parenthesized type, possibly followed by more alternatives.
+                Some(ty) => {
+                    let mut first = Type1::Paren(ty);
+                    while let Tok::Control(op) = self.peek().clone() {
+                        self.next();
+                        let arg = self.type2()?;
+                        first = Type1::Control {
+                            target: Box::new(first),
+                            op,
+                            arg: Box::new(arg),
+                        };
+                    }
+                    let mut alternatives = vec![first];
+                    while self.peek() == &Tok::Slash {
+                        self.next();
+                        alternatives.push(self.type1()?);
+                    }
+                    RuleValue::Type(Type(alternatives))
+                }
+                None => RuleValue::Group(group),
+            }
+        } else {
+            RuleValue::Type(self.ty()?)
+        };
+
+        Ok(Rule { name, value })
+    }
+
+    fn group(&mut self, end: &Tok) -> Result<Group, ParseError> {
+        let mut choices = Vec::new();
+        loop {
+            let mut entries = Vec::new();
+            while self.peek() != end && self.peek() != &Tok::DoubleSlash {
+                entries.push(self.entry()?);
+                if self.peek() == &Tok::Comma {
+                    self.next();
+                }
+            }
+            if !entries.is_empty() {
+                choices.push(entries);
+            }
+            if self.peek() == &Tok::DoubleSlash {
+                self.next();
+            } else {
+                break;
+            }
+        }
+        Ok(Group(choices))
+    }
+
+    fn entry(&mut self) -> Result<Entry, ParseError> {
+        let occurrence = match self.peek() {
+            Tok::Question => Occurrence::Optional,
+            Tok::Star => Occurrence::ZeroOrMore,
+            Tok::Plus => Occurrence::OneOrMore,
+            _ => Occurrence::One,
+        };
+        if occurrence != Occurrence::One {
+            self.next();
+        }
+
+        if matches!(self.peek(), Tok::Ident(_) | Tok::Text(_)) && self.peek_at(1) == &Tok::Colon {
+            let key = match self.next() {
+                Tok::Ident(key) | Tok::Text(key) => key,
+                _ => unreachable!(),
+            };
+            self.next();
+            let value = self.ty()?;
+            return Ok(Entry {
+                occurrence,
+                key: Some(Key::Bare(key)),
+                value: EntryValue::Type(value),
+            });
+        }
+
+        if self.peek() == &Tok::LParen && self.is_group_paren() {
+            self.next();
+            let group = self.group(&Tok::RParen)?;
+            self.expect(Tok::RParen)?;
+            return Ok(Entry {
+                occurrence,
+                key: None,
+                value: EntryValue::Group(group),
+            });
+        }
+
+        // `(a // b)` only turns out to be a group once the parser reaches the `//`.
+        let start = self.pos;
+        let ty = match self.ty() {
+            Ok(ty) => ty,
+            Err(_) if self.tokens[start].0 == Tok::LParen => {
+                self.pos = start + 1;
+                let group = self.group(&Tok::RParen)?;
+                self.expect(Tok::RParen)?;
+                return Ok(Entry {
+                    occurrence,
+                    key: None,
+                    value: EntryValue::Group(group),
+                });
+            }
+            Err(err) => return Err(err),
+        };
+        if self.peek() == &Tok::Arrow {
+            self.next();
+            let value = self.ty()?;
+            return Ok(Entry {
+                occurrence,
+                key: Some(Key::Type(ty)),
+                value: EntryValue::Type(value),
+            });
+        }
+
+        Ok(Entry {
+            occurrence,
+            key: None,
+            value: EntryValue::Type(ty),
+        })
+    }
+
+    // Whether the parenthesis at the current position opens a group rather than a type.
+    fn is_group_paren(&self) -> bool {
+        match self.peek_at(1) {
+            Tok::Question | Tok::Star | Tok::Plus => true,
+            Tok::Ident(_) | Tok::Text(_) => self.peek_at(2) == &Tok::Colon,
+            _ => false,
+        }
+    }
+
+    fn ty(&mut self) -> Result<Type, ParseError> {
+        let mut alternatives = vec![self.type1()?];
+        while self.peek() == &Tok::Slash {
+            self.next();
+            alternatives.push(self.type1()?);
+        }
+        Ok(Type(alternatives))
+    }
+
+    fn type1(&mut self) -> Result<Type1, ParseError> {
+        let mut ty = self.type2()?;
+        if let Tok::Range(inclusive) = *self.peek() {
+            self.next();
+            let upper = self.type2()?;
+            ty = match (ty, upper) {
+                (
+                    Type1::Number(lower) | Type1::Name(lower),
+                    Type1::Number(upper) | Type1::Name(upper),
+                ) => Type1::Range {
+                    lower,
+                    upper,
+                    inclusive,
+                },
+                _ => return self.error("unsupported range bounds"),
+            };
+        }
+        while let Tok::Control(op) = self.peek().clone() {
+            self.next();
+            let arg = self.type2()?;
+            ty = Type1::Control {
+                target: Box::new(ty),
+                op,
+                arg: Box::new(arg),
+            };
+        }
+        Ok(ty)
+    }
+
+    fn type2(&mut self) -> Result<Type1, ParseError> {
+        match self.next() {
+            Tok::Ident(name) => Ok(Type1::Name(name)),
+            Tok::Text(text) => Ok(Type1::Text(text)),
+            Tok::Number(number) => Ok(Type1::Number(number)),
+            Tok::LBrace => {
+                let group = self.group(&Tok::RBrace)?;
+                self.expect(Tok::RBrace)?;
+                Ok(Type1::Map(group))
+            }
+            Tok::LBracket => {
+                let group = self.group(&Tok::RBracket)?;
+                self.expect(Tok::RBracket)?;
+                Ok(Type1::Array(group))
+            }
+            Tok::LParen => {
+                let ty = self.ty()?;
+                self.expect(Tok::RParen)?;
+                Ok(Type1::Paren(ty))
+            }
+            tok => self.error(format!("expected a type, found {:?}", tok)),
+        }
+    }
+}
+
+// Returns the type of a parenthesized group that is really a parenthesized type,
+// i.e. a single entry without a key or an occurrence indicator.
+fn as_type(group: &Group) -> Option<Type> {
+    match group.0.as_slice() {
+        [entries] => match entries.as_slice() {
+            [Entry {
+                occurrence: Occurrence::One,
+                key: None,
+                value: EntryValue::Type(ty),
+            }] => Some(ty.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,657 @@
+//! Checks JSON values against the rules of a CDDL schema.
+//!
+//! Used to catch drift between the models and the specification: a command that
+//! does not conform to `remote.cddl` is reported with the path of the offending
+//! value, instead of as an `invalid argument` error from the browser.
+//!
+//! Maps accept extension keys (`prefix:name`) at every level, even where the schema
+//! does not list `Extensible`, since implementations define their own.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::sync::OnceLock;
+
+use serde_json::{Map, Value};
+
+use super::parser::{
+    parse, Entry, EntryValue, Group, Key, Occurrence, ParseError, RuleValue, Type, Type1,
+};
+
+// --------------------------------------------------
+
+const REMOTE_CDDL: &str = include_str!("../../cddl/remote.cddl");
+const LOCAL_CDDL: &str = include_str!("../../cddl/local.cddl");
+
+const ID_KEY: &str = "id";
+const METHOD_KEY: &str = "method";
+const PARAMS_KEY: &str = "params";
+const TYPE_KEY: &str = "type";
+
+// --------------------------------------------------
+
+/// A value that does not conform to the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The path of the offending value, e.g. `params.contexts[0]`.
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(path: &str, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+type ValidationResult = Result<(), ValidationError>;
+
+// --------------------------------------------------
+
+/// The rules of a CDDL document, indexed for validation.
+#[derive(Debug)]
+pub struct Schema {
+    rules: HashMap<String, RuleValue>,
+    // The params type of every command and event, by method name.
+    methods: HashMap<String, Type>,
+}
+
+impl Schema {
+    /// Parses a CDDL document.
+    pub fn parse(cddl: &str) -> Result<Self, ParseError> {
+        let rules: HashMap<String, RuleValue> = parse(cddl)?
+            .into_iter()
+            .map(|rule| (rule.name, rule.value))
+            .collect();
+
+        let mut methods = HashMap::new();
+        for value in rules.values() {
+            let RuleValue::Group(Group(choices)) = value else {
+                continue;
+            };
+            let [entries] = choices.as_slice() else {
+                continue;
+            };
+            let field = |name: &str| {
+                entries
+                    .iter()
+                    .find_map(|entry| match (&entry.key, &entry.value) {
+                        (Some(Key::Bare(key)), EntryValue::Type(ty)) if key == name => Some(ty),
+                        _ => None,
+                    })
+            };
+            if let (Some(method), Some(params)) = (field(METHOD_KEY), field(PARAMS_KEY)) {
+                if let Some(Type1::Text(method)) = method.single() {
+                    methods.insert(method.clone(), params.clone());
+                }
+            }
+        }
+
+        Ok(Self { rules, methods })
+    }
+
+    /// The schema of the messages sent to the remote end (`cddl/remote.cddl`).
+    pub fn remote() -> &'static Self {
+        static SCHEMA: OnceLock<Schema> = OnceLock::new();
+        SCHEMA.get_or_init(|| Self::parse(REMOTE_CDDL).expect("remote.cddl is valid CDDL"))
+    }
+
+    /// The schema of the messages received from the remote end (`cddl/local.cddl`).
+    pub fn local() -> &'static Self {
+        static SCHEMA: OnceLock<Schema> = OnceLock::new();
+        SCHEMA.get_or_init(|| Self::parse(LOCAL_CDDL).expect("local.cddl is valid CDDL"))
+    }
+
+    /// Whether the schema defines a command or an event named `method`.
+    pub fn has_method(&self, method: &str) -> bool {
+        self.methods.contains_key(method)
+    }
+
+    /// Validates `value` against the rule named `rule`, e.g. `browsingContext.Locator`.
+    pub fn validate(&self, rule: &str, value: &Value) -> ValidationResult {
+        if !self.rules.contains_key(rule) {
+            return Err(ValidationError::new(
+                "",
+                format!("undefined rule `{}`", rule),
+            ));
+        }
+        self.match_name(rule, value, "")
+    }
+
+    /// Validates a serialized `remote::command::Command`.
+    ///
+    /// Commands whose method is not defined in the schema (e.g. extension modules
+    /// missing from the bundled CDDL) are only checked for an `id` and a `method`.
+    pub fn validate_command(&self, command: &Value) -> ValidationResult {
+        let object = as_object(command, "")?;
+        match object.get(ID_KEY) {
+            Some(id) => self.match_name("js-uint", id, ID_KEY)?,
+            None => return Err(ValidationError::new("", "missing key `id`")),
+        }
+        self.validate_params(object)
+    }
+
+    /// Validates a received `local::message::Message`.
+    ///
+    /// Events are checked against the params of their method, and command responses
+    /// against `CommandResponse`, since the command they answer is not known here.
+    pub fn validate_message(&self, message: &Value) -> ValidationResult {
+        let object = as_object(message, "")?;
+        match object.get(TYPE_KEY).and_then(Value::as_str) {
+            Some("event") => self.validate_params(object),
+            Some("success") => self.validate("CommandResponse", message),
+            Some("error") => self.validate("ErrorResponse", message),
+            _ => self.validate("Message", message),
+        }
+    }
+
+    fn validate_params(&self, object: &Map<String, Value>) -> ValidationResult {
+        let method = match object.get(METHOD_KEY) {
+            Some(Value::String(method)) => method,
+            Some(other) => {
+                return Err(ValidationError::new(
+                    METHOD_KEY,
+                    format!("expected text, found {}", kind(other)),
+                ))
+            }
+            None => return Err(ValidationError::new("", "missing key `method`")),
+        };
+        let Some(params_type) = self.methods.get(method) else {
+            return Ok(());
+        };
+        match object.get(PARAMS_KEY) {
+            Some(params) => self.match_type(params_type, params, PARAMS_KEY),
+            None => Err(ValidationError::new("", "missing key `params`")),
+        }
+    }
+
+    // --------------------------------------------------
+    // Types
+
+    fn match_type(&self, ty: &Type, value: &Value, path: &str) -> ValidationResult {
+        let mut errors = Vec::new();
+        for t1 in &ty.0 {
+            match self.match_type1(t1, value, path) {
+                Ok(()) => return Ok(()),
+                Err(err) => errors.push(err),
+            }
+        }
+        if ty.0.len() > 1 && errors.iter().all(|err| err.path == path) {
+            let expected: Vec<String> = ty.0.iter().map(describe).collect();
+            return Err(ValidationError::new(
+                path,
+                format!("expected {}, found {}", expected.join(" / "), kind(value)),
+            ));
+        }
+        Err(deepest(errors).unwrap_or_else(|| ValidationError::new(path, "empty type choice")))
+    }
+
+    fn match_type1(&self, t1: &Type1, value: &Value, path: &str) -> ValidationResult {
+        match t1 {
+            Type1::Name(name) => self.match_name(name, value, path),
+            Type1::Text(text) => match value {
+                Value::String(s) if s == text => Ok(()),
+                _ => Err(mismatch(path, &format!("\"{}\"", text), value)),
+            },
+            Type1::Number(number) => match (value.as_f64(), number.parse::<f64>()) {
+                (Some(v), Ok(n)) if v == n => Ok(()),
+                _ => Err(mismatch(path, number, value)),
+            },
+            Type1::Range {
+                lower,
+                upper,
+                inclusive,
+            } => {
+                let description = format!("{}..{}", lower, upper);
+                let Some(v) = value.as_f64() else {
+                    return Err(mismatch(path, &description, value));
+                };
+                let integral = !lower.contains('.') && !upper.contains('.');
+                let (lower, upper) = (lower.parse::<f64>(), upper.parse::<f64>());
+                let in_range = match (lower, upper) {
+                    (Ok(lower), Ok(upper)) => {
+                        v >= lower && if *inclusive { v <= upper } else { v < upper }
+                    }
+                    _ => true,
+                };
+                if in_range && (!integral || is_integer(value)) {
+                    Ok(())
+                } else {
+                    Err(ValidationError::new(
+                        path,
+                        format!("expected {}, found {}", description, value),
+                    ))
+                }
+            }
+            Type1::Map(group) => {
+                let object = as_object(value, path)?;
+                self.match_map(group, object, path)
+            }
+            Type1::Array(group) => match value {
+                Value::Array(items) => self.match_array(group, items, path),
+                _ => Err(mismatch(path, "an array", value)),
+            },
+            Type1::Paren(ty) => self.match_type(ty, value, path),
+            Type1::Control { target, op, arg } => self.match_control(target, op, arg, value, path),
+        }
+    }
+
+    fn match_control(
+        &self,
+        target: &Type1,
+        op: &str,
+        arg: &Type1,
+        value: &Value,
+        path: &str,
+    ) -> ValidationResult {
+        // `{ ... } .and other` adds the entries of `other` to the map.
+        if let (Type1::Map(group), "and", Type1::Name(other)) = (target, op, arg) {
+            if let Some(other_group) = self.map_group(other) {
+                let object = as_object(value, path)?;
+                let combined = Group(
+                    group
+                        .0
+                        .iter()
+                        .map(|entries| {
+                            let mut entries = entries.clone();
+                            entries.push(Entry {
+                                occurrence: Occurrence::One,
+                                key: None,
+                                value: EntryValue::Group(other_group.clone()),
+                            });
+                            entries
+                        })
+                        .collect(),
+                );
+                return self.match_map(&combined, object, path);
+            }
+        }
+
+        self.match_type1(target, value, path)?;
+        let bound = match arg {
+            Type1::Number(number) => number.parse::<f64>().ok(),
+            _ => None,
+        };
+        let satisfied = match (op, value.as_f64(), bound) {
+            ("ge", Some(v), Some(b)) => v >= b,
+            ("gt", Some(v), Some(b)) => v > b,
+            ("le", Some(v), Some(b)) => v <= b,
+            ("lt", Some(v), Some(b)) => v < b,
+            ("and", _, _) => return self.match_type1(arg, value, path),
+            _ => true,
+        };
+        if satisfied {
+            Ok(())
+        } else {
+            Err(ValidationError::new(
+                path,
+                format!(
+                    "expected a value .{} {}, found {}",
+                    op,
+                    describe(arg),
+                    value
+                ),
+            ))
+        }
+    }
+
+    fn match_name(&self, name: &str, value: &Value, path: &str) -> ValidationResult {
+        let matches = match name {
+            "any" => true,
+            "null" | "nil" => value.is_null(),
+            "bool" => value.is_boolean(),
+            "true" => value == &Value::Bool(true),
+            "false" => value == &Value::Bool(false),
+            "text" | "tstr" => value.is_string(),
+            "number" | "float" | "float16" | "float32" | "float64" => value.is_number(),
+            "int" => is_integer(value),
+            "uint" => is_integer(value) && value.as_f64().is_some_and(|v| v >= 0.0),
+            _ => {
+                return match self.rules.get(name) {
+                    Some(RuleValue::Type(ty)) => self.match_type(ty, value, path),
+                    Some(RuleValue::Group(group)) => {
+                        let object = as_object(value, path)?;
+                        self.match_map(group, object, path)
+                    }
+                    None => Err(ValidationError::new(
+                        path,
+                        format!("undefined rule `{}`", name),
+                    )),
+                };
+            }
+        };
+        if matches {
+            Ok(())
+        } else {
+            Err(mismatch(path, name, value))
+        }
+    }
+
+    // The group of a rule that can be used inside a map: `X = (...)` or `X = {...}`.
+    fn map_group(&self, name: &str) -> Option<&Group> {
+        match self.rules.get(name)? {
+            RuleValue::Group(group) => Some(group),
+            RuleValue::Type(ty) => match ty.single()? {
+                Type1::Map(group) => Some(group),
+                _ => None,
+            },
+        }
+    }
+
+    // --------------------------------------------------
+    // Maps
+
+    fn match_map(
+        &self,
+        group: &Group,
+        object: &Map<String, Value>,
+        path: &str,
+    ) -> ValidationResult {
+        let mut errors = Vec::new();
+        for entries in &group.0 {
+            let mut state = MapState {
+                remaining: object.keys().map(String::as_str).collect(),
+                splats: Vec::new(),
+            };
+            let result = self
+                .match_entries(entries, object, &mut state, path)
+                .and_then(|()| self.match_splats(object, &mut state, path));
+            match result {
+                Ok(()) => return Ok(()),
+                Err(err) => errors.push(err),
+            }
+        }
+        if group.0.is_empty() {
+            let mut state = MapState {
+                remaining: object.keys().map(String::as_str).collect(),
+                splats: Vec::new(),
+            };
+            return self.match_splats(object, &mut state, path);
+        }
+        Err(deepest(errors).expect("at least one group choice"))
+    }
+
+    fn match_entries<'v, 's>(
+        &'s self,
+        entries: &'s [Entry],
+        object: &'v Map<String, Value>,
+        state: &mut MapState<'v, 's>,
+        path: &str,
+    ) -> ValidationResult {
+        for entry in entries {
+            let optional = matches!(
+                entry.occurrence,
+                Occurrence::Optional | Occurrence::ZeroOrMore
+            );
+            match (&entry.key, &entry.value) {
+                (Some(Key::Bare(key)), EntryValue::Type(ty)) => match object.get_key_value(key) {
+                    Some((key, value)) => {
+                        state.remaining.remove(key.as_str());
+                        self.match_type(ty, value, &join(path, key))?;
+                    }
+                    None if optional => {}
+                    None => {
+                        return Err(ValidationError::new(path, format!("missing key `{}`", key)))
+                    }
+                },
+                (Some(Key::Type(key)), EntryValue::Type(value)) => state.splats.push((key, value)),
+                (None, EntryValue::Type(ty)) => {
+                    let group = match ty.single() {
+                        Some(Type1::Name(name)) => self.map_group(name),
+                        _ => None,
+                    };
+                    let Some(group) = group else {
+                        return Err(ValidationError::new(
+                            path,
+                            format!("unsupported map entry `{}`", describe_type(ty)),
+                        ));
+                    };
+                    self.match_inline_group(group, optional, object, state, path)?;
+                }
+                (None, EntryValue::Group(group)) => {
+                    self.match_inline_group(group, optional, object, state, path)?;
+                }
+                (Some(_), EntryValue::Group(_)) => {}
+            }
+        }
+        Ok(())
+    }
+
+    // Matches a group inlined into a map. Of the matching choices, the one that
+    // consumes the most keys wins.
+    fn match_inline_group<'v, 's>(
+        &'s self,
+        group: &'s Group,
+        optional: bool,
+        object: &'v Map<String, Value>,
+        state: &mut MapState<'v, 's>,
+        path: &str,
+    ) -> ValidationResult {
+        let mut best: Option<MapState> = None;
+        let mut errors = Vec::new();
+        for entries in &group.0 {
+            let mut candidate = state.clone();
+            match self.match_entries(entries, object, &mut candidate, path) {
+                Ok(()) => {
+                    if best
+                        .as_ref()
+                        .is_none_or(|best| candidate.remaining.len() < best.remaining.len())
+                    {
+                        best = Some(candidate);
+                    }
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+        match best {
+            Some(best) => {
+                *state = best;
+                Ok(())
+            }
+            None if optional => Ok(()),
+            None => {
+                Err(deepest(errors).unwrap_or_else(|| ValidationError::new(path, "empty group")))
+            }
+        }
+    }
+
+    fn match_splats<'v, 's>(
+        &'s self,
+        object: &'v Map<String, Value>,
+        state: &mut MapState<'v, 's>,
+        path: &str,
+    ) -> ValidationResult {
+        for (key_type, value_type) in &state.splats {
+            for key in state.remaining.clone() {
+                if self
+                    .match_type(key_type, &Value::String(key.to_string()), path)
+                    .is_ok()
+                {
+                    self.match_type(value_type, &object[key], &join(path, key))?;
+                    state.remaining.remove(key);
+                }
+            }
+        }
+        match state.remaining.iter().find(|key| !is_extension_key(key)) {
+            Some(key) => Err(ValidationError::new(
+                &join(path, key),
+                "unexpected key".to_string(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // --------------------------------------------------
+    // Arrays
+
+    fn match_array(&self, group: &Group, items: &[Value], path: &str) -> ValidationResult {
+        let mut errors = Vec::new();
+        for entries in &group.0 {
+            match self.match_array_entries(entries, items, path) {
+                Ok(()) => return Ok(()),
+                Err(err) => errors.push(err),
+            }
+        }
+        if group.0.is_empty() && items.is_empty() {
+            return Ok(());
+        }
+        Err(deepest(errors)
+            .unwrap_or_else(|| ValidationError::new(path, "expected an empty array")))
+    }
+
+    fn match_array_entries(
+        &self,
+        entries: &[Entry],
+        items: &[Value],
+        path: &str,
+    ) -> ValidationResult {
+        let mut index = 0;
+        for (position, entry) in entries.iter().enumerate() {
+            let EntryValue::Type(ty) = &entry.value else {
+                return Err(ValidationError::new(path, "unsupported array entry"));
+            };
+            let is_last = position == entries.len() - 1;
+            let item_path = |index: usize| format!("{}[{}]", path, index);
+            match entry.occurrence {
+                Occurrence::One => {
+                    let Some(item) = items.get(index) else {
+                        return Err(ValidationError::new(
+                            path,
+                            format!("missing element {}", describe_type(ty)),
+                        ));
+                    };
+                    self.match_type(ty, item, &item_path(index))?;
+                    index += 1;
+                }
+                Occurrence::Optional => {
+                    if let Some(item) = items.get(index) {
+                        if self.match_type(ty, item, &item_path(index)).is_ok() {
+                            index += 1;
+                        }
+                    }
+                }
+                Occurrence::ZeroOrMore | Occurrence::OneOrMore => {
+                    let start = index;
+                    while let Some(item) = items.get(index) {
+                        match self.match_type(ty, item, &item_path(index)) {
+                            Ok(()) => index += 1,
+                            // The last entry takes all remaining elements, so its errors are
+                            // reported rather than ending the repetition.
+                            Err(err) if is_last => return Err(err),
+                            Err(_) => break,
+                        }
+                    }
+                    if entry.occurrence == Occurrence::OneOrMore && index == start {
+                        return Err(ValidationError::new(
+                            path,
+                            format!("expected at least one {}", describe_type(ty)),
+                        ));
+                    }
+                }
+            }
+        }
+        match items.get(index) {
+            Some(_) => Err(ValidationError::new(
+                &format!("{}[{}]", path, index),
+                "unexpected element",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+// --------------------------------------------------
+
+#[derive(Clone)]
+struct MapState<'v, 's> {
+    // The keys of the object not matched by any entry yet.
+    remaining: BTreeSet<&'v str>,
+    // `key-type => value-type` entries, matched against the remaining keys last.
+    splats: Vec<(&'s Type, &'s Type)>,
+}
+
+fn as_object<'v>(value: &'v Value, path: &str) -> Result<&'v Map<String, Value>, ValidationError> {
+    value
+        .as_object()
+        .ok_or_else(|| mismatch(path, "a map", value))
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+// Keys with a vendor prefix (e.g. `goog:flag`) are extensions, allowed in any map.
+fn is_extension_key(key: &str) -> bool {
+    key.contains(':')
+}
+
+fn is_integer(value: &Value) -> bool {
+    value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|v| v.fract() == 0.0)
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "text",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "a map",
+    }
+}
+
+fn mismatch(path: &str, expected: &str, value: &Value) -> ValidationError {
+    ValidationError::new(
+        path,
+        format!("expected {}, found {}", expected, kind(value)),
+    )
+}
+
+// The error that got furthest into the value, i.e. the one of the closest alternative.
+fn deepest(errors: Vec<ValidationError>) -> Option<ValidationError> {
+    let mut deepest: Option<ValidationError> = None;
+    for err in errors {
+        if deepest
+            .as_ref()
+            .is_none_or(|d| err.path.len() > d.path.len())
+        {
+            deepest = Some(err);
+        }
+    }
+    deepest
+}
+
+fn describe(t1: &Type1) -> String {
+    match t1 {
+        Type1::Name(name) => name.clone(),
+        Type1::Text(text) => format!("\"{}\"", text),
+        Type1::Number(number) => number.clone(),
+        Type1::Range { lower, upper, .. } => format!("{}..{}", lower, upper),
+        Type1::Map(_) => "a map".to_string(),
+        Type1::Array(_) => "an array".to_string(),
+        Type1::Paren(ty) => format!("({})", describe_type(ty)),
+        Type1::Control { target, .. } => describe(target),
+    }
+}
+
+fn describe_type(ty: &Type) -> String {
+    ty.0.iter().map(describe).collect::<Vec<_>>().join(" / ")
+}
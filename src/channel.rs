@@ -0,0 +1,189 @@
+//! Typed streams of `script.message` events.
+//!
+//! Preload scripts receive `ChannelValue` arguments as functions that post
+//! `script.message` events tagged with the channel ID. The session routes those events
+//! to the [`ChannelStream`] that opened the channel.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures::stream::Stream;
+use log::error;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+// --------------------------------------------------
+
+use crate::ids::PreloadScript;
+use crate::local::script::MessageParameters;
+use crate::remote::script::Channel;
+
+// --------------------------------------------------
+
+static NEXT_CHANNEL: AtomicU64 = AtomicU64::new(0);
+
+// --------------------------------------------------
+
+struct Route {
+    script: Option<PreloadScript>,
+    sender: mpsc::UnboundedSender<MessageParameters>,
+}
+
+/// Dispatches `script.message` events to the channels opened on a session.
+#[derive(Clone, Default)]
+pub(crate) struct ChannelRouter {
+    routes: Arc<Mutex<HashMap<Channel, Route>>>,
+    // Whether the session subscribed to `script.message` for its channels.
+    subscribed: Arc<AtomicBool>,
+}
+
+impl ChannelRouter {
+    /// Opens a channel with a unique ID and returns the receiving end of its messages.
+    pub(crate) fn open(&self) -> (Channel, mpsc::UnboundedReceiver<MessageParameters>) {
+        let channel = format!(
+            "webdriverbidi-channel-{}",
+            NEXT_CHANNEL.fetch_add(1, Ordering::Relaxed)
+        );
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let route = Route {
+            script: None,
+            sender,
+        };
+        self.routes.lock().unwrap().insert(channel.clone(), route);
+        (channel, receiver)
+    }
+
+    /// Ties a channel to the preload script it was passed to, see `close_script`.
+    pub(crate) fn bind(&self, channel: &str, script: PreloadScript) {
+        if let Some(route) = self.routes.lock().unwrap().get_mut(channel) {
+            route.script = Some(script);
+        }
+    }
+
+    /// Closes a channel, ending its stream once the queued messages are consumed.
+    pub(crate) fn close(&self, channel: &str) {
+        self.routes.lock().unwrap().remove(channel);
+    }
+
+    /// Closes the channels passed to a preload script.
+    pub(crate) fn close_script(&self, script: &PreloadScript) {
+        self.routes
+            .lock()
+            .unwrap()
+            .retain(|_, route| route.script.as_ref() != Some(script));
+    }
+
+    /// Returns `true` the first time it is called, when the session has yet to subscribe
+    /// to `script.message`.
+    pub(crate) fn claim_subscription(&self) -> bool {
+        !self.subscribed.swap(true, Ordering::SeqCst)
+    }
+
+    /// Undoes `claim_subscription`, e.g. when subscribing failed.
+    pub(crate) fn release_subscription(&self) {
+        self.subscribed.store(false, Ordering::SeqCst);
+    }
+
+    /// Forwards a `script.message` event to its channel, if it was opened here.
+    pub(crate) fn route(&self, event: &Value) {
+        let Some(channel) = event
+            .pointer("/params/channel")
+            .and_then(|channel| channel.as_str())
+        else {
+            return;
+        };
+        let mut routes = self.routes.lock().unwrap();
+        let Some(route) = routes.get(channel) else {
+            return;
+        };
+        match serde_json::from_value::<MessageParameters>(event["params"].clone()) {
+            Ok(params) => {
+                // The receiver was dropped: nobody is listening anymore.
+                if route.sender.send(params).is_err() {
+                    routes.remove(channel);
+                }
+            }
+            Err(e) => error!("Failed to parse script.message event: {}", e),
+        }
+    }
+}
+
+// --------------------------------------------------
+
+/// A stream of the messages posted to a channel, deserialized as `T`.
+///
+/// The stream ends when the preload script it was created for is removed with
+/// `WebDriverBiDiSession::script_remove_preload_script`. Dropping the stream closes
+/// its channel, later messages are discarded. Messages that cannot be deserialized
+/// as `T` are logged and skipped.
+pub struct ChannelStream<T> {
+    channel: Channel,
+    script: PreloadScript,
+    receiver: mpsc::UnboundedReceiver<MessageParameters>,
+    router: ChannelRouter,
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<T> ChannelStream<T> {
+    pub(crate) fn new(
+        channel: Channel,
+        script: PreloadScript,
+        receiver: mpsc::UnboundedReceiver<MessageParameters>,
+        router: ChannelRouter,
+    ) -> Self {
+        Self {
+            channel,
+            script,
+            receiver,
+            router,
+            _item: PhantomData,
+        }
+    }
+
+    /// Returns the ID of the channel.
+    pub fn channel(&self) -> &Channel {
+        &self.channel
+    }
+
+    /// Returns the preload script the channel was passed to.
+    pub fn preload_script(&self) -> &PreloadScript {
+        &self.script
+    }
+}
+
+impl<T> fmt::Debug for ChannelStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChannelStream")
+            .field("channel", &self.channel)
+            .field("script", &self.script)
+            .finish()
+    }
+}
+
+impl<T> Drop for ChannelStream<T> {
+    fn drop(&mut self) {
+        self.router.close(&self.channel);
+    }
+}
+
+impl<T: DeserializeOwned> Stream for ChannelStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        loop {
+            let Some(message) = futures::ready!(self.receiver.poll_recv(cx)) else {
+                return Poll::Ready(None);
+            };
+            match message.data.into() {
+                Ok(item) => return Poll::Ready(Some(item)),
+                Err(e) => error!("Skipping message on channel {}: {}", self.channel, e),
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 // --------------------------------------------------
 
 use futures::SinkExt;
-use log::{debug, error};
+use log::{debug, error, warn};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::Value;
@@ -16,12 +16,16 @@ use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 
 // --------------------------------------------------
 
-use super::error::CommandError;
+use super::error::{CommandError, CommandInfo};
+#[cfg(debug_assertions)]
+use crate::cddl::Schema;
+use crate::local::error_response::ErrorResponse;
 
 // --------------------------------------------------
 
 const COMMAND_ID_KEY: &str = "id";
 const RESULT_KEY: &str = "result";
+const ERROR_KEY: &str = "error";
 // Wait 60 seconds max for a command response
 const RECEIVER_TIMEOUT: u64 = 60;
 
@@ -42,6 +46,22 @@ pub async fn send_command<T: Serialize, U: DeserializeOwned>(
     })?;
     debug!("Serialized command: {:?}", value);
 
+    // Catch drift between the models and the spec before the browser does. Methods
+    // missing from the bundled CDDL (extensions, or commands newer than the
+    // bundled spec) only get a warning, since the CDDL cannot describe them.
+    #[cfg(debug_assertions)]
+    {
+        let schema = Schema::remote();
+        if let Err(e) = schema.validate_command(&value) {
+            match value.get("method").and_then(Value::as_str) {
+                Some(method) if schema.has_method(method) => {
+                    panic!("Command does not conform to remote.cddl: {}: {}", e, value)
+                }
+                _ => warn!("Command does not conform to remote.cddl: {}: {}", e, value),
+            }
+        }
+    }
+
     let command_id = value
         .get(COMMAND_ID_KEY)
         .and_then(|id| id.as_u64())
@@ -50,6 +70,7 @@ pub async fn send_command<T: Serialize, U: DeserializeOwned>(
             CommandError::MissingCommandId
         })?;
 
+    let command_info = CommandInfo::from_command(command_id, &value);
     let message = Message::Text(value.to_string().into());
 
     let (sender, receiver) = oneshot::channel();
@@ -66,38 +87,73 @@ pub async fn send_command<T: Serialize, U: DeserializeOwned>(
         if let Err(e) = websocket_stream.send(message).await {
             error!("Error sending message: {:?}", e);
             pending_commands.lock().await.remove(&command_id);
-            return Err(CommandError::WebSocketSendError(e));
+            return Err(CommandError::WebSocketSendError {
+                command: command_info,
+                source: e,
+            });
         }
     }
 
     debug!("Awaiting a response for command id: {}", command_id);
 
     // Await the receiver to get the response with a timeout
-    let response = timeout(Duration::from_secs(RECEIVER_TIMEOUT), receiver)
-        .await
-        .map_err(|e| {
-            error!("Timeout waiting for response: {:?}", e);
-            CommandError::TimeoutError
-        })?
-        .map_err(|e| {
+    let response = match timeout(Duration::from_secs(RECEIVER_TIMEOUT), receiver).await {
+        Ok(Ok(response)) => response,
+        Ok(Err(e)) => {
             error!("Receiver error: {:?}:", e);
-            CommandError::OneshotReceiverError(e)
-        })?;
+            return Err(CommandError::OneshotReceiverError {
+                command: command_info,
+                source: e,
+            });
+        }
+        Err(e) => {
+            error!("Timeout waiting for response: {:?}", e);
+            pending_commands.lock().await.remove(&command_id);
+            return Err(CommandError::TimeoutError {
+                command: command_info,
+            });
+        }
+    };
 
     debug!("Received response: {:?}", response);
 
     let Some(rslt) = response.get(RESULT_KEY) else {
-        if response.get("error").is_some() {
+        if response.get(ERROR_KEY).is_some() {
             error!("Command returned error response: {:?}", response);
-            return Err(CommandError::Error(response));
+            return match serde_json::from_value::<ErrorResponse>(response.clone()) {
+                Ok(error_response) => Err(CommandError::Error {
+                    command: command_info,
+                    response: error_response,
+                }),
+                Err(e) => {
+                    error!("Deserialization error: {:?} for error response", e);
+                    Err(CommandError::DeserializationError {
+                        command: command_info,
+                        path: ERROR_KEY.to_string(),
+                        source: e,
+                        response,
+                    })
+                }
+            };
         } else {
             error!("Missing result in the response: {:?}", response);
-            return Err(CommandError::MissingResult);
+            return Err(CommandError::MissingResult {
+                command: command_info,
+                response,
+            });
         }
     };
-    let rslt = serde_json::from_value(rslt.to_owned()).map_err(|e| {
+    serde_path_to_error::deserialize(rslt).map_err(|e| {
         error!("Deserialization error: {:?} for JSON: {:?}", e, rslt);
-        CommandError::SerdeError(e)
-    })?;
-    Ok(rslt)
+        let path = match e.path().to_string().as_str() {
+            "." => RESULT_KEY.to_string(),
+            path => format!("{}.{}", RESULT_KEY, path),
+        };
+        CommandError::DeserializationError {
+            command: command_info,
+            path,
+            source: e.into_inner(),
+            response: response.clone(),
+        }
+    })
 }
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+// --------------------------------------------------
+
+use super::id;
+use super::utils;
+use crate::error::CommandError;
+use crate::local::result_data::EmptyResult;
+use crate::remote::bluetooth::*;
+use crate::session::WebDriverBiDiSession;
+
+// --------------------------------------------------
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-handlerequestdeviceprompt-command
+define_command!(
+    HandleRequestDevicePromptCommand,
+    HandleRequestDevicePrompt,
+    HandleRequestDevicePromptParameters,
+    handle_request_device_prompt,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateAdapter-command
+define_command!(
+    SimulateAdapterCommand,
+    SimulateAdapter,
+    SimulateAdapterParameters,
+    simulate_adapter,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-disableSimulation-command
+define_command!(
+    DisableSimulationCommand,
+    DisableSimulation,
+    DisableSimulationParameters,
+    disable_simulation,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateconnectedperipheral-command
+define_command!(
+    SimulatePreconnectedPeripheralCommand,
+    SimulatePreconnectedPeripheral,
+    SimulatePreconnectedPeripheralParameters,
+    simulate_preconnected_peripheral,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateadvertisement-command
+define_command!(
+    SimulateAdvertisementCommand,
+    SimulateAdvertisement,
+    SimulateAdvertisementParameters,
+    simulate_advertisement,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateGattConnectionResponse-command
+define_command!(
+    SimulateGattConnectionResponseCommand,
+    SimulateGattConnectionResponse,
+    SimulateGattConnectionResponseParameters,
+    simulate_gatt_connection_response,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateGattDisconnection-command
+define_command!(
+    SimulateGattDisconnectionCommand,
+    SimulateGattDisconnection,
+    SimulateGattDisconnectionParameters,
+    simulate_gatt_disconnection,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateService-command
+define_command!(
+    SimulateServiceCommand,
+    SimulateService,
+    SimulateServiceParameters,
+    simulate_service,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateCharacteristic-command
+define_command!(
+    SimulateCharacteristicCommand,
+    SimulateCharacteristic,
+    SimulateCharacteristicParameters,
+    simulate_characteristic,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateCharacteristicResponse-command
+define_command!(
+    SimulateCharacteristicResponseCommand,
+    SimulateCharacteristicResponse,
+    SimulateCharacteristicResponseParameters,
+    simulate_characteristic_response,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateDescriptor-command
+define_command!(
+    SimulateDescriptorCommand,
+    SimulateDescriptor,
+    SimulateDescriptorParameters,
+    simulate_descriptor,
+    EmptyResult
+);
+
+// https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateDescriptorResponse-command
+define_command!(
+    SimulateDescriptorResponseCommand,
+    SimulateDescriptorResponse,
+    SimulateDescriptorResponseParameters,
+    simulate_descriptor_response,
+    EmptyResult
+);
@@ -10,19 +10,18 @@ use crate::local::browser::ClientWindowInfo;
 use crate::local::browser::*;
 use crate::local::result_data::EmptyResult;
 use crate::remote::browser::*;
-use crate::remote::EmptyParams;
 use crate::session::WebDriverBiDiSession;
 
 // --------------------------------------------------
 
 // https://w3c.github.io/webdriver-bidi/#command-browser-close
-define_command!(CloseCommand, Close, EmptyParams, close, EmptyResult);
+define_command!(CloseCommand, Close, CloseParameters, close, EmptyResult);
 
 // https://w3c.github.io/webdriver-bidi/#command-browser-createUserContext
 define_command!(
     CreateUserContextCommand,
     CreateUserContext,
-    EmptyParams,
+    CreateUserContextParameters,
     create_user_context,
     CreateUserContextResult
 );
@@ -31,7 +30,7 @@ define_command!(
 define_command!(
     GetClientWindowsCommand,
     GetClientWindows,
-    EmptyParams,
+    GetClientWindowsParameters,
     get_client_windows,
     GetClientWindowsResult
 );
@@ -40,7 +39,7 @@ define_command!(
 define_command!(
     GetUserContextsCommand,
     GetUserContexts,
-    EmptyParams,
+    GetUserContextsParameters,
     get_user_contexts,
     GetUserContextsResult
 );
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+// --------------------------------------------------
+
+use super::id;
+use super::utils;
+use crate::error::CommandError;
+use crate::local::result_data::EmptyResult;
+use crate::remote::permissions::*;
+use crate::session::WebDriverBiDiSession;
+
+// --------------------------------------------------
+
+// https://w3c.github.io/permissions/#webdriver-bidi-command-permissions-setPermission
+define_command!(
+    SetPermissionCommand,
+    SetPermission,
+    SetPermissionParameters,
+    set_permission,
+    EmptyResult
+);
@@ -0,0 +1,38 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// --------------------------------------------------
+
+use super::id;
+use super::utils;
+use crate::error::CommandError;
+use crate::session::WebDriverBiDiSession;
+
+// --------------------------------------------------
+
+/// A command identified only by its method name, for methods that have no
+/// dedicated model such as vendor extensions or newly specified commands.
+#[derive(Debug, Serialize)]
+struct RawCommand<'a, P> {
+    id: u64,
+    method: &'a str,
+    params: P,
+}
+
+/// Sends a command with the given method name and parameters, allocating its ID.
+pub async fn call<P, R>(
+    session: &mut WebDriverBiDiSession,
+    method: &str,
+    params: P,
+) -> Result<R, CommandError>
+where
+    P: Serialize,
+    R: DeserializeOwned,
+{
+    let cmd = RawCommand {
+        id: id::get_next_id(),
+        method,
+        params,
+    };
+    utils::send_command(session, cmd).await
+}
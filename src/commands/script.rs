@@ -4,7 +4,6 @@ use serde::{Deserialize, Serialize};
 
 use super::id;
 use super::utils;
-use crate::define_command;
 use crate::error::CommandError;
 use crate::local::result_data::EmptyResult;
 use crate::local::script::EvaluateResult;
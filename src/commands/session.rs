@@ -9,19 +9,24 @@ use crate::error::CommandError;
 use crate::local::result_data::EmptyResult;
 use crate::local::session::*;
 use crate::remote::session::*;
-use crate::remote::EmptyParams;
 use crate::session::WebDriverBiDiSession;
 
 // --------------------------------------------------
 
 // https://w3c.github.io/webdriver-bidi/#command-session-status
-define_command!(StatusCommand, Status, EmptyParams, status, StatusResult);
+define_command!(
+    StatusCommand,
+    Status,
+    StatusParameters,
+    status,
+    StatusResult
+);
 
 // https://w3c.github.io/webdriver-bidi/#command-session-new
 define_command!(NewCommand, New, NewParameters, new, NewResult);
 
 // https://w3c.github.io/webdriver-bidi/#command-session-end
-define_command!(EndCommand, End, EmptyParams, end, EmptyResult);
+define_command!(EndCommand, End, EndParameters, end, EmptyResult);
 
 // https://w3c.github.io/webdriver-bidi/#command-session-subscribe
 define_command!(
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+// --------------------------------------------------
+
+use super::id;
+use super::utils;
+use crate::error::CommandError;
+use crate::local::result_data::EmptyResult;
+use crate::local::web_authn::*;
+use crate::remote::web_authn::*;
+use crate::session::WebDriverBiDiSession;
+
+// --------------------------------------------------
+
+// https://w3c.github.io/webauthn/#sctn-automation-add-virtual-authenticator
+define_command!(
+    AddVirtualAuthenticatorCommand,
+    AddVirtualAuthenticator,
+    AddVirtualAuthenticatorParameters,
+    add_virtual_authenticator,
+    AddVirtualAuthenticatorResult
+);
+
+// https://w3c.github.io/webauthn/#sctn-automation-remove-virtual-authenticator
+define_command!(
+    RemoveVirtualAuthenticatorCommand,
+    RemoveVirtualAuthenticator,
+    RemoveVirtualAuthenticatorParameters,
+    remove_virtual_authenticator,
+    EmptyResult
+);
+
+// https://w3c.github.io/webauthn/#sctn-automation-add-credential
+define_command!(
+    AddCredentialCommand,
+    AddCredential,
+    AddCredentialParameters,
+    add_credential,
+    EmptyResult
+);
+
+// https://w3c.github.io/webauthn/#sctn-automation-get-credentials
+define_command!(
+    GetCredentialsCommand,
+    GetCredentials,
+    GetCredentialsParameters,
+    get_credentials,
+    GetCredentialsResult
+);
+
+// https://w3c.github.io/webauthn/#sctn-automation-remove-credential
+define_command!(
+    RemoveCredentialCommand,
+    RemoveCredential,
+    RemoveCredentialParameters,
+    remove_credential,
+    EmptyResult
+);
+
+// https://w3c.github.io/webauthn/#sctn-automation-remove-all-credentials
+define_command!(
+    RemoveAllCredentialsCommand,
+    RemoveAllCredentials,
+    RemoveAllCredentialsParameters,
+    remove_all_credentials,
+    EmptyResult
+);
+
+// https://w3c.github.io/webauthn/#sctn-automation-set-user-verified
+define_command!(
+    SetUserVerifiedCommand,
+    SetUserVerified,
+    SetUserVerifiedParameters,
+    set_user_verified,
+    EmptyResult
+);
+
+// https://w3c.github.io/webauthn/#sctn-automation-set-credential-properties
+define_command!(
+    SetCredentialPropertiesCommand,
+    SetCredentialProperties,
+    SetCredentialPropertiesParameters,
+    set_credential_properties,
+    EmptyResult
+);
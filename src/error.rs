@@ -1,11 +1,66 @@
+use serde_json::Value;
+use std::fmt;
 use thiserror::Error;
 use tokio::sync::oneshot;
 use tokio_tungstenite::tungstenite;
 
+// --------------------------------------------------
+
+use crate::local::error_response::ErrorResponse;
+use crate::local::script::{ExceptionDetails, RemoteValue, StackTrace};
+use crate::local::ErrorCode;
+
+// Maximum number of characters of the params kept in a `CommandInfo`.
+const PARAMS_SNAPSHOT_MAX_LEN: usize = 256;
+
+/// Identifies the command an error relates to.
+#[derive(Debug, Clone)]
+pub struct CommandInfo {
+    /// The ID of the command.
+    pub id: u64,
+    /// The method name of the command, e.g. `browsingContext.navigate`.
+    pub method: String,
+    /// The serialized params of the command, truncated to a few hundred characters.
+    pub params: String,
+}
+
+impl CommandInfo {
+    /// Builds a `CommandInfo` from a serialized command.
+    pub(crate) fn from_command(id: u64, command: &Value) -> Self {
+        let method = command
+            .get("method")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let params = command
+            .get("params")
+            .map(|params| truncate(&params.to_string(), PARAMS_SNAPSHOT_MAX_LEN))
+            .unwrap_or_default();
+        Self { id, method, params }
+    }
+}
+
+impl fmt::Display for CommandInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "command {} `{}` with params {}",
+            self.id, self.method, self.params
+        )
+    }
+}
+
+fn truncate(s: &str, max_len: usize) -> String {
+    match s.char_indices().nth(max_len) {
+        Some((idx, _)) => format!("{}...", &s[..idx]),
+        None => s.to_string(),
+    }
+}
+
 /// Errors that can occur when sending a WebDriver command.
 #[derive(Error, Debug)]
 pub enum CommandError {
-    /// Error during JSON serialization/deserialization.
+    /// Error during JSON serialization of the command.
     #[error("Serialization error: {0}.")]
     SerdeError(#[from] serde_json::Error),
 
@@ -14,30 +69,78 @@ pub enum CommandError {
     MissingCommandId,
 
     /// Error when sending data over a WebSocket.
-    #[error("WebSocket send error: {0}.")]
-    WebSocketSendError(#[from] tungstenite::Error),
+    #[error("WebSocket send error for {command}: {source}.")]
+    WebSocketSendError {
+        command: CommandInfo,
+        source: tungstenite::Error,
+    },
 
     /// Missing result field in the response.
-    #[error("Missing result field.")]
-    MissingResult,
+    #[error("Missing result field for {command}. Response: {response}.")]
+    MissingResult {
+        command: CommandInfo,
+        response: Value,
+    },
+
+    /// Command returned an error response.
+    #[error("Command returned error for {command}: {response}")]
+    Error {
+        command: CommandInfo,
+        response: ErrorResponse,
+    },
 
-    // Command returned an error.
-    #[error("Command returned error: {0}")]
-    Error(serde_json::Value),
+    /// The result of the command could not be deserialized.
+    #[error("Deserialization error for {command} at `{path}`: {source}. Response: {response}.")]
+    DeserializationError {
+        command: CommandInfo,
+        /// The JSON path that failed, e.g. `result.contexts[0].clientWindow`.
+        path: String,
+        source: serde_json::Error,
+        /// The raw response.
+        response: Value,
+    },
 
     /// Timeout when waiting for a receiver response
-    #[error("Timeout waiting for receiver response")]
-    TimeoutError,
+    #[error("Timeout waiting for receiver response for {command}")]
+    TimeoutError { command: CommandInfo },
 
     /// Error when receiving a value from a one-shot channel.
-    #[error("Oneshot receiver error: {0}.")]
-    OneshotReceiverError(#[from] oneshot::error::RecvError),
+    #[error("Oneshot receiver error for {command}: {source}.")]
+    OneshotReceiverError {
+        command: CommandInfo,
+        source: oneshot::error::RecvError,
+    },
 
     /// Other command errors.
     #[error("Command error: {0}.")]
     Other(String),
 }
 
+impl CommandError {
+    /// Returns the protocol error code if the remote end returned an error response.
+    pub fn error_code(&self) -> Option<&ErrorCode> {
+        match self {
+            CommandError::Error { response, .. } => Some(&response.error),
+            _ => None,
+        }
+    }
+
+    /// Returns the command the error relates to, if it was sent.
+    pub fn command(&self) -> Option<&CommandInfo> {
+        match self {
+            CommandError::WebSocketSendError { command, .. }
+            | CommandError::MissingResult { command, .. }
+            | CommandError::Error { command, .. }
+            | CommandError::DeserializationError { command, .. }
+            | CommandError::TimeoutError { command }
+            | CommandError::OneshotReceiverError { command, .. } => Some(command),
+            CommandError::SerdeError(_)
+            | CommandError::MissingCommandId
+            | CommandError::Other(_) => None,
+        }
+    }
+}
+
 // --------------------------------------------------
 
 /// Errors that can occur when starting a WebDriver session.
@@ -55,3 +158,111 @@ pub enum SessionError {
     #[error("Session error: {0}.")]
     Other(String),
 }
+
+// --------------------------------------------------
+
+/// Errors that can occur when converting between script values and Rust types.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("Script value error: {0}.")]
+pub struct ValueError(pub String);
+
+impl serde::de::Error for ValueError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ValueError(msg.to_string())
+    }
+}
+
+impl serde::ser::Error for ValueError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ValueError(msg.to_string())
+    }
+}
+
+// --------------------------------------------------
+
+/// Errors that can occur when evaluating a script or calling a function
+/// through `WebDriverBiDiSession::eval` and `WebDriverBiDiSession::call_function`.
+#[derive(Error, Debug)]
+pub enum ScriptError {
+    /// The command could not be sent or returned an error response.
+    #[error(transparent)]
+    CommandError(Box<CommandError>),
+
+    /// The script threw an exception.
+    #[error("{}", format_exception(.0))]
+    Exception(Box<ExceptionDetails>),
+
+    /// The script returned a value that could not be converted to the requested type.
+    #[error(transparent)]
+    ValueError(#[from] ValueError),
+}
+
+impl From<CommandError> for ScriptError {
+    fn from(error: CommandError) -> Self {
+        ScriptError::CommandError(Box::new(error))
+    }
+}
+
+impl ScriptError {
+    /// Returns the thrown value if the script threw an exception.
+    pub fn exception(&self) -> Option<&RemoteValue> {
+        match self {
+            ScriptError::Exception(details) => Some(&details.exception),
+            _ => None,
+        }
+    }
+
+    /// Returns the exception message, e.g. `Error: boom`, if the script threw an exception.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            ScriptError::Exception(details) => Some(&details.text),
+            _ => None,
+        }
+    }
+
+    /// Returns the stack trace of the exception if the script threw one.
+    pub fn stack_trace(&self) -> Option<&StackTrace> {
+        match self {
+            ScriptError::Exception(details) => Some(&details.stack_trace),
+            _ => None,
+        }
+    }
+}
+
+// Line and column numbers are zero-based on the wire; they are printed one-based, as
+// browser consoles do.
+fn format_exception(details: &ExceptionDetails) -> String {
+    let mut out = format!(
+        "Script threw an exception at line {}, column {}: {}",
+        details.line_number + 1,
+        details.column_number + 1,
+        details.text
+    );
+    if !details.stack_trace.call_frames.is_empty() {
+        out.push_str(&format!("\n{}", details.stack_trace));
+    }
+    out
+}
+
+impl fmt::Display for StackTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, frame) in self.call_frames.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let function_name = match frame.function_name.as_str() {
+                "" => "<anonymous>",
+                name => name,
+            };
+            write!(
+                f,
+                "    at {} ({}:{}:{})",
+                function_name,
+                frame.url,
+                frame.line_number + 1,
+                frame.column_number + 1
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -29,6 +29,11 @@ pub enum EventType {
     ScriptRealmCreated,
     ScriptRealmDestroyed,
     LogEntryAdded,
+    InputFileDialogOpened,
+    BluetoothRequestDevicePromptUpdated,
+    BluetoothGattConnectionAttempted,
+    BluetoothCharacteristicEventGenerated,
+    BluetoothDescriptorEventGenerated,
 }
 
 /// Simple error type for parsing EventType.
@@ -72,6 +77,17 @@ impl FromStr for EventType {
             "script.realmCreated" => Ok(EventType::ScriptRealmCreated),
             "script.realmDestroyed" => Ok(EventType::ScriptRealmDestroyed),
             "log.entryAdded" => Ok(EventType::LogEntryAdded),
+            "input.fileDialogOpened" => Ok(EventType::InputFileDialogOpened),
+            "bluetooth.requestDevicePromptUpdated" => {
+                Ok(EventType::BluetoothRequestDevicePromptUpdated)
+            }
+            "bluetooth.gattConnectionAttempted" => Ok(EventType::BluetoothGattConnectionAttempted),
+            "bluetooth.characteristicEventGenerated" => {
+                Ok(EventType::BluetoothCharacteristicEventGenerated)
+            }
+            "bluetooth.descriptorEventGenerated" => {
+                Ok(EventType::BluetoothDescriptorEventGenerated)
+            }
             _ => Err(ParseEventTypeError),
         }
     }
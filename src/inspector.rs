@@ -0,0 +1,521 @@
+//! Lazy, navigable views of JavaScript object graphs.
+//!
+//! A serialized `RemoteValue` stops at `maxObjectDepth` and repeats an object that
+//! occurs more than once, e.g. in a cycle, as a bare `internalId`. An [`Inspector`]
+//! turns the value behind a [`JsHandle`] into a tree of [`InspectorNode`]s: back-references
+//! are resolved to the node they point to within the same serialization, and the children
+//! of truncated nodes are fetched on demand with `script.callFunction`. A fetched child
+//! that is one of its own ancestors becomes a back-reference to that ancestor.
+//!
+//! Nodes are located from the root by the index of each step among its parent's
+//! children, so children fetched after the page mutated the graph may be out of date.
+
+use std::collections::HashMap;
+use std::fmt;
+
+// --------------------------------------------------
+
+use crate::error::{ScriptError, ValueError};
+use crate::js_handle::JsHandle;
+use crate::local::script::{
+    InternalId, NodeRemoteValue, NumberOrSpecialNumber, PrimitiveProtocolValue, RemoteValue,
+    RemoteValueOrText, SpecialNumber,
+};
+use crate::remote::script::{LocalValue, SerializationOptions};
+use crate::remote::JsUint;
+
+// --------------------------------------------------
+
+// Walks `path` from `this`, following the same child order as the protocol serialization.
+// Returns the value along with, for each of its children, the index of the ancestor on
+// the path it is identical to or -1, since separate serializations share no `internalId`.
+const CHILD_FUNCTION: &str = r#"function (path) {
+    const children = (value) => {
+        if (value instanceof Map || value instanceof Set) {
+            return [...value.values()];
+        } else if (typeof Node !== "undefined" && value instanceof Node) {
+            return [...value.childNodes];
+        } else if (
+            Array.isArray(value) ||
+            (typeof NodeList !== "undefined" && value instanceof NodeList) ||
+            (typeof HTMLCollection !== "undefined" && value instanceof HTMLCollection)
+        ) {
+            return [...value];
+        }
+        return Object.values(value);
+    };
+    const ancestors = [this];
+    for (const index of path) {
+        ancestors.push(children(ancestors[ancestors.length - 1])[index]);
+    }
+    const value = ancestors[ancestors.length - 1];
+    return [value, children(value).map((child) => ancestors.indexOf(child))];
+}"#;
+
+// --------------------------------------------------
+
+/// Identifies a node within an [`Inspector`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+/// How a node is reached from its parent.
+#[derive(Debug)]
+pub enum NodeKey {
+    /// A property name or a string map key.
+    Name(String),
+    /// A position in an array, set, node list or the child nodes of a DOM node.
+    Index(usize),
+    /// A map key that is not a string.
+    Value(RemoteValue),
+}
+
+impl fmt::Display for NodeKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeKey::Name(name) => write!(f, "{}", name),
+            NodeKey::Index(index) => write!(f, "{}", index),
+            NodeKey::Value(value) => write!(f, "{}", summary(value)),
+        }
+    }
+}
+
+/// A value in the inspected graph.
+#[derive(Debug)]
+pub struct InspectorNode {
+    key: Option<NodeKey>,
+    value: RemoteValue,
+    parent: Option<NodeId>,
+    reference: Option<NodeId>,
+    children: Option<Vec<NodeId>>,
+}
+
+impl InspectorNode {
+    /// Returns how the node is reached from its parent, or `None` for the root.
+    pub fn key(&self) -> Option<&NodeKey> {
+        self.key.as_ref()
+    }
+
+    /// Returns the value of the node, without the children that are nodes of their own.
+    pub fn value(&self) -> &RemoteValue {
+        &self.value
+    }
+
+    /// Returns the parent of the node, or `None` for the root.
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    /// Returns the node this one is a back-reference to, if the value was serialized as
+    /// a bare `internalId`.
+    pub fn reference(&self) -> Option<NodeId> {
+        self.reference
+    }
+
+    /// Returns the children of the node, or `None` if they have not been fetched yet.
+    pub fn children(&self) -> Option<&[NodeId]> {
+        self.children.as_deref()
+    }
+
+    /// Returns a one-line description of the value, e.g. `"text"`, `Array` or `<div>`.
+    pub fn summary(&self) -> String {
+        summary(&self.value)
+    }
+}
+
+/// A lazily expanded tree over the object graph behind a [`JsHandle`].
+///
+/// ```no_run
+/// # use webdriverbidi::inspector::Inspector;
+/// # async fn example(
+/// #     session: &mut webdriverbidi::session::WebDriverBiDiSession,
+/// #     context: webdriverbidi::ids::BrowsingContext,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let handle = session.eval_handle(context, "window.store").await?;
+/// let mut inspector = Inspector::new(handle)?;
+/// for child in inspector.children(inspector.root()).await? {
+///     inspector.children(child).await?;
+/// }
+/// println!("{}", inspector);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Inspector {
+    root: JsHandle,
+    nodes: Vec<InspectorNode>,
+    max_depth: JsUint,
+}
+
+impl Inspector {
+    /// Creates an inspector over an object, map or DOM node.
+    ///
+    /// The tree starts with whatever the handle's value was serialized with.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The handle to the value to inspect.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `Inspector`, or a `ValueError` if the value is of
+    /// another type.
+    pub fn new(root: JsHandle) -> Result<Self, ValueError> {
+        if !matches!(
+            root.value(),
+            RemoteValue::ObjectRemoteValue(_)
+                | RemoteValue::MapRemoteValue(_)
+                | RemoteValue::NodeRemoteValue(_)
+        ) {
+            return Err(ValueError(format!(
+                "cannot inspect a value of type `{}`, expected an object, a map or a node",
+                summary(root.value())
+            )));
+        }
+        let value = serde_json::to_value(root.value())
+            .and_then(serde_json::from_value)
+            .map_err(|e| ValueError(e.to_string()))?;
+        let mut inspector = Self {
+            root,
+            nodes: Vec::new(),
+            max_depth: 1,
+        };
+        let mut ids = HashMap::new();
+        let mut references = Vec::new();
+        inspector.insert(None, None, value, &mut ids, &mut references);
+        inspector.resolve_references(&ids, references);
+        Ok(inspector)
+    }
+
+    /// Sets how many levels below a node are serialized when its children are fetched.
+    ///
+    /// Defaults to 1, i.e. each fetch returns the children without their own contents.
+    pub fn max_depth(mut self, max_depth: JsUint) -> Self {
+        self.max_depth = max_depth.max(1);
+        self
+    }
+
+    /// Returns the root node.
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    /// Returns a node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` belongs to another inspector.
+    pub fn node(&self, id: NodeId) -> &InspectorNode {
+        &self.nodes[id.0]
+    }
+
+    /// Follows back-references until a node that holds its own value.
+    pub fn resolve(&self, mut id: NodeId) -> NodeId {
+        while let Some(reference) = self.node(id).reference {
+            id = reference;
+        }
+        id
+    }
+
+    /// Returns the child indices leading from the root to a node.
+    pub fn path(&self, id: NodeId) -> Vec<usize> {
+        let mut path = Vec::new();
+        let mut current = id;
+        while let Some(parent) = self.node(current).parent {
+            let index = self
+                .node(parent)
+                .children()
+                .and_then(|children| children.iter().position(|&child| child == current))
+                .expect("a node is one of its parent's children");
+            path.push(index);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the children of a node, fetching them first if needed.
+    ///
+    /// Back-references return the children of the node they point to.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The node to expand.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the children, or a `ScriptError`.
+    pub async fn children(&mut self, id: NodeId) -> Result<Vec<NodeId>, ScriptError> {
+        let id = self.resolve(id);
+        if self.node(id).children.is_none() {
+            self.fetch(id).await?;
+        }
+        Ok(self.node(id).children.clone().unwrap_or_default())
+    }
+
+    async fn fetch(&mut self, id: NodeId) -> Result<(), ScriptError> {
+        let path = LocalValue::from_serialize(&self.path(id))?;
+        // One more level for the array wrapping the value and its cycles.
+        let options =
+            SerializationOptions::new(Some(self.max_depth), Some(self.max_depth + 1), None);
+        let result = self
+            .root
+            .call_on_this(CHILD_FUNCTION, vec![path], Some(options))
+            .await?;
+        let (mut value, cycles) = match result {
+            RemoteValue::ArrayRemoteValue(array) => match array.value.map(<[_; 2]>::try_from) {
+                Some(Ok([value, cycles])) => (value, cycles.into::<Vec<i64>>()?),
+                _ => return Err(ValueError("malformed child serialization".into()).into()),
+            },
+            _ => return Err(ValueError("malformed child serialization".into()).into()),
+        };
+
+        let mut ids = HashMap::new();
+        let mut references = Vec::new();
+        if let Some(internal_id) = value.internal_id() {
+            ids.insert(internal_id.clone(), id);
+        }
+        let children: Vec<NodeId> = take_children(&mut value)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, child)| self.insert(Some(key), Some(id), child, &mut ids, &mut references))
+            .collect();
+        let ancestors = self.ancestors(id);
+        for (&child, &cycle) in children.iter().zip(&cycles) {
+            if let Some(&target) = usize::try_from(cycle).ok().and_then(|i| ancestors.get(i)) {
+                let node = &mut self.nodes[child.0];
+                node.reference = Some(target);
+                node.children = None;
+            }
+        }
+        self.nodes[id.0].value = value;
+        self.nodes[id.0].children = Some(children);
+        self.resolve_references(&ids, references);
+        Ok(())
+    }
+
+    // The nodes from the root down to and including `id`.
+    fn ancestors(&self, id: NodeId) -> Vec<NodeId> {
+        let mut ancestors = vec![id];
+        let mut current = id;
+        while let Some(parent) = self.node(current).parent {
+            ancestors.push(parent);
+            current = parent;
+        }
+        ancestors.reverse();
+        ancestors
+    }
+
+    fn insert(
+        &mut self,
+        key: Option<NodeKey>,
+        parent: Option<NodeId>,
+        mut value: RemoteValue,
+        ids: &mut HashMap<InternalId, NodeId>,
+        references: &mut Vec<(NodeId, InternalId)>,
+    ) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        let entries = take_children(&mut value);
+        match (value.internal_id(), &entries) {
+            // The first occurrence carries the contents, later ones only the ID.
+            (Some(internal_id), Some(_)) => {
+                ids.entry(internal_id.clone()).or_insert(id);
+            }
+            (Some(internal_id), None) => references.push((id, internal_id.clone())),
+            (None, _) => {}
+        }
+        let children = if is_container(&value) {
+            entries.as_ref().map(|_| Vec::new())
+        } else {
+            Some(Vec::new())
+        };
+        self.nodes.push(InspectorNode {
+            key,
+            value,
+            parent,
+            reference: None,
+            children,
+        });
+
+        if let Some(entries) = entries {
+            let children = entries
+                .into_iter()
+                .map(|(key, child)| self.insert(Some(key), Some(id), child, ids, references))
+                .collect();
+            self.nodes[id.0].children = Some(children);
+        }
+        id
+    }
+
+    fn resolve_references(
+        &mut self,
+        ids: &HashMap<InternalId, NodeId>,
+        references: Vec<(NodeId, InternalId)>,
+    ) {
+        for (id, internal_id) in references {
+            if let Some(&target) = ids.get(&internal_id) {
+                let node = &mut self.nodes[id.0];
+                node.reference = Some(target);
+                node.children = None;
+            }
+        }
+    }
+
+    fn is_ancestor(&self, ancestor: NodeId, mut id: NodeId) -> bool {
+        while let Some(parent) = self.node(id).parent {
+            if parent == ancestor {
+                return true;
+            }
+            id = parent;
+        }
+        false
+    }
+
+    fn render(&self, f: &mut fmt::Formatter<'_>, id: NodeId, depth: usize) -> fmt::Result {
+        let node = self.node(id);
+        write!(f, "{:indent$}", "", indent = depth * 2)?;
+        if let Some(key) = &node.key {
+            write!(f, "{}: ", key)?;
+        }
+        match (node.reference, &node.children) {
+            (Some(target), _) if self.is_ancestor(target, id) => {
+                writeln!(f, "[Circular {}]", self.node(target).summary())
+            }
+            (Some(target), _) => writeln!(f, "[Ref {}]", self.node(target).summary()),
+            (None, None) => writeln!(f, "{} …", node.summary()),
+            (None, Some(children)) => {
+                writeln!(f, "{}", node.summary())?;
+                children
+                    .iter()
+                    .try_for_each(|&child| self.render(f, child, depth + 1))
+            }
+        }
+    }
+}
+
+/// Renders the nodes fetched so far as an indented tree, one node per line.
+///
+/// Nodes whose children have not been fetched end with `…`. Back-references are shown
+/// as `[Circular …]` when they point to an ancestor and as `[Ref …]` otherwise.
+impl fmt::Display for Inspector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, self.root(), 0)
+    }
+}
+
+// --------------------------------------------------
+
+fn is_container(value: &RemoteValue) -> bool {
+    matches!(
+        value,
+        RemoteValue::ObjectRemoteValue(_)
+            | RemoteValue::ArrayRemoteValue(_)
+            | RemoteValue::MapRemoteValue(_)
+            | RemoteValue::SetRemoteValue(_)
+            | RemoteValue::NodeListRemoteValue(_)
+            | RemoteValue::HTMLCollectionRemoteValue(_)
+            | RemoteValue::NodeRemoteValue(_)
+    )
+}
+
+// Moves the serialized children out of a container, or returns `None` if they were cut off.
+fn take_children(value: &mut RemoteValue) -> Option<Vec<(NodeKey, RemoteValue)>> {
+    let indexed = |list: Vec<RemoteValue>| {
+        list.into_iter()
+            .enumerate()
+            .map(|(index, value)| (NodeKey::Index(index), value))
+            .collect()
+    };
+    match value {
+        RemoteValue::ObjectRemoteValue(v) => v.value.take().map(keyed),
+        RemoteValue::MapRemoteValue(v) => v.value.take().map(keyed),
+        RemoteValue::ArrayRemoteValue(v) => v.value.take().map(indexed),
+        RemoteValue::SetRemoteValue(v) => v.value.take().map(indexed),
+        RemoteValue::NodeListRemoteValue(v) => v.value.take().map(indexed),
+        RemoteValue::HTMLCollectionRemoteValue(v) => v.value.take().map(indexed),
+        RemoteValue::NodeRemoteValue(v) => {
+            let properties = v.value.as_mut()?;
+            match properties.children.take() {
+                Some(children) => Some(indexed(
+                    children
+                        .into_iter()
+                        .map(RemoteValue::NodeRemoteValue)
+                        .collect(),
+                )),
+                None if properties.child_node_count == 0 => Some(Vec::new()),
+                None => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn keyed(entries: Vec<(RemoteValueOrText, RemoteValue)>) -> Vec<(NodeKey, RemoteValue)> {
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            let key = match key {
+                RemoteValueOrText::Text(name) => NodeKey::Name(name),
+                RemoteValueOrText::RemoteValue(key) => NodeKey::Value(key),
+            };
+            (key, value)
+        })
+        .collect()
+}
+
+fn summary(value: &RemoteValue) -> String {
+    match value {
+        RemoteValue::PrimitiveProtocolValue(primitive) => match primitive {
+            PrimitiveProtocolValue::StringValue(v) => format!("{:?}", v.value),
+            PrimitiveProtocolValue::NumberValue(v) => match &v.value {
+                NumberOrSpecialNumber::Number(n) => n.to_string(),
+                NumberOrSpecialNumber::SpecialNumber(special) => match special {
+                    SpecialNumber::NaN => "NaN".to_string(),
+                    SpecialNumber::NegativeZero => "-0".to_string(),
+                    SpecialNumber::Infinity => "Infinity".to_string(),
+                    SpecialNumber::NegativeInfinity => "-Infinity".to_string(),
+                    SpecialNumber::Unknown(other) => other.clone(),
+                },
+            },
+            PrimitiveProtocolValue::BooleanValue(v) => v.value.to_string(),
+            PrimitiveProtocolValue::BigIntValue(v) => format!("{}n", v.value),
+            PrimitiveProtocolValue::NullValue(_) => "null".to_string(),
+            PrimitiveProtocolValue::UndefinedValue(_) => "undefined".to_string(),
+            PrimitiveProtocolValue::Unknown { value_type, .. } => value_type.clone(),
+        },
+        RemoteValue::SymbolRemoteValue(_) => "Symbol".to_string(),
+        RemoteValue::ArrayRemoteValue(_) => "Array".to_string(),
+        RemoteValue::ObjectRemoteValue(_) => "Object".to_string(),
+        RemoteValue::FunctionRemoteValue(_) => "Function".to_string(),
+        RemoteValue::RegExpRemoteValue(v) => format!("/{}/{}", v.value.pattern, {
+            v.value.flags.as_deref().unwrap_or_default()
+        }),
+        RemoteValue::DateRemoteValue(v) => format!("Date {}", v.value),
+        RemoteValue::MapRemoteValue(_) => "Map".to_string(),
+        RemoteValue::SetRemoteValue(_) => "Set".to_string(),
+        RemoteValue::WeakMapRemoteValue(_) => "WeakMap".to_string(),
+        RemoteValue::WeakSetRemoteValue(_) => "WeakSet".to_string(),
+        RemoteValue::GeneratorRemoteValue(_) => "Generator".to_string(),
+        RemoteValue::ErrorRemoteValue(_) => "Error".to_string(),
+        RemoteValue::ProxyRemoteValue(_) => "Proxy".to_string(),
+        RemoteValue::PromiseRemoteValue(_) => "Promise".to_string(),
+        RemoteValue::TypedArrayRemoteValue(_) => "TypedArray".to_string(),
+        RemoteValue::ArrayBufferRemoteValue(_) => "ArrayBuffer".to_string(),
+        RemoteValue::NodeListRemoteValue(_) => "NodeList".to_string(),
+        RemoteValue::HTMLCollectionRemoteValue(_) => "HTMLCollection".to_string(),
+        RemoteValue::NodeRemoteValue(node) => node_summary(node),
+        RemoteValue::WindowProxyRemoteValue(v) => format!("Window {}", v.value.context),
+        RemoteValue::Unknown { value_type, .. } => value_type.clone(),
+    }
+}
+
+fn node_summary(node: &NodeRemoteValue) -> String {
+    let Some(properties) = &node.value else {
+        return "Node".to_string();
+    };
+    match (&properties.local_name, &properties.node_value) {
+        (Some(local_name), _) => format!("<{}>", local_name),
+        (None, Some(node_value)) => format!("#text {:?}", node_value),
+        (None, None) if properties.node_type == 9 => "#document".to_string(),
+        (None, None) => "Node".to_string(),
+    }
+}
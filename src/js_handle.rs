@@ -0,0 +1,209 @@
+//! Handles to JavaScript objects that release themselves when dropped.
+//!
+//! Values returned with `ResultOwnership::Root` are kept alive by the page until their
+//! handle is disowned. A [`JsHandle`] owns such a handle: dropping it queues the handle
+//! on its session, and the queue is sent as one `script.disown` command per realm.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+// --------------------------------------------------
+
+use crate::error::{ScriptError, ValueError};
+use crate::ids::{Handle, Realm};
+use crate::local::script::RemoteValue;
+use crate::remote::script::{
+    CallFunctionParameters, LocalValue, RealmTarget, RemoteObjectReference, RemoteReference,
+    ResultOwnership, SerializationOptions, Target,
+};
+use crate::remote::Extensible;
+use crate::session::{evaluate_result_success, WebDriverBiDiSession};
+
+// --------------------------------------------------
+
+/// The handles of dropped `JsHandle`s awaiting `script.disown`, per realm.
+pub(crate) type DisownQueue = Arc<Mutex<HashMap<Realm, Vec<Handle>>>>;
+
+const GET_PROPERTY_FUNCTION: &str = "function (name) { return this[name]; }";
+
+const CALL_METHOD_FUNCTION: &str = "function (name, ...args) { return this[name](...args); }";
+
+const SELF_FUNCTION: &str = "function () { return this; }";
+
+// --------------------------------------------------
+
+/// An owned handle to a JavaScript object in a realm.
+///
+/// The handle is disowned when the `JsHandle` is dropped. Within a Tokio runtime this
+/// happens shortly after in the background; otherwise it happens on the next call to
+/// `WebDriverBiDiSession::flush_disowns`.
+pub struct JsHandle {
+    session: WebDriverBiDiSession,
+    realm: Realm,
+    handle: Handle,
+    value: RemoteValue,
+}
+
+impl JsHandle {
+    /// Wraps a value returned with `ResultOwnership::Root` in `realm`.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The session the value was returned on.
+    /// * `realm` - The realm the value lives in.
+    /// * `value` - The value, which must carry a handle.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `JsHandle`, or a `ValueError` if the value has no handle.
+    pub fn new(
+        session: WebDriverBiDiSession,
+        realm: Realm,
+        value: RemoteValue,
+    ) -> Result<Self, ValueError> {
+        let handle = value.handle().cloned().ok_or_else(|| {
+            ValueError("the value has no handle, request it with ResultOwnership::Root".into())
+        })?;
+        Ok(Self {
+            session,
+            realm,
+            handle,
+            value,
+        })
+    }
+
+    /// Returns the handle of the object.
+    pub fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// Returns the realm the object lives in.
+    pub fn realm(&self) -> &Realm {
+        &self.realm
+    }
+
+    /// Returns the value the object was serialized as when the handle was created.
+    pub fn value(&self) -> &RemoteValue {
+        &self.value
+    }
+
+    /// Returns a reference to the object, to pass it as an argument or as `this`.
+    ///
+    /// The reference is only valid while the `JsHandle` is alive.
+    pub fn to_local_value(&self) -> LocalValue {
+        LocalValue::RemoteReference(RemoteReference::RemoteObjectReference(
+            RemoteObjectReference::new(self.handle.clone(), None, Extensible::new()),
+        ))
+    }
+
+    /// Reads a property of the object, i.e. `this[name]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the value of the property as a `T`, or a `ScriptError`.
+    pub async fn get_property<T: DeserializeOwned>(&self, name: &str) -> Result<T, ScriptError> {
+        let value = self
+            .call_on_this(GET_PROPERTY_FUNCTION, vec![name.into()], None)
+            .await?;
+        Ok(value.into()?)
+    }
+
+    /// Calls a method of the object, i.e. `this[name](...args)`.
+    ///
+    /// Promises are awaited.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method.
+    /// * `arguments` - The arguments of the method, see `LocalValue::from_serialize`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the return value of the method as a `T`, or a `ScriptError`.
+    pub async fn call_method<T: DeserializeOwned>(
+        &self,
+        name: &str,
+        arguments: Vec<LocalValue>,
+    ) -> Result<T, ScriptError> {
+        let mut all_arguments = Vec::with_capacity(arguments.len() + 1);
+        all_arguments.push(name.into());
+        all_arguments.extend(arguments);
+        let value = self
+            .call_on_this(CALL_METHOD_FUNCTION, all_arguments, None)
+            .await?;
+        Ok(value.into()?)
+    }
+
+    /// Serializes the current state of the object to JSON, see `RemoteValue::to_json`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the JSON value, or a `ScriptError`.
+    pub async fn json_value(&self) -> Result<Value, ScriptError> {
+        let value = self.call_on_this(SELF_FUNCTION, vec![], None).await?;
+        Ok(value.to_json())
+    }
+
+    /// Disowns the handle immediately instead of waiting for the batched flush.
+    ///
+    /// # Returns
+    ///
+    /// A result containing `()` or a `ScriptError`.
+    pub async fn dispose(self) -> Result<(), ScriptError> {
+        let mut session = self.session.clone();
+        drop(self);
+        session.flush_disowns().await?;
+        Ok(())
+    }
+
+    /// Calls `function_declaration` in the object's realm with the object as `this`.
+    pub(crate) async fn call_on_this(
+        &self,
+        function_declaration: &str,
+        arguments: Vec<LocalValue>,
+        serialization_options: Option<SerializationOptions>,
+    ) -> Result<RemoteValue, ScriptError> {
+        let mut builder = CallFunctionParameters::builder(
+            function_declaration.to_string(),
+            true,
+            Target::RealmTarget(RealmTarget::new(self.realm.clone())),
+        )
+        .this(self.to_local_value())
+        .arguments(arguments)
+        .result_ownership(ResultOwnership::None);
+        if let Some(serialization_options) = serialization_options {
+            builder = builder.serialization_options(serialization_options);
+        }
+        let result = self
+            .session
+            .clone()
+            .script_call_function(builder.build())
+            .await?;
+        Ok(evaluate_result_success(result)?.result)
+    }
+}
+
+impl fmt::Debug for JsHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsHandle")
+            .field("realm", &self.realm)
+            .field("handle", &self.handle)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Drop for JsHandle {
+    fn drop(&mut self) {
+        self.session
+            .queue_disown(self.realm.clone(), self.handle.clone());
+    }
+}
@@ -4,8 +4,16 @@ pub mod webdriver {
 }
 pub mod session;
 pub mod models {
+    #[cfg(feature = "num-bigint")]
+    mod bigint;
+    mod conversions;
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    mod dates;
+    mod de;
+    pub mod ids;
     pub mod local;
     pub mod remote;
+    mod ser;
 }
 mod commands {
     pub mod browser;
@@ -14,17 +22,33 @@ mod commands {
     pub mod session;
     #[macro_use]
     mod utils;
+    pub mod bluetooth;
     pub mod input;
     pub mod network;
+    pub mod permissions;
+    pub mod raw;
     pub mod script;
     pub mod storage;
+    pub mod web_authn;
     pub mod web_extension;
 }
+pub mod cddl {
+    mod parser;
+    mod validator;
+
+    pub use parser::*;
+    pub use validator::*;
+}
+mod binding;
+pub mod channel;
 mod command_sender;
 pub mod error;
 pub mod events;
+pub mod inspector;
+pub mod js_handle;
 mod message_handler;
 
 // Re-export key structs and enums
+pub use models::ids;
 pub use models::local;
 pub use models::remote;
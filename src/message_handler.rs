@@ -16,6 +16,7 @@ use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 
 // --------------------------------------------------
 
+use crate::channel::ChannelRouter;
 use crate::events::EventType;
 use crate::session::EventHandler;
 
@@ -33,6 +34,7 @@ pub async fn handle_messages(
     websocket_stream: Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>,
     pending_commands: Arc<Mutex<HashMap<u64, oneshot::Sender<Value>>>>,
     event_handlers: Arc<Mutex<HashMap<EventType, EventHandler>>>,
+    channels: ChannelRouter,
 ) {
     loop {
         let message = {
@@ -71,6 +73,9 @@ pub async fn handle_messages(
                             json.get(METHOD_FIELD).and_then(|method| method.as_str())
                         {
                             if let Ok(event_type) = EventType::from_str(event_type_str) {
+                                if event_type == EventType::ScriptMessage {
+                                    channels.route(&json);
+                                }
                                 let event_handlers = Arc::clone(&event_handlers);
                                 let json = json.clone();
                                 tokio::spawn(async move {
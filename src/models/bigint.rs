@@ -0,0 +1,30 @@
+//! Conversions between script BigInts and `num_bigint::BigInt`.
+
+use num_bigint::BigInt;
+
+use crate::error::ValueError;
+use crate::local::script as l;
+use crate::remote::script as r;
+
+// --------------------------------------------------
+
+impl l::BigIntValue {
+    /// Parses the value as a `num_bigint::BigInt`.
+    pub fn to_bigint(&self) -> Result<BigInt, ValueError> {
+        self.value
+            .parse()
+            .map_err(|e| ValueError(format!("invalid BigInt `{}`: {}", self.value, e)))
+    }
+}
+
+impl From<BigInt> for r::BigIntValue {
+    fn from(value: BigInt) -> Self {
+        r::BigIntValue::new(value.to_string())
+    }
+}
+
+impl From<BigInt> for r::LocalValue {
+    fn from(value: BigInt) -> Self {
+        r::LocalValue::PrimitiveProtocolValue(r::PrimitiveProtocolValue::BigIntValue(value.into()))
+    }
+}
@@ -0,0 +1,661 @@
+//! Conversions between the `local` and `remote` models.
+//!
+//! Values received from the remote end (e.g. the result of `script.evaluate`) can be
+//! converted into the corresponding `remote` types and passed back as command arguments,
+//! and vice versa.
+
+use crate::error::ValueError;
+use crate::local;
+use crate::remote;
+
+use local::script as l;
+use local::session as ls;
+use remote::script as r;
+use remote::session as rs;
+
+// --------------------------------------------------
+
+// Implements `From` in both directions for enums with identical unit variants
+// and an `Unknown(String)` fallback.
+macro_rules! convert_enum {
+    ($name:ident, [$($variant:ident),* $(,)?]) => {
+        impl From<l::$name> for r::$name {
+            fn from(value: l::$name) -> Self {
+                match value {
+                    $(l::$name::$variant => r::$name::$variant,)*
+                    l::$name::Unknown(value) => r::$name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<r::$name> for l::$name {
+            fn from(value: r::$name) -> Self {
+                match value {
+                    $(r::$name::$variant => l::$name::$variant,)*
+                    r::$name::Unknown(value) => l::$name::Unknown(value),
+                }
+            }
+        }
+    };
+}
+
+convert_enum!(
+    SpecialNumber,
+    [NaN, NegativeZero, Infinity, NegativeInfinity]
+);
+
+convert_enum!(
+    RealmType,
+    [
+        Window,
+        DedicatedWorker,
+        SharedWorker,
+        ServiceWorker,
+        Worker,
+        PaintWorklet,
+        AudioWorklet,
+        Worklet,
+    ]
+);
+
+convert_enum!(ResultOwnership, [Root, None]);
+
+convert_enum!(IncludeShadowTree, [None, Open, All]);
+
+// --------------------------------------------------
+
+impl From<l::NumberOrSpecialNumber> for r::NumberOrSpecialNumber {
+    fn from(value: l::NumberOrSpecialNumber) -> Self {
+        match value {
+            l::NumberOrSpecialNumber::Number(n) => r::NumberOrSpecialNumber::Number(n),
+            l::NumberOrSpecialNumber::SpecialNumber(n) => {
+                r::NumberOrSpecialNumber::SpecialNumber(n.into())
+            }
+        }
+    }
+}
+
+impl From<r::NumberOrSpecialNumber> for l::NumberOrSpecialNumber {
+    fn from(value: r::NumberOrSpecialNumber) -> Self {
+        match value {
+            r::NumberOrSpecialNumber::Number(n) => l::NumberOrSpecialNumber::Number(n),
+            r::NumberOrSpecialNumber::SpecialNumber(n) => {
+                l::NumberOrSpecialNumber::SpecialNumber(n.into())
+            }
+        }
+    }
+}
+
+/// Fails with the value unchanged when it is of a type this crate does not know.
+impl TryFrom<l::PrimitiveProtocolValue> for r::PrimitiveProtocolValue {
+    type Error = l::PrimitiveProtocolValue;
+
+    fn try_from(value: l::PrimitiveProtocolValue) -> Result<Self, Self::Error> {
+        let value = match value {
+            l::PrimitiveProtocolValue::UndefinedValue(_) => {
+                r::PrimitiveProtocolValue::UndefinedValue(r::UndefinedValue::new())
+            }
+            l::PrimitiveProtocolValue::NullValue(_) => {
+                r::PrimitiveProtocolValue::NullValue(r::NullValue::new())
+            }
+            l::PrimitiveProtocolValue::StringValue(v) => {
+                r::PrimitiveProtocolValue::StringValue(r::StringValue::new(v.value))
+            }
+            l::PrimitiveProtocolValue::NumberValue(v) => {
+                r::PrimitiveProtocolValue::NumberValue(r::NumberValue::new(v.value.into()))
+            }
+            l::PrimitiveProtocolValue::BooleanValue(v) => {
+                r::PrimitiveProtocolValue::BooleanValue(r::BooleanValue::new(v.value))
+            }
+            l::PrimitiveProtocolValue::BigIntValue(v) => {
+                r::PrimitiveProtocolValue::BigIntValue(r::BigIntValue::new(v.value))
+            }
+            unknown @ l::PrimitiveProtocolValue::Unknown { .. } => return Err(unknown),
+        };
+        Ok(value)
+    }
+}
+
+impl From<r::PrimitiveProtocolValue> for l::PrimitiveProtocolValue {
+    fn from(value: r::PrimitiveProtocolValue) -> Self {
+        match value {
+            r::PrimitiveProtocolValue::UndefinedValue(_) => {
+                l::PrimitiveProtocolValue::UndefinedValue(l::UndefinedValue {
+                    value_type: "undefined".to_string(),
+                })
+            }
+            r::PrimitiveProtocolValue::NullValue(_) => {
+                l::PrimitiveProtocolValue::NullValue(l::NullValue {
+                    value_type: "null".to_string(),
+                })
+            }
+            r::PrimitiveProtocolValue::StringValue(v) => {
+                l::PrimitiveProtocolValue::StringValue(l::StringValue {
+                    value_type: "string".to_string(),
+                    value: v.value,
+                })
+            }
+            r::PrimitiveProtocolValue::NumberValue(v) => {
+                l::PrimitiveProtocolValue::NumberValue(l::NumberValue {
+                    value_type: "number".to_string(),
+                    value: v.value.into(),
+                })
+            }
+            r::PrimitiveProtocolValue::BooleanValue(v) => {
+                l::PrimitiveProtocolValue::BooleanValue(l::BooleanValue {
+                    value_type: "boolean".to_string(),
+                    value: v.value,
+                })
+            }
+            r::PrimitiveProtocolValue::BigIntValue(v) => {
+                l::PrimitiveProtocolValue::BigIntValue(l::BigIntValue {
+                    value_type: "bigint".to_string(),
+                    value: v.value,
+                })
+            }
+        }
+    }
+}
+
+// --------------------------------------------------
+
+impl From<l::SharedReference> for r::SharedReference {
+    fn from(value: l::SharedReference) -> Self {
+        r::SharedReference::new(value.shared_id, value.handle, value.extensible)
+    }
+}
+
+impl From<r::SharedReference> for l::SharedReference {
+    fn from(value: r::SharedReference) -> Self {
+        l::SharedReference {
+            shared_id: value.shared_id,
+            handle: value.handle,
+            extensible: value.extensible,
+        }
+    }
+}
+
+impl From<l::RemoteObjectReference> for r::RemoteObjectReference {
+    fn from(value: l::RemoteObjectReference) -> Self {
+        r::RemoteObjectReference::new(value.handle, value.shared_id, value.extensible)
+    }
+}
+
+impl From<r::RemoteObjectReference> for l::RemoteObjectReference {
+    fn from(value: r::RemoteObjectReference) -> Self {
+        l::RemoteObjectReference {
+            handle: value.handle,
+            shared_id: value.shared_id,
+            extensible: value.extensible,
+        }
+    }
+}
+
+impl From<l::RemoteReference> for r::RemoteReference {
+    fn from(value: l::RemoteReference) -> Self {
+        match value {
+            l::RemoteReference::SharedReference(v) => r::RemoteReference::SharedReference(v.into()),
+            l::RemoteReference::RemoteObjectReference(v) => {
+                r::RemoteReference::RemoteObjectReference(v.into())
+            }
+        }
+    }
+}
+
+impl From<r::RemoteReference> for l::RemoteReference {
+    fn from(value: r::RemoteReference) -> Self {
+        match value {
+            r::RemoteReference::SharedReference(v) => l::RemoteReference::SharedReference(v.into()),
+            r::RemoteReference::RemoteObjectReference(v) => {
+                l::RemoteReference::RemoteObjectReference(v.into())
+            }
+        }
+    }
+}
+
+impl From<l::RemoteReference> for r::LocalValue {
+    fn from(value: l::RemoteReference) -> Self {
+        r::LocalValue::RemoteReference(value.into())
+    }
+}
+
+// --------------------------------------------------
+
+impl From<l::RegExpValue> for r::RegExpValue {
+    fn from(value: l::RegExpValue) -> Self {
+        r::RegExpValue::new(value.pattern, value.flags)
+    }
+}
+
+impl From<r::RegExpValue> for l::RegExpValue {
+    fn from(value: r::RegExpValue) -> Self {
+        l::RegExpValue {
+            pattern: value.pattern,
+            flags: value.flags,
+        }
+    }
+}
+
+impl From<l::SerializationOptions> for r::SerializationOptions {
+    fn from(value: l::SerializationOptions) -> Self {
+        r::SerializationOptions::new(
+            value.max_dom_depth,
+            value.max_object_depth,
+            value.include_shadow_tree.map(Into::into),
+        )
+    }
+}
+
+impl From<r::SerializationOptions> for l::SerializationOptions {
+    fn from(value: r::SerializationOptions) -> Self {
+        l::SerializationOptions {
+            max_dom_depth: value.max_dom_depth,
+            max_object_depth: value.max_object_depth,
+            include_shadow_tree: value.include_shadow_tree.map(Into::into),
+        }
+    }
+}
+
+impl From<l::StackFrame> for r::StackFrame {
+    fn from(value: l::StackFrame) -> Self {
+        r::StackFrame::new(
+            value.column_number,
+            value.function_name,
+            value.line_number,
+            value.url,
+        )
+    }
+}
+
+impl From<r::StackFrame> for l::StackFrame {
+    fn from(value: r::StackFrame) -> Self {
+        l::StackFrame {
+            column_number: value.column_number,
+            function_name: value.function_name,
+            line_number: value.line_number,
+            url: value.url,
+        }
+    }
+}
+
+impl From<l::StackTrace> for r::StackTrace {
+    fn from(value: l::StackTrace) -> Self {
+        r::StackTrace::new(value.call_frames.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<r::StackTrace> for l::StackTrace {
+    fn from(value: r::StackTrace) -> Self {
+        l::StackTrace {
+            call_frames: value.call_frames.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+// --------------------------------------------------
+
+impl From<l::ChannelValue> for r::ChannelValue {
+    fn from(value: l::ChannelValue) -> Self {
+        let properties = value.value;
+        r::ChannelValue::new(r::ChannelProperties::new(
+            properties.channel,
+            properties.serialization_options.map(Into::into),
+            properties.ownership.map(Into::into),
+        ))
+    }
+}
+
+impl TryFrom<l::LocalValueOrText> for r::LocalValueOrText {
+    type Error = ValueError;
+
+    fn try_from(value: l::LocalValueOrText) -> Result<Self, Self::Error> {
+        match value {
+            l::LocalValueOrText::LocalValue(v) => {
+                Ok(r::LocalValueOrText::LocalValue(v.try_into()?))
+            }
+            l::LocalValueOrText::Text(v) => Ok(r::LocalValueOrText::Text(v)),
+        }
+    }
+}
+
+fn list_to_remote(list: l::ListLocalValue) -> Result<r::ListLocalValue, ValueError> {
+    list.into_iter().map(TryInto::try_into).collect()
+}
+
+fn mapping_to_remote(mapping: l::MappingLocalValue) -> Result<r::MappingLocalValue, ValueError> {
+    mapping
+        .0
+        .into_iter()
+        .map(|(k, v)| Ok((k.try_into()?, v.try_into()?)))
+        .collect()
+}
+
+/// Fails when the value contains a primitive of a type this crate does not know.
+impl TryFrom<l::LocalValue> for r::LocalValue {
+    type Error = ValueError;
+
+    fn try_from(value: l::LocalValue) -> Result<Self, Self::Error> {
+        let value = match value {
+            l::LocalValue::RemoteReference(v) => v.into(),
+            l::LocalValue::PrimitiveProtocolValue(v) => {
+                r::LocalValue::PrimitiveProtocolValue(v.try_into().map_err(|v| {
+                    ValueError(format!("cannot pass back the primitive value {:?}", v))
+                })?)
+            }
+            l::LocalValue::ChannelValue(v) => r::LocalValue::ChannelValue(v.into()),
+            l::LocalValue::ArrayLocalValue(v) => {
+                r::LocalValue::ArrayLocalValue(r::ArrayLocalValue::new(list_to_remote(v.value)?))
+            }
+            l::LocalValue::DateLocalValue(v) => {
+                r::LocalValue::DateLocalValue(r::DateLocalValue::new(v.value))
+            }
+            l::LocalValue::MapLocalValue(v) => {
+                r::LocalValue::MapLocalValue(r::MapLocalValue::new(mapping_to_remote(v.value)?))
+            }
+            l::LocalValue::ObjectLocalValue(v) => r::LocalValue::ObjectLocalValue(
+                r::ObjectLocalValue::new(mapping_to_remote(v.value)?),
+            ),
+            l::LocalValue::RegExpLocalValue(v) => {
+                r::LocalValue::RegExpLocalValue(r::RegExpLocalValue::new(v.value.into()))
+            }
+            l::LocalValue::SetLocalValue(v) => {
+                r::LocalValue::SetLocalValue(r::SetLocalValue::new(list_to_remote(v.value)?))
+            }
+        };
+        Ok(value)
+    }
+}
+
+// --------------------------------------------------
+
+// Returns whether a remote value can be passed back as a `remote::script::LocalValue`.
+fn is_convertible(value: &l::RemoteValue) -> bool {
+    if value.shared_id().is_some() || value.handle().is_some() {
+        return true;
+    }
+    let list = |list: &Option<l::ListRemoteValue>| {
+        list.as_ref()
+            .is_some_and(|list| list.iter().all(is_convertible))
+    };
+    let mapping = |mapping: &Option<l::MappingRemoteValue>| {
+        mapping.as_ref().is_some_and(|mapping| {
+            mapping.iter().all(|(k, v)| {
+                let key = match k {
+                    l::RemoteValueOrText::RemoteValue(k) => is_convertible(k),
+                    l::RemoteValueOrText::Text(_) => true,
+                };
+                key && is_convertible(v)
+            })
+        })
+    };
+    match value {
+        l::RemoteValue::PrimitiveProtocolValue(v) => {
+            !matches!(v, l::PrimitiveProtocolValue::Unknown { .. })
+        }
+        l::RemoteValue::RegExpRemoteValue(_) | l::RemoteValue::DateRemoteValue(_) => true,
+        l::RemoteValue::ArrayRemoteValue(v) => list(&v.value),
+        l::RemoteValue::SetRemoteValue(v) => list(&v.value),
+        l::RemoteValue::ObjectRemoteValue(v) => mapping(&v.value),
+        l::RemoteValue::MapRemoteValue(v) => mapping(&v.value),
+        _ => false,
+    }
+}
+
+fn list_to_local_value(list: Option<l::ListRemoteValue>) -> r::ListLocalValue {
+    list.unwrap_or_default()
+        .into_iter()
+        .map(to_local_value)
+        .collect()
+}
+
+fn mapping_to_local_value(mapping: Option<l::MappingRemoteValue>) -> r::MappingLocalValue {
+    mapping
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(k, v)| {
+            let key = match k {
+                l::RemoteValueOrText::RemoteValue(k) => {
+                    r::LocalValueOrText::LocalValue(to_local_value(k))
+                }
+                l::RemoteValueOrText::Text(k) => r::LocalValueOrText::Text(k),
+            };
+            (key, to_local_value(v))
+        })
+        .collect()
+}
+
+// Converts a remote value already checked by `is_convertible`.
+fn to_local_value(value: l::RemoteValue) -> r::LocalValue {
+    if let Some(shared_id) = value.shared_id() {
+        let reference = r::SharedReference::new(
+            shared_id.clone(),
+            value.handle().cloned(),
+            remote::Extensible::new(),
+        );
+        return r::LocalValue::RemoteReference(r::RemoteReference::SharedReference(reference));
+    }
+    if let Some(handle) = value.handle() {
+        let reference =
+            r::RemoteObjectReference::new(handle.clone(), None, remote::Extensible::new());
+        return r::LocalValue::RemoteReference(r::RemoteReference::RemoteObjectReference(
+            reference,
+        ));
+    }
+    match value {
+        l::RemoteValue::PrimitiveProtocolValue(v) => r::LocalValue::PrimitiveProtocolValue(
+            v.try_into()
+                .unwrap_or_else(|_| unreachable!("value was checked by is_convertible")),
+        ),
+        l::RemoteValue::RegExpRemoteValue(v) => {
+            r::LocalValue::RegExpLocalValue(r::RegExpLocalValue::new(v.value.into()))
+        }
+        l::RemoteValue::DateRemoteValue(v) => {
+            r::LocalValue::DateLocalValue(r::DateLocalValue::new(v.value))
+        }
+        l::RemoteValue::ArrayRemoteValue(v) => {
+            r::LocalValue::ArrayLocalValue(r::ArrayLocalValue::new(list_to_local_value(v.value)))
+        }
+        l::RemoteValue::SetRemoteValue(v) => {
+            r::LocalValue::SetLocalValue(r::SetLocalValue::new(list_to_local_value(v.value)))
+        }
+        l::RemoteValue::ObjectRemoteValue(v) => r::LocalValue::ObjectLocalValue(
+            r::ObjectLocalValue::new(mapping_to_local_value(v.value)),
+        ),
+        l::RemoteValue::MapRemoteValue(v) => {
+            r::LocalValue::MapLocalValue(r::MapLocalValue::new(mapping_to_local_value(v.value)))
+        }
+        _ => unreachable!("value was checked by is_convertible"),
+    }
+}
+
+/// Converts a value returned by the remote end into an argument for `script.callFunction`.
+///
+/// Values with a handle or shared ID are passed back by reference, so the remote end
+/// receives the same object. Primitives, dates, regular expressions and fully serialized
+/// arrays, objects, maps and sets are passed back by value. Any other value (e.g. a
+/// function serialized without a handle) is returned unchanged as the error.
+impl TryFrom<l::RemoteValue> for r::LocalValue {
+    type Error = l::RemoteValue;
+
+    fn try_from(value: l::RemoteValue) -> Result<Self, Self::Error> {
+        if is_convertible(&value) {
+            Ok(to_local_value(value))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+// --------------------------------------------------
+
+impl From<ls::UserPromptHandlerType> for rs::UserPromptHandlerType {
+    fn from(value: ls::UserPromptHandlerType) -> Self {
+        match value {
+            ls::UserPromptHandlerType::Accept => rs::UserPromptHandlerType::Accept,
+            ls::UserPromptHandlerType::Dismiss => rs::UserPromptHandlerType::Dismiss,
+            ls::UserPromptHandlerType::Ignore => rs::UserPromptHandlerType::Ignore,
+            ls::UserPromptHandlerType::Unknown(value) => rs::UserPromptHandlerType::Unknown(value),
+        }
+    }
+}
+
+impl From<rs::UserPromptHandlerType> for ls::UserPromptHandlerType {
+    fn from(value: rs::UserPromptHandlerType) -> Self {
+        match value {
+            rs::UserPromptHandlerType::Accept => ls::UserPromptHandlerType::Accept,
+            rs::UserPromptHandlerType::Dismiss => ls::UserPromptHandlerType::Dismiss,
+            rs::UserPromptHandlerType::Ignore => ls::UserPromptHandlerType::Ignore,
+            rs::UserPromptHandlerType::Unknown(value) => ls::UserPromptHandlerType::Unknown(value),
+        }
+    }
+}
+
+impl From<ls::UserPromptHandler> for rs::UserPromptHandler {
+    fn from(value: ls::UserPromptHandler) -> Self {
+        Self {
+            alert: value.alert.map(Into::into),
+            before_unload: value.before_unload.map(Into::into),
+            confirm: value.confirm.map(Into::into),
+            default: value.default.map(Into::into),
+            file: value.file.map(Into::into),
+            prompt: value.prompt.map(Into::into),
+        }
+    }
+}
+
+impl From<rs::UserPromptHandler> for ls::UserPromptHandler {
+    fn from(value: rs::UserPromptHandler) -> Self {
+        Self {
+            alert: value.alert.map(Into::into),
+            before_unload: value.before_unload.map(Into::into),
+            confirm: value.confirm.map(Into::into),
+            default: value.default.map(Into::into),
+            file: value.file.map(Into::into),
+            prompt: value.prompt.map(Into::into),
+        }
+    }
+}
+
+impl From<ls::SocksProxyConfiguration> for rs::SocksProxyConfiguration {
+    fn from(value: ls::SocksProxyConfiguration) -> Self {
+        Self::new(value.socks_proxy, value.socks_version)
+    }
+}
+
+impl From<rs::SocksProxyConfiguration> for ls::SocksProxyConfiguration {
+    fn from(value: rs::SocksProxyConfiguration) -> Self {
+        Self {
+            socks_proxy: value.socks_proxy,
+            socks_version: value.socks_version,
+        }
+    }
+}
+
+/// Fails with the value unchanged when it is of a type this crate does not know.
+// The local variants carry their `proxyType` tag as a field.
+impl TryFrom<ls::ProxyConfiguration> for rs::ProxyConfiguration {
+    type Error = ls::ProxyConfiguration;
+
+    fn try_from(value: ls::ProxyConfiguration) -> Result<Self, Self::Error> {
+        let value = match value {
+            ls::ProxyConfiguration::AutodetectProxyConfiguration(v) => {
+                Self::AutodetectProxyConfiguration(rs::AutodetectProxyConfiguration::new(
+                    v.extensible,
+                ))
+            }
+            ls::ProxyConfiguration::DirectProxyConfiguration(v) => {
+                Self::DirectProxyConfiguration(rs::DirectProxyConfiguration::new(v.extensible))
+            }
+            ls::ProxyConfiguration::ManualProxyConfiguration(v) => {
+                Self::ManualProxyConfiguration(rs::ManualProxyConfiguration::new(
+                    v.ftp_proxy,
+                    v.http_proxy,
+                    v.ssl_proxy,
+                    v.socks_proxy_configuration.map(Into::into),
+                    v.no_proxy,
+                    v.extensible,
+                ))
+            }
+            ls::ProxyConfiguration::PacProxyConfiguration(v) => Self::PacProxyConfiguration(
+                rs::PacProxyConfiguration::new(v.proxy_autoconfig_url, v.extensible),
+            ),
+            ls::ProxyConfiguration::SystemProxyConfiguration(v) => {
+                Self::SystemProxyConfiguration(rs::SystemProxyConfiguration::new(v.extensible))
+            }
+            ls::ProxyConfiguration::Unknown { .. } => return Err(value),
+        };
+        Ok(value)
+    }
+}
+
+impl From<rs::ProxyConfiguration> for ls::ProxyConfiguration {
+    fn from(value: rs::ProxyConfiguration) -> Self {
+        match value {
+            rs::ProxyConfiguration::AutodetectProxyConfiguration(v) => {
+                Self::AutodetectProxyConfiguration(ls::AutodetectProxyConfiguration {
+                    proxy_type: "autodetect".to_string(),
+                    extensible: v.extensible,
+                })
+            }
+            rs::ProxyConfiguration::DirectProxyConfiguration(v) => {
+                Self::DirectProxyConfiguration(ls::DirectProxyConfiguration {
+                    proxy_type: "direct".to_string(),
+                    extensible: v.extensible,
+                })
+            }
+            rs::ProxyConfiguration::ManualProxyConfiguration(v) => {
+                Self::ManualProxyConfiguration(ls::ManualProxyConfiguration {
+                    proxy_type: "manual".to_string(),
+                    ftp_proxy: v.ftp_proxy,
+                    http_proxy: v.http_proxy,
+                    ssl_proxy: v.ssl_proxy,
+                    socks_proxy_configuration: v.socks_proxy_configuration.map(Into::into),
+                    no_proxy: v.no_proxy,
+                    extensible: v.extensible,
+                })
+            }
+            rs::ProxyConfiguration::PacProxyConfiguration(v) => {
+                Self::PacProxyConfiguration(ls::PacProxyConfiguration {
+                    proxy_type: "pac".to_string(),
+                    proxy_autoconfig_url: v.proxy_autoconfig_url,
+                    extensible: v.extensible,
+                })
+            }
+            rs::ProxyConfiguration::SystemProxyConfiguration(v) => {
+                Self::SystemProxyConfiguration(ls::SystemProxyConfiguration {
+                    proxy_type: "system".to_string(),
+                    extensible: v.extensible,
+                })
+            }
+        }
+    }
+}
+
+/// Creates a user context with the same certificate, proxy and user prompt settings as
+/// the session the capabilities were returned for.
+///
+/// A proxy of a type this crate does not know is left out.
+impl From<ls::Capabilities> for remote::browser::CreateUserContextParameters {
+    fn from(value: ls::Capabilities) -> Self {
+        Self::new(
+            Some(value.accept_insecure_certs),
+            value.proxy.and_then(|proxy| proxy.try_into().ok()),
+            value.unhandled_prompt_behavior.map(Into::into),
+        )
+    }
+}
+
+// --------------------------------------------------
+
+impl remote::input::SetFilesParameters {
+    /// Builds the parameters answering an `input.fileDialogOpened` event with the given files.
+    ///
+    /// Returns `None` when the dialog was not opened by an input element, in
+    /// which case there is no element to set the files on.
+    pub fn from_file_dialog_info(
+        info: local::input::FileDialogInfo,
+        files: Vec<String>,
+    ) -> Option<Self> {
+        Some(Self::new(info.context, info.element?.into(), files))
+    }
+}
@@ -0,0 +1,74 @@
+//! Conversions between script dates and the `chrono` and `time` date types.
+//!
+//! Dates travel as the ISO 8601 strings produced by `Date.prototype.toISOString`, in UTC
+//! with millisecond precision.
+
+use crate::error::ValueError;
+use crate::local::script::DateRemoteValue;
+use crate::remote::script::{DateLocalValue, LocalValue};
+
+// --------------------------------------------------
+
+#[cfg(feature = "chrono")]
+impl DateRemoteValue {
+    /// Parses the date as a `chrono::DateTime<Utc>`.
+    pub fn to_chrono(&self) -> Result<chrono::DateTime<chrono::Utc>, ValueError> {
+        chrono::DateTime::parse_from_rfc3339(&self.value)
+            .map(|date| date.with_timezone(&chrono::Utc))
+            .map_err(|e| ValueError(format!("invalid date `{}`: {}", self.value, e)))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for DateLocalValue {
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        DateLocalValue::new(
+            value
+                .with_timezone(&chrono::Utc)
+                .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for LocalValue {
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        LocalValue::DateLocalValue(value.into())
+    }
+}
+
+// --------------------------------------------------
+
+#[cfg(feature = "time")]
+impl DateRemoteValue {
+    /// Parses the date as a `time::OffsetDateTime` in UTC.
+    pub fn to_time(&self) -> Result<time::OffsetDateTime, ValueError> {
+        time::OffsetDateTime::parse(&self.value, &time::format_description::well_known::Rfc3339)
+            .map_err(|e| ValueError(format!("invalid date `{}`: {}", self.value, e)))
+    }
+}
+
+// Unlike chrono, `time` refuses to format years outside 0..=9999 as RFC 3339, so the
+// conversion is fallible.
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for DateLocalValue {
+    type Error = ValueError;
+
+    fn try_from(value: time::OffsetDateTime) -> Result<Self, ValueError> {
+        let utc = value.to_offset(time::UtcOffset::UTC);
+        utc.replace_millisecond(utc.millisecond())
+            .map_err(|e| ValueError(e.to_string()))?
+            .format(&time::format_description::well_known::Rfc3339)
+            .map(DateLocalValue::new)
+            .map_err(|e| ValueError(format!("unrepresentable date `{}`: {}", value, e)))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for LocalValue {
+    type Error = ValueError;
+
+    fn try_from(value: time::OffsetDateTime) -> Result<Self, ValueError> {
+        DateLocalValue::try_from(value).map(LocalValue::DateLocalValue)
+    }
+}
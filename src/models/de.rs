@@ -0,0 +1,413 @@
+//! Reading Rust values out of `local::script::RemoteValue`.
+//!
+//! `RemoteValue` implements `serde::Deserializer`, so the result of `script.evaluate` or
+//! `script.callFunction` can be turned into any `T: Deserialize` with
+//! [`RemoteValue::into`]. Objects and maps deserialize like JSON objects, arrays, sets
+//! and node lists like sequences, and dates and regular expressions like strings.
+//! Values with no JSON counterpart (nodes, functions, promises, ...) deserialize from
+//! their protocol representation, which keeps their `handle` and `sharedId`.
+
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer,
+    VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use serde_json::{Map, Number, Value};
+
+use crate::error::ValueError;
+use crate::local::script::{
+    ArrayRemoteValue, BigIntValue, DateRemoteValue, HTMLCollectionRemoteValue, MapRemoteValue,
+    NodeListRemoteValue, NumberOrSpecialNumber, ObjectRemoteValue, PrimitiveProtocolValue,
+    RegExpRemoteValue, RegExpValue, RemoteValue, RemoteValueOrText, SetRemoteValue, SpecialNumber,
+    StringValue,
+};
+
+// Integers beyond this are not represented exactly by a JavaScript number.
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+// --------------------------------------------------
+
+impl RemoteValue {
+    /// Deserializes the value into `T`.
+    ///
+    /// ```no_run
+    /// # use webdriverbidi::local::script::RemoteValue;
+    /// # fn example(result: RemoteValue) -> Result<(), webdriverbidi::error::ValueError> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let point: Point = result.into()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into<T: DeserializeOwned>(self) -> Result<T, ValueError> {
+        T::deserialize(self)
+    }
+
+    /// Converts the value into JSON.
+    ///
+    /// The conversion is lossy: `undefined`, `NaN` and `±Infinity` become `null`,
+    /// BigInts become numbers when they fit in 64 bits and strings otherwise, dates
+    /// become their ISO string, regular expressions become `/pattern/flags`, and
+    /// non-string map keys are stringified. Values with no JSON counterpart keep their
+    /// protocol representation, e.g. `{"type": "node", "sharedId": ..., "value": ...}`.
+    pub fn to_json(&self) -> Value {
+        match self {
+            RemoteValue::PrimitiveProtocolValue(value) => primitive_to_json(value),
+            RemoteValue::ArrayRemoteValue(ArrayRemoteValue {
+                value: Some(list), ..
+            })
+            | RemoteValue::SetRemoteValue(SetRemoteValue {
+                value: Some(list), ..
+            })
+            | RemoteValue::NodeListRemoteValue(NodeListRemoteValue {
+                value: Some(list), ..
+            })
+            | RemoteValue::HTMLCollectionRemoteValue(HTMLCollectionRemoteValue {
+                value: Some(list),
+                ..
+            }) => Value::Array(list.iter().map(RemoteValue::to_json).collect()),
+            RemoteValue::ObjectRemoteValue(ObjectRemoteValue {
+                value: Some(mapping),
+                ..
+            })
+            | RemoteValue::MapRemoteValue(MapRemoteValue {
+                value: Some(mapping),
+                ..
+            }) => Value::Object(
+                mapping
+                    .iter()
+                    .map(|(key, value)| (key_to_string(key), value.to_json()))
+                    .collect::<Map<_, _>>(),
+            ),
+            RemoteValue::DateRemoteValue(DateRemoteValue { value, .. }) => {
+                Value::String(value.clone())
+            }
+            RemoteValue::RegExpRemoteValue(RegExpRemoteValue { value, .. }) => {
+                Value::String(regexp_to_string(value))
+            }
+            _ => protocol_json(self),
+        }
+    }
+}
+
+fn primitive_to_json(value: &PrimitiveProtocolValue) -> Value {
+    match value {
+        PrimitiveProtocolValue::UndefinedValue(_) | PrimitiveProtocolValue::NullValue(_) => {
+            Value::Null
+        }
+        PrimitiveProtocolValue::StringValue(v) => Value::String(v.value.clone()),
+        PrimitiveProtocolValue::BooleanValue(v) => Value::Bool(v.value),
+        PrimitiveProtocolValue::NumberValue(v) => match &v.value {
+            NumberOrSpecialNumber::Number(n) if is_safe_integer(*n) => Value::from(*n as i64),
+            NumberOrSpecialNumber::Number(n) => {
+                Number::from_f64(*n).map_or(Value::Null, Value::Number)
+            }
+            NumberOrSpecialNumber::SpecialNumber(SpecialNumber::NegativeZero) => Value::from(-0.0),
+            NumberOrSpecialNumber::SpecialNumber(SpecialNumber::Unknown(s)) => {
+                Value::String(s.clone())
+            }
+            NumberOrSpecialNumber::SpecialNumber(_) => Value::Null,
+        },
+        PrimitiveProtocolValue::BigIntValue(v) => {
+            if let Ok(n) = v.value.parse::<i64>() {
+                Value::from(n)
+            } else if let Ok(n) = v.value.parse::<u64>() {
+                Value::from(n)
+            } else {
+                Value::String(v.value.clone())
+            }
+        }
+        PrimitiveProtocolValue::Unknown { raw, .. } => raw.clone(),
+    }
+}
+
+fn key_to_string(key: &RemoteValueOrText) -> String {
+    match key {
+        RemoteValueOrText::Text(text) => text.clone(),
+        RemoteValueOrText::RemoteValue(value) => match value.to_json() {
+            Value::String(s) => s,
+            other => other.to_string(),
+        },
+    }
+}
+
+fn regexp_to_string(value: &RegExpValue) -> String {
+    format!(
+        "/{}/{}",
+        value.pattern,
+        value.flags.as_deref().unwrap_or_default()
+    )
+}
+
+fn protocol_json(value: &RemoteValue) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+fn is_safe_integer(n: f64) -> bool {
+    n.fract() == 0.0 && n.abs() <= MAX_SAFE_INTEGER && !(n == 0.0 && n.is_sign_negative())
+}
+
+fn is_nullish(value: &RemoteValue) -> bool {
+    matches!(
+        value,
+        RemoteValue::PrimitiveProtocolValue(
+            PrimitiveProtocolValue::NullValue(_) | PrimitiveProtocolValue::UndefinedValue(_)
+        )
+    )
+}
+
+fn type_name(value: &RemoteValue) -> String {
+    protocol_json(value)
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+// Map keys are either plain strings or serialized values.
+fn key_to_value(key: RemoteValueOrText) -> RemoteValue {
+    match key {
+        RemoteValueOrText::RemoteValue(value) => value,
+        RemoteValueOrText::Text(text) => {
+            RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(StringValue {
+                value_type: "string".to_string(),
+                value: text,
+            }))
+        }
+    }
+}
+
+// --------------------------------------------------
+
+impl<'de> Deserializer<'de> for RemoteValue {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            RemoteValue::PrimitiveProtocolValue(value) => deserialize_primitive(value, visitor),
+            RemoteValue::ArrayRemoteValue(ArrayRemoteValue {
+                value: Some(list), ..
+            })
+            | RemoteValue::SetRemoteValue(SetRemoteValue {
+                value: Some(list), ..
+            })
+            | RemoteValue::NodeListRemoteValue(NodeListRemoteValue {
+                value: Some(list), ..
+            })
+            | RemoteValue::HTMLCollectionRemoteValue(HTMLCollectionRemoteValue {
+                value: Some(list),
+                ..
+            }) => {
+                let mut seq = SeqDeserializer::new(list.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            RemoteValue::ObjectRemoteValue(ObjectRemoteValue {
+                value: Some(mapping),
+                ..
+            })
+            | RemoteValue::MapRemoteValue(MapRemoteValue {
+                value: Some(mapping),
+                ..
+            }) => {
+                let entries = mapping
+                    .into_iter()
+                    .map(|(key, value)| (key_to_value(key), value));
+                let mut map = MapDeserializer::new(entries);
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            RemoteValue::DateRemoteValue(DateRemoteValue { value, .. }) => {
+                visitor.visit_string(value)
+            }
+            RemoteValue::RegExpRemoteValue(RegExpRemoteValue { value, .. }) => {
+                visitor.visit_string(regexp_to_string(&value))
+            }
+            other => protocol_json(&other)
+                .deserialize_any(visitor)
+                .map_err(de::Error::custom),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        if is_nullish(&self) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        self.deserialize_string(visitor)
+    }
+
+    // BigInts are read as strings when a string is asked for, so values that don't fit
+    // in 128 bits can still be deserialized.
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::BigIntValue(
+                BigIntValue { value, .. },
+            )) => visitor.visit_string(value),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        match self {
+            RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(
+                StringValue { value, .. },
+            )) => visitor.visit_enum(IntoDeserializer::<ValueError>::into_deserializer(value)),
+            RemoteValue::ObjectRemoteValue(ObjectRemoteValue {
+                value: Some(mapping),
+                ..
+            }) if mapping.len() == 1 => {
+                let (variant, value) = mapping.into_iter().next().unwrap();
+                visitor.visit_enum(Enum {
+                    variant: key_to_value(variant),
+                    value,
+                })
+            }
+            other => Err(de::Error::custom(format!(
+                "expected a string or an object with a single key for an enum, found `{}`",
+                type_name(&other)
+            ))),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ValueError> for RemoteValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+fn deserialize_primitive<'de, V: Visitor<'de>>(
+    value: PrimitiveProtocolValue,
+    visitor: V,
+) -> Result<V::Value, ValueError> {
+    match value {
+        PrimitiveProtocolValue::UndefinedValue(_) | PrimitiveProtocolValue::NullValue(_) => {
+            visitor.visit_unit()
+        }
+        PrimitiveProtocolValue::StringValue(v) => visitor.visit_string(v.value),
+        PrimitiveProtocolValue::BooleanValue(v) => visitor.visit_bool(v.value),
+        PrimitiveProtocolValue::NumberValue(v) => match v.value {
+            NumberOrSpecialNumber::Number(n) if is_safe_integer(n) && n >= 0.0 => {
+                visitor.visit_u64(n as u64)
+            }
+            NumberOrSpecialNumber::Number(n) if is_safe_integer(n) => visitor.visit_i64(n as i64),
+            NumberOrSpecialNumber::Number(n) => visitor.visit_f64(n),
+            NumberOrSpecialNumber::SpecialNumber(special) => match special {
+                SpecialNumber::NaN => visitor.visit_f64(f64::NAN),
+                SpecialNumber::NegativeZero => visitor.visit_f64(-0.0),
+                SpecialNumber::Infinity => visitor.visit_f64(f64::INFINITY),
+                SpecialNumber::NegativeInfinity => visitor.visit_f64(f64::NEG_INFINITY),
+                SpecialNumber::Unknown(s) => {
+                    Err(de::Error::custom(format!("unknown special number `{}`", s)))
+                }
+            },
+        },
+        PrimitiveProtocolValue::BigIntValue(v) => {
+            if let Ok(n) = v.value.parse::<i64>() {
+                visitor.visit_i64(n)
+            } else if let Ok(n) = v.value.parse::<u64>() {
+                visitor.visit_u64(n)
+            } else if let Ok(n) = v.value.parse::<i128>() {
+                visitor.visit_i128(n)
+            } else if let Ok(n) = v.value.parse::<u128>() {
+                visitor.visit_u128(n)
+            } else {
+                visitor.visit_string(v.value)
+            }
+        }
+        PrimitiveProtocolValue::Unknown { raw, .. } => {
+            raw.deserialize_any(visitor).map_err(de::Error::custom)
+        }
+    }
+}
+
+// --------------------------------------------------
+
+// An externally tagged enum variant: `{ "Variant": value }`.
+struct Enum {
+    variant: RemoteValue,
+    value: RemoteValue,
+}
+
+impl<'de> EnumAccess<'de> for Enum {
+    type Error = ValueError;
+    type Variant = RemoteValue;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, RemoteValue), ValueError> {
+        let variant = seed.deserialize(self.variant)?;
+        Ok((variant, self.value))
+    }
+}
+
+impl<'de> VariantAccess<'de> for RemoteValue {
+    type Error = ValueError;
+
+    fn unit_variant(self) -> Result<(), ValueError> {
+        if is_nullish(&self) {
+            Ok(())
+        } else {
+            Err(de::Error::custom(format!(
+                "expected a unit variant, found `{}`",
+                type_name(&self)
+            )))
+        }
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<S::Value, ValueError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        self.deserialize_map(visitor)
+    }
+}
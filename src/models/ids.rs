@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// --------------------------------------------------
+
+// Defines a serde-transparent newtype around a protocol ID string.
+macro_rules! define_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Creates a new ID from the given string.
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// Returns the ID as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Consumes the ID and returns the underlying string.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+// --------------------------------------------------
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-browsingContext-BrowsingContext
+    BrowsingContext
+);
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-script-Realm
+    Realm
+);
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-script-Handle
+    Handle
+);
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-script-SharedId
+    SharedId
+);
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-network-Intercept
+    Intercept
+);
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-browser-UserContext
+    UserContext
+);
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-network-Request
+    Request
+);
+
+define_id!(
+    /// https://w3c.github.io/webdriver-bidi/#type-script-PreloadScript
+    PreloadScript
+);
@@ -0,0 +1,111 @@
+use crate::local::{browsing_context, JsUint};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum BluetoothEvent {
+    RequestDevicePromptUpdated(RequestDevicePromptUpdated),
+    GattConnectionAttempted(GattConnectionAttempted),
+    CharacteristicEventGenerated(CharacteristicEventGenerated),
+    DescriptorEventGenerated(DescriptorEventGenerated),
+}
+
+pub type BluetoothUuid = String;
+
+pub type RequestDevice = String;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestDeviceInfo {
+    pub id: RequestDevice,
+    pub name: Option<String>,
+}
+
+pub type RequestDevicePrompt = String;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestDevicePromptUpdated {
+    pub method: String,
+    pub params: RequestDevicePromptUpdatedParameters,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestDevicePromptUpdatedParameters {
+    pub context: browsing_context::BrowsingContext,
+    pub prompt: RequestDevicePrompt,
+    pub devices: Vec<RequestDeviceInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GattConnectionAttempted {
+    pub method: String,
+    pub params: GattConnectionAttemptedParameters,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GattConnectionAttemptedParameters {
+    pub context: browsing_context::BrowsingContext,
+    pub address: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CharacteristicEventGenerated {
+    pub method: String,
+    pub params: CharacteristicEventGeneratedParameters,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CharacteristicEventGeneratedParameters {
+    pub context: browsing_context::BrowsingContext,
+    pub address: String,
+    #[serde(rename = "serviceUuid")]
+    pub service_uuid: BluetoothUuid,
+    #[serde(rename = "characteristicUuid")]
+    pub characteristic_uuid: BluetoothUuid,
+    #[serde(rename = "type")]
+    pub event_type: CharacteristicEventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<JsUint>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum CharacteristicEventType {
+    Read,
+    WriteWithResponse,
+    WriteWithoutResponse,
+    SubscribeToNotifications,
+    UnsubscribeFromNotifications,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DescriptorEventGenerated {
+    pub method: String,
+    pub params: DescriptorEventGeneratedParameters,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DescriptorEventGeneratedParameters {
+    pub context: browsing_context::BrowsingContext,
+    pub address: String,
+    #[serde(rename = "serviceUuid")]
+    pub service_uuid: BluetoothUuid,
+    #[serde(rename = "characteristicUuid")]
+    pub characteristic_uuid: BluetoothUuid,
+    #[serde(rename = "descriptorUuid")]
+    pub descriptor_uuid: BluetoothUuid,
+    #[serde(rename = "type")]
+    pub event_type: DescriptorEventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<JsUint>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum DescriptorEventType {
+    Read,
+    Write,
+    #[serde(untagged)]
+    Unknown(String),
+}
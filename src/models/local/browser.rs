@@ -29,9 +29,11 @@ pub enum ClientWindowInfoState {
     Maximized,
     Minimized,
     Normal,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-pub type UserContext = String;
+pub use crate::models::ids::UserContext;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UserContextInfo {
@@ -31,7 +31,7 @@ pub enum BrowsingContextEvent {
     UserPromptOpened(UserPromptOpened),
 }
 
-pub type BrowsingContext = String;
+pub use crate::models::ids::BrowsingContext;
 
 pub type InfoList = Vec<Info>;
 
@@ -112,6 +112,8 @@ pub struct InnerTextLocator {
 pub enum InnerTextLocatorMatchType {
     Full,
     Partial,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -138,6 +140,8 @@ pub enum UserPromptType {
     BeforeUnload,
     Confirm,
     Prompt,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 pub type Extensible = HashMap<String, serde_json::Value>;
 
@@ -22,6 +23,8 @@ pub enum ErrorCode {
     MoveTargetOutOfBounds,
     #[serde(rename = "no such alert")]
     NoSuchAlert,
+    #[serde(rename = "no such network collector")]
+    NoSuchNetworkCollector,
     #[serde(rename = "no such element")]
     NoSuchElement,
     #[serde(rename = "no such frame")]
@@ -32,6 +35,8 @@ pub enum ErrorCode {
     NoSuchHistoryEntry,
     #[serde(rename = "no such intercept")]
     NoSuchIntercept,
+    #[serde(rename = "no such network data")]
+    NoSuchNetworkData,
     #[serde(rename = "no such node")]
     NoSuchNode,
     #[serde(rename = "no such request")]
@@ -54,6 +59,8 @@ pub enum ErrorCode {
     UnableToSetCookie,
     #[serde(rename = "unable to set file input")]
     UnableToSetFileInput,
+    #[serde(rename = "unavailable network data")]
+    UnavailableNetworkData,
     #[serde(rename = "underspecified storage partition")]
     UnderspecifiedStoragePartition,
     #[serde(rename = "unknown command")]
@@ -62,4 +69,51 @@ pub enum ErrorCode {
     UnknownError,
     #[serde(rename = "unsupported operation")]
     UnsupportedOperation,
+    /// An error code not known to this version of the library.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// Returns the error code as it appears on the wire, e.g. `no such frame`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::InvalidArgument => "invalid argument",
+            ErrorCode::InvalidSelector => "invalid selector",
+            ErrorCode::InvalidSessionId => "invalid session id",
+            ErrorCode::InvalidWebExtension => "invalid web extension",
+            ErrorCode::MoveTargetOutOfBounds => "move target out of bounds",
+            ErrorCode::NoSuchAlert => "no such alert",
+            ErrorCode::NoSuchNetworkCollector => "no such network collector",
+            ErrorCode::NoSuchElement => "no such element",
+            ErrorCode::NoSuchFrame => "no such frame",
+            ErrorCode::NoSuchHandle => "no such handle",
+            ErrorCode::NoSuchHistoryEntry => "no such history entry",
+            ErrorCode::NoSuchIntercept => "no such intercept",
+            ErrorCode::NoSuchNetworkData => "no such network data",
+            ErrorCode::NoSuchNode => "no such node",
+            ErrorCode::NoSuchRequest => "no such request",
+            ErrorCode::NoSuchScript => "no such script",
+            ErrorCode::NoSuchStoragePartition => "no such storage partition",
+            ErrorCode::NoSuchUserContext => "no such user context",
+            ErrorCode::NoSuchWebExtension => "no such web extension",
+            ErrorCode::SessionNotCreated => "session not created",
+            ErrorCode::UnableToCaptureScreen => "unable to capture screen",
+            ErrorCode::UnableToCloseBrowser => "unable to close browser",
+            ErrorCode::UnableToSetCookie => "unable to set cookie",
+            ErrorCode::UnableToSetFileInput => "unable to set file input",
+            ErrorCode::UnavailableNetworkData => "unavailable network data",
+            ErrorCode::UnderspecifiedStoragePartition => "underspecified storage partition",
+            ErrorCode::UnknownCommand => "unknown command",
+            ErrorCode::UnknownError => "unknown error",
+            ErrorCode::UnsupportedOperation => "unsupported operation",
+            ErrorCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
@@ -1,5 +1,6 @@
 use crate::local::{ErrorCode, Extensible, JsUint};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ErrorResponse {
@@ -12,3 +13,9 @@ pub struct ErrorResponse {
     #[serde(flatten)]
     pub extensible: Extensible,
 }
+
+impl fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.error, self.message)
+    }
+}
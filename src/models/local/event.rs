@@ -1,6 +1,8 @@
 #![allow(clippy::all)]
 
+use crate::local::bluetooth::BluetoothEvent;
 use crate::local::browsing_context::BrowsingContextEvent;
+use crate::local::input::InputEvent;
 use crate::local::log::LogEvent;
 use crate::local::network::NetworkEvent;
 use crate::local::script::ScriptEvent;
@@ -19,7 +21,9 @@ pub struct Event {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum EventData {
+    BluetoothEvent(BluetoothEvent),
     BrowsingContextEvent(BrowsingContextEvent),
+    InputEvent(InputEvent),
     LogEvent(LogEvent),
     NetworkEvent(NetworkEvent),
     ScriptEvent(ScriptEvent),
@@ -0,0 +1,22 @@
+use crate::local::{browsing_context, script};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum InputEvent {
+    FileDialogOpened(FileDialogOpened),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FileDialogOpened {
+    pub method: String,
+    pub params: FileDialogInfo,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FileDialogInfo {
+    pub context: browsing_context::BrowsingContext,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub element: Option<script::SharedReference>,
+    pub multiple: bool,
+}
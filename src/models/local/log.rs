@@ -1,5 +1,6 @@
 use crate::local::{script, JsUint};
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
@@ -14,9 +15,11 @@ pub enum Level {
     Info,
     Warn,
     Error,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(untagged)]
 pub enum Entry {
     GenericLogEntry(GenericLogEntry),
@@ -24,6 +27,20 @@ pub enum Entry {
     JavascriptLogEntry(JavascriptLogEntry),
 }
 
+// Any entry parses as a `GenericLogEntry`, so the variant is picked from the `type`
+// field rather than by trying each one in turn.
+impl<'de> Deserialize<'de> for Entry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let variant = match value.get("type").and_then(Value::as_str) {
+            Some("console") => from_value(value).map(Entry::ConsoleLogEntry),
+            Some("javascript") => from_value(value).map(Entry::JavascriptLogEntry),
+            _ => from_value(value).map(Entry::GenericLogEntry),
+        };
+        variant.map_err(de::Error::custom)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BaseLogEntry {
     pub level: Level,
@@ -1,9 +1,11 @@
+pub mod bluetooth;
 pub mod browser;
 pub mod browsing_context;
 pub mod command_response;
 pub mod common;
 pub mod error_response;
 pub mod event;
+pub mod input;
 pub mod log;
 pub mod message;
 pub mod network;
@@ -11,6 +13,7 @@ pub mod result_data;
 pub mod script;
 pub mod session;
 pub mod storage;
+pub mod web_authn;
 pub mod web_extension;
 
 // pub use message::*;
@@ -20,10 +23,13 @@ pub mod web_extension;
 // pub use event::*;
 // pub use session::*;
 // pub use browser::*;
+// pub use bluetooth::*;
 // pub use browsing_context::*;
 // pub use network::*;
 // pub use script::*;
 // pub use storage::*;
+// pub use input::*;
 // pub use log::*;
+// pub use web_authn::*;
 // pub use web_extension::*;
 pub use common::*;
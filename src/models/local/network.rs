@@ -25,8 +25,10 @@ pub struct AuthChallenge {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BaseParameters {
     pub context: Option<browsing_context::BrowsingContext>,
+    #[serde(rename = "isBlocked")]
     pub is_blocked: bool,
     pub navigation: Option<browsing_context::Navigation>,
+    #[serde(rename = "redirectCount")]
     pub redirect_count: JsUint,
     pub request: RequestData,
     pub timestamp: JsUint,
@@ -60,6 +62,8 @@ pub enum SameSite {
     Strict,
     Lax,
     None,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -137,10 +141,12 @@ pub enum InitiatorType {
     Script,
     Preflight,
     Other,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-pub type Intercept = String;
-pub type Request = String;
+pub use crate::models::ids::Intercept;
+pub use crate::models::ids::Request;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RequestData {
@@ -5,6 +5,7 @@ use crate::local::network::NetworkResult;
 use crate::local::script::ScriptResult;
 use crate::local::session::SessionResult;
 use crate::local::storage::StorageResult;
+use crate::local::web_authn::WebAuthnResult;
 use crate::local::web_extension::WebExtensionResult;
 use crate::local::Extensible;
 use serde::{Deserialize, Serialize};
@@ -18,6 +19,7 @@ pub enum ResultData {
     ScriptResult(ScriptResult),
     SessionResult(SessionResult),
     StorageResult(StorageResult),
+    WebAuthnResult(WebAuthnResult),
     WebExtensionResult(WebExtensionResult),
 }
 
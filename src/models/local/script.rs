@@ -1,9 +1,11 @@
 use crate::local::{browsing_context, Extensible, JsUint};
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{from_value, Value};
 use std::collections::HashMap;
 
 use super::result_data::EmptyResult;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum ScriptResult {
@@ -78,10 +80,10 @@ pub struct ExceptionDetails {
     pub text: String,
 }
 
-pub type Handle = String;
+pub use crate::models::ids::Handle;
 pub type InternalId = String;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(untagged)]
 pub enum LocalValue {
     RemoteReference(RemoteReference),
@@ -95,6 +97,34 @@ pub enum LocalValue {
     SetLocalValue(SetLocalValue),
 }
 
+// `PrimitiveProtocolValue` accepts any `type`, so it cannot be tried before the other
+// variants: the variant is picked from the `type` field instead.
+impl<'de> Deserialize<'de> for LocalValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let variant = match value.get("type").and_then(Value::as_str) {
+            None => from_value(value).map(LocalValue::RemoteReference),
+            Some("undefined" | "null" | "string" | "number" | "boolean" | "bigint") => {
+                from_value(value).map(LocalValue::PrimitiveProtocolValue)
+            }
+            Some("channel") => from_value(value).map(LocalValue::ChannelValue),
+            Some("array") => from_value(value).map(LocalValue::ArrayLocalValue),
+            Some("date") => from_value(value).map(LocalValue::DateLocalValue),
+            Some("map") => from_value(value).map(LocalValue::MapLocalValue),
+            Some("object") => from_value(value).map(LocalValue::ObjectLocalValue),
+            Some("regexp") => from_value(value).map(LocalValue::RegExpLocalValue),
+            Some("set") => from_value(value).map(LocalValue::SetLocalValue),
+            Some(other) => {
+                return Err(de::Error::custom(format!(
+                    "unknown local value type `{}`",
+                    other
+                )))
+            }
+        };
+        variant.map_err(de::Error::custom)
+    }
+}
+
 pub type ListLocalValue = Vec<LocalValue>;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -156,10 +186,10 @@ pub struct SetLocalValue {
     pub value: ListLocalValue,
 }
 
-pub type PreloadScript = String;
-pub type Realm = String;
+pub use crate::models::ids::PreloadScript;
+pub use crate::models::ids::Realm;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(untagged)]
 pub enum PrimitiveProtocolValue {
     StringValue(StringValue),
@@ -168,6 +198,49 @@ pub enum PrimitiveProtocolValue {
     BigIntValue(BigIntValue),
     NullValue(NullValue),
     UndefinedValue(UndefinedValue),
+    /// A value of a type this crate does not know, serialized back as received.
+    #[serde(serialize_with = "serialize_unknown")]
+    Unknown {
+        value_type: String,
+        raw: Value,
+    },
+}
+
+// Several primitive values share the same shape, so the variant is picked from the
+// `type` field rather than by trying each one in turn.
+impl<'de> Deserialize<'de> for PrimitiveProtocolValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let variant = match value_type(&value) {
+            "undefined" => from_value(value).map(PrimitiveProtocolValue::UndefinedValue),
+            "null" => from_value(value).map(PrimitiveProtocolValue::NullValue),
+            "string" => from_value(value).map(PrimitiveProtocolValue::StringValue),
+            "number" => from_value(value).map(PrimitiveProtocolValue::NumberValue),
+            "boolean" => from_value(value).map(PrimitiveProtocolValue::BooleanValue),
+            "bigint" => from_value(value).map(PrimitiveProtocolValue::BigIntValue),
+            "" => return Err(de::Error::missing_field("type")),
+            other => Ok(PrimitiveProtocolValue::Unknown {
+                value_type: other.to_string(),
+                raw: value,
+            }),
+        };
+        variant.map_err(de::Error::custom)
+    }
+}
+
+fn value_type(value: &Value) -> &str {
+    value
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+pub(crate) fn serialize_unknown<S: Serializer>(
+    _value_type: &String,
+    raw: &Value,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    raw.serialize(serializer)
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -197,6 +270,8 @@ pub enum SpecialNumber {
     Infinity,
     #[serde(rename = "-Infinity")]
     NegativeInfinity,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -227,7 +302,7 @@ pub struct BigIntValue {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(untagged)]
 pub enum RealmInfo {
     WindowRealmInfo(WindowRealmInfo),
@@ -238,6 +313,36 @@ pub enum RealmInfo {
     PaintWorkletRealmInfo(PaintWorkletRealmInfo),
     AudioWorkletRealmInfo(AudioWorkletRealmInfo),
     WorkletRealmInfo(WorkletRealmInfo),
+    /// A realm of a type this crate does not know, serialized back as received.
+    #[serde(serialize_with = "serialize_unknown")]
+    Unknown {
+        realm_type: String,
+        raw: Value,
+    },
+}
+
+// Most realm infos share the same shape, so the variant is picked from the `type`
+// field rather than by trying each one in turn.
+impl<'de> Deserialize<'de> for RealmInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let variant = match value_type(&value) {
+            "window" => from_value(value).map(RealmInfo::WindowRealmInfo),
+            "dedicated-worker" => from_value(value).map(RealmInfo::DedicatedWorkerRealmInfo),
+            "shared-worker" => from_value(value).map(RealmInfo::SharedWorkerRealmInfo),
+            "service-worker" => from_value(value).map(RealmInfo::ServiceWorkerRealmInfo),
+            "worker" => from_value(value).map(RealmInfo::WorkerRealmInfo),
+            "paint-worklet" => from_value(value).map(RealmInfo::PaintWorkletRealmInfo),
+            "audio-worklet" => from_value(value).map(RealmInfo::AudioWorkletRealmInfo),
+            "worklet" => from_value(value).map(RealmInfo::WorkletRealmInfo),
+            "" => return Err(de::Error::missing_field("type")),
+            other => Ok(RealmInfo::Unknown {
+                realm_type: other.to_string(),
+                raw: value,
+            }),
+        };
+        variant.map_err(de::Error::custom)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -332,6 +437,8 @@ pub enum RealmType {
     AudioWorklet,
     #[serde(rename = "worklet")]
     Worklet,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -360,7 +467,7 @@ pub struct RemoteObjectReference {
     pub extensible: Extensible,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(untagged)]
 pub enum RemoteValue {
     PrimitiveProtocolValue(PrimitiveProtocolValue),
@@ -384,6 +491,113 @@ pub enum RemoteValue {
     HTMLCollectionRemoteValue(HTMLCollectionRemoteValue),
     NodeRemoteValue(NodeRemoteValue),
     WindowProxyRemoteValue(WindowProxyRemoteValue),
+    /// A value of a type this crate does not know, serialized back as received.
+    #[serde(serialize_with = "serialize_unknown")]
+    Unknown {
+        value_type: String,
+        raw: Value,
+    },
+}
+
+impl<'de> Deserialize<'de> for RemoteValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let variant = match value_type(&value) {
+            "undefined" | "null" | "string" | "number" | "boolean" | "bigint" => {
+                from_value(value).map(RemoteValue::PrimitiveProtocolValue)
+            }
+            "symbol" => from_value(value).map(RemoteValue::SymbolRemoteValue),
+            "array" => from_value(value).map(RemoteValue::ArrayRemoteValue),
+            "object" => from_value(value).map(RemoteValue::ObjectRemoteValue),
+            "function" => from_value(value).map(RemoteValue::FunctionRemoteValue),
+            "regexp" => from_value(value).map(RemoteValue::RegExpRemoteValue),
+            "date" => from_value(value).map(RemoteValue::DateRemoteValue),
+            "map" => from_value(value).map(RemoteValue::MapRemoteValue),
+            "set" => from_value(value).map(RemoteValue::SetRemoteValue),
+            "weakmap" => from_value(value).map(RemoteValue::WeakMapRemoteValue),
+            "weakset" => from_value(value).map(RemoteValue::WeakSetRemoteValue),
+            "generator" => from_value(value).map(RemoteValue::GeneratorRemoteValue),
+            "error" => from_value(value).map(RemoteValue::ErrorRemoteValue),
+            "proxy" => from_value(value).map(RemoteValue::ProxyRemoteValue),
+            "promise" => from_value(value).map(RemoteValue::PromiseRemoteValue),
+            "typedarray" => from_value(value).map(RemoteValue::TypedArrayRemoteValue),
+            "arraybuffer" => from_value(value).map(RemoteValue::ArrayBufferRemoteValue),
+            "nodelist" => from_value(value).map(RemoteValue::NodeListRemoteValue),
+            "htmlcollection" => from_value(value).map(RemoteValue::HTMLCollectionRemoteValue),
+            "node" => from_value(value).map(RemoteValue::NodeRemoteValue),
+            "window" => from_value(value).map(RemoteValue::WindowProxyRemoteValue),
+            "" => return Err(de::Error::missing_field("type")),
+            other => Ok(RemoteValue::Unknown {
+                value_type: other.to_string(),
+                raw: value,
+            }),
+        };
+        variant.map_err(de::Error::custom)
+    }
+}
+
+impl RemoteValue {
+    /// Returns the handle of the value, if it was serialized with one.
+    pub fn handle(&self) -> Option<&Handle> {
+        match self {
+            RemoteValue::PrimitiveProtocolValue(_) | RemoteValue::Unknown { .. } => None,
+            RemoteValue::SymbolRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::ArrayRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::ObjectRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::FunctionRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::RegExpRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::DateRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::MapRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::SetRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::WeakMapRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::WeakSetRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::GeneratorRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::ErrorRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::ProxyRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::PromiseRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::TypedArrayRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::ArrayBufferRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::NodeListRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::HTMLCollectionRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::NodeRemoteValue(v) => v.handle.as_ref(),
+            RemoteValue::WindowProxyRemoteValue(v) => v.handle.as_ref(),
+        }
+    }
+
+    /// Returns the internal ID of the value, if it occurs more than once in its serialization.
+    pub fn internal_id(&self) -> Option<&InternalId> {
+        match self {
+            RemoteValue::PrimitiveProtocolValue(_) | RemoteValue::Unknown { .. } => None,
+            RemoteValue::SymbolRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::ArrayRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::ObjectRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::FunctionRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::RegExpRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::DateRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::MapRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::SetRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::WeakMapRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::WeakSetRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::GeneratorRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::ErrorRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::ProxyRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::PromiseRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::TypedArrayRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::ArrayBufferRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::NodeListRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::HTMLCollectionRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::NodeRemoteValue(v) => v.internal_id.as_ref(),
+            RemoteValue::WindowProxyRemoteValue(v) => v.internal_id.as_ref(),
+        }
+    }
+
+    /// Returns the shared ID of the value, if it is a node.
+    pub fn shared_id(&self) -> Option<&SharedId> {
+        match self {
+            RemoteValue::NodeRemoteValue(v) => v.shared_id.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 pub type ListRemoteValue = Vec<RemoteValue>;
@@ -637,6 +851,8 @@ pub struct NodeProperties {
 pub enum NodePropertiesMode {
     Open,
     Closed,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -660,6 +876,8 @@ pub struct WindowProxyProperties {
 pub enum ResultOwnership {
     Root,
     None,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -678,9 +896,11 @@ pub enum IncludeShadowTree {
     None,
     Open,
     All,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-pub type SharedId = String;
+pub use crate::models::ids::SharedId;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct StackFrame {
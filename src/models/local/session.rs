@@ -1,7 +1,8 @@
 #![allow(clippy::all)]
 
 use crate::local::Extensible;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
@@ -43,7 +44,7 @@ pub struct CapabilityRequest {
     pub extensible: Extensible,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Debug)]
 #[serde(untagged)]
 pub enum ProxyConfiguration {
     AutodetectProxyConfiguration(AutodetectProxyConfiguration),
@@ -51,6 +52,37 @@ pub enum ProxyConfiguration {
     ManualProxyConfiguration(ManualProxyConfiguration),
     PacProxyConfiguration(PacProxyConfiguration),
     SystemProxyConfiguration(SystemProxyConfiguration),
+    /// A proxy of a type this crate does not know, serialized back as received.
+    #[serde(serialize_with = "super::script::serialize_unknown")]
+    Unknown {
+        proxy_type: String,
+        raw: Value,
+    },
+}
+
+// Every variant but `pac` only has optional fields, so the variant is picked by
+// `proxyType` rather than by trying each one in turn.
+impl<'de> Deserialize<'de> for ProxyConfiguration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let proxy_type = value
+            .get("proxyType")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let variant = match proxy_type {
+            "autodetect" => from_value(value).map(ProxyConfiguration::AutodetectProxyConfiguration),
+            "direct" => from_value(value).map(ProxyConfiguration::DirectProxyConfiguration),
+            "manual" => from_value(value).map(ProxyConfiguration::ManualProxyConfiguration),
+            "pac" => from_value(value).map(ProxyConfiguration::PacProxyConfiguration),
+            "system" => from_value(value).map(ProxyConfiguration::SystemProxyConfiguration),
+            "" => return Err(de::Error::missing_field("proxyType")),
+            other => Ok(ProxyConfiguration::Unknown {
+                proxy_type: other.to_string(),
+                raw: value,
+            }),
+        };
+        variant.map_err(de::Error::custom)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -79,8 +111,8 @@ pub struct ManualProxyConfiguration {
     pub http_proxy: Option<String>,
     #[serde(rename = "sslProxy", skip_serializing_if = "Option::is_none")]
     pub ssl_proxy: Option<String>,
-    #[serde(rename = "socksProxy", skip_serializing_if = "Option::is_none")]
-    pub socks_proxy: Option<SocksProxyConfiguration>,
+    #[serde(flatten)]
+    pub socks_proxy_configuration: Option<SocksProxyConfiguration>,
     #[serde(rename = "noProxy", skip_serializing_if = "Option::is_none")]
     pub no_proxy: Option<Vec<String>>,
     #[serde(flatten)]
@@ -124,6 +156,8 @@ pub struct UserPromptHandler {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<UserPromptHandlerType>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<UserPromptHandlerType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt: Option<UserPromptHandlerType>,
 }
 
@@ -133,6 +167,8 @@ pub enum UserPromptHandlerType {
     Accept,
     Dismiss,
     Ignore,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 type Subscription = String;
@@ -145,6 +181,7 @@ pub struct StatusResult {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct NewResult {
+    #[serde(rename = "sessionId")]
     pub session_id: String,
     pub capabilities: Capabilities,
 }
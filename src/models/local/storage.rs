@@ -1,3 +1,4 @@
+use crate::local::browser::UserContext;
 use crate::local::{network, Extensible};
 use serde::{Deserialize, Serialize};
 
@@ -12,7 +13,7 @@ pub enum StorageResult {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PartitionKey {
     #[serde(rename = "userContext", skip_serializing_if = "Option::is_none")]
-    pub user_context: Option<String>,
+    pub user_context: Option<UserContext>,
     #[serde(rename = "sourceOrigin", skip_serializing_if = "Option::is_none")]
     pub source_origin: Option<String>,
     #[serde(flatten)]
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum WebAuthnResult {
+    AddVirtualAuthenticatorResult(AddVirtualAuthenticatorResult),
+    GetCredentialsResult(GetCredentialsResult),
+}
+
+// The credential format is shared with `webAuthn.addCredential`.
+pub use crate::remote::web_authn::{AuthenticatorId, Credential};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AddVirtualAuthenticatorResult {
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetCredentialsResult {
+    pub credentials: Vec<Credential>,
+}
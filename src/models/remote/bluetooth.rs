@@ -0,0 +1,982 @@
+#![allow(clippy::all)]
+
+use crate::local;
+use crate::remote::browsing_context::BrowsingContext;
+use crate::remote::command::BidiCommand;
+use crate::remote::JsUint;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BluetoothCommand {
+    HandleRequestDevicePrompt(HandleRequestDevicePrompt),
+    SimulateAdapter(SimulateAdapter),
+    DisableSimulation(DisableSimulation),
+    SimulatePreconnectedPeripheral(SimulatePreconnectedPeripheral),
+    SimulateAdvertisement(SimulateAdvertisement),
+    SimulateGattConnectionResponse(SimulateGattConnectionResponse),
+    SimulateGattDisconnection(SimulateGattDisconnection),
+    SimulateService(SimulateService),
+    SimulateCharacteristic(SimulateCharacteristic),
+    SimulateCharacteristicResponse(SimulateCharacteristicResponse),
+    SimulateDescriptor(SimulateDescriptor),
+    SimulateDescriptorResponse(SimulateDescriptorResponse),
+}
+
+pub type BluetoothUuid = String;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BluetoothManufacturerData {
+    pub key: JsUint,
+    pub data: String,
+}
+
+impl BluetoothManufacturerData {
+    pub fn new(key: JsUint, data: String) -> Self {
+        Self { key, data }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CharacteristicProperties {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broadcast: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read: Option<bool>,
+    #[serde(
+        rename = "writeWithoutResponse",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub write_without_response: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indicate: Option<bool>,
+    #[serde(
+        rename = "authenticatedSignedWrites",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub authenticated_signed_writes: Option<bool>,
+    #[serde(rename = "extendedProperties", skip_serializing_if = "Option::is_none")]
+    pub extended_properties: Option<bool>,
+}
+
+impl CharacteristicProperties {
+    pub fn new(
+        broadcast: Option<bool>,
+        read: Option<bool>,
+        write_without_response: Option<bool>,
+        write: Option<bool>,
+        notify: Option<bool>,
+        indicate: Option<bool>,
+        authenticated_signed_writes: Option<bool>,
+        extended_properties: Option<bool>,
+    ) -> Self {
+        Self {
+            broadcast,
+            read,
+            write_without_response,
+            write,
+            notify,
+            indicate,
+            authenticated_signed_writes,
+            extended_properties,
+        }
+    }
+
+    pub fn builder() -> CharacteristicPropertiesBuilder {
+        CharacteristicPropertiesBuilder {
+            inner: Self::new(None, None, None, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CharacteristicPropertiesBuilder {
+    inner: CharacteristicProperties,
+}
+
+impl CharacteristicPropertiesBuilder {
+    pub fn broadcast(mut self, broadcast: bool) -> Self {
+        self.inner.broadcast = Some(broadcast);
+        self
+    }
+
+    pub fn read(mut self, read: bool) -> Self {
+        self.inner.read = Some(read);
+        self
+    }
+
+    pub fn write_without_response(mut self, write_without_response: bool) -> Self {
+        self.inner.write_without_response = Some(write_without_response);
+        self
+    }
+
+    pub fn write(mut self, write: bool) -> Self {
+        self.inner.write = Some(write);
+        self
+    }
+
+    pub fn notify(mut self, notify: bool) -> Self {
+        self.inner.notify = Some(notify);
+        self
+    }
+
+    pub fn indicate(mut self, indicate: bool) -> Self {
+        self.inner.indicate = Some(indicate);
+        self
+    }
+
+    pub fn authenticated_signed_writes(mut self, authenticated_signed_writes: bool) -> Self {
+        self.inner.authenticated_signed_writes = Some(authenticated_signed_writes);
+        self
+    }
+
+    pub fn extended_properties(mut self, extended_properties: bool) -> Self {
+        self.inner.extended_properties = Some(extended_properties);
+        self
+    }
+
+    pub fn build(self) -> CharacteristicProperties {
+        self.inner
+    }
+}
+
+pub type RequestDevice = String;
+
+pub type RequestDevicePrompt = String;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScanRecord {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuids: Option<Vec<BluetoothUuid>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appearance: Option<JsUint>,
+    #[serde(rename = "manufacturerData", skip_serializing_if = "Option::is_none")]
+    pub manufacturer_data: Option<Vec<BluetoothManufacturerData>>,
+}
+
+impl ScanRecord {
+    pub fn new(
+        name: Option<String>,
+        uuids: Option<Vec<BluetoothUuid>>,
+        appearance: Option<JsUint>,
+        manufacturer_data: Option<Vec<BluetoothManufacturerData>>,
+    ) -> Self {
+        Self {
+            name,
+            uuids,
+            appearance,
+            manufacturer_data,
+        }
+    }
+
+    pub fn builder() -> ScanRecordBuilder {
+        ScanRecordBuilder {
+            inner: Self::new(None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScanRecordBuilder {
+    inner: ScanRecord,
+}
+
+impl ScanRecordBuilder {
+    pub fn name(mut self, name: String) -> Self {
+        self.inner.name = Some(name);
+        self
+    }
+
+    pub fn uuids(mut self, uuids: Vec<BluetoothUuid>) -> Self {
+        self.inner.uuids = Some(uuids);
+        self
+    }
+
+    pub fn appearance(mut self, appearance: JsUint) -> Self {
+        self.inner.appearance = Some(appearance);
+        self
+    }
+
+    pub fn manufacturer_data(mut self, manufacturer_data: Vec<BluetoothManufacturerData>) -> Self {
+        self.inner.manufacturer_data = Some(manufacturer_data);
+        self
+    }
+
+    pub fn build(self) -> ScanRecord {
+        self.inner
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HandleRequestDevicePrompt {
+    pub method: String,
+    pub params: HandleRequestDevicePromptParameters,
+}
+
+impl HandleRequestDevicePrompt {
+    pub fn new(params: HandleRequestDevicePromptParameters) -> Self {
+        Self {
+            method: HandleRequestDevicePromptParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HandleRequestDevicePromptParameters {
+    pub context: BrowsingContext,
+    pub prompt: RequestDevicePrompt,
+    #[serde(flatten)]
+    pub accept_or_cancel: HandleRequestDevicePromptAcceptOrCancelParameters,
+}
+
+impl HandleRequestDevicePromptParameters {
+    pub fn new(
+        context: BrowsingContext,
+        prompt: RequestDevicePrompt,
+        accept_or_cancel: HandleRequestDevicePromptAcceptOrCancelParameters,
+    ) -> Self {
+        Self {
+            context,
+            prompt,
+            accept_or_cancel,
+        }
+    }
+}
+
+impl BidiCommand for HandleRequestDevicePromptParameters {
+    const METHOD: &'static str = "bluetooth.handleRequestDevicePrompt";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum HandleRequestDevicePromptAcceptOrCancelParameters {
+    HandleRequestDevicePromptAcceptParameters(HandleRequestDevicePromptAcceptParameters),
+    HandleRequestDevicePromptCancelParameters(HandleRequestDevicePromptCancelParameters),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HandleRequestDevicePromptAcceptParameters {
+    pub accept: bool,
+    pub device: RequestDevice,
+}
+
+impl HandleRequestDevicePromptAcceptParameters {
+    pub fn new(device: RequestDevice) -> Self {
+        Self {
+            accept: true,
+            device,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HandleRequestDevicePromptCancelParameters {
+    pub accept: bool,
+}
+
+impl HandleRequestDevicePromptCancelParameters {
+    pub fn new() -> Self {
+        Self { accept: false }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateAdapter {
+    pub method: String,
+    pub params: SimulateAdapterParameters,
+}
+
+impl SimulateAdapter {
+    pub fn new(params: SimulateAdapterParameters) -> Self {
+        Self {
+            method: SimulateAdapterParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateAdapterParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "leSupported", skip_serializing_if = "Option::is_none")]
+    pub le_supported: Option<bool>,
+    pub state: SimulateAdapterState,
+}
+
+impl SimulateAdapterParameters {
+    pub fn new(
+        context: BrowsingContext,
+        le_supported: Option<bool>,
+        state: SimulateAdapterState,
+    ) -> Self {
+        Self {
+            context,
+            le_supported,
+            state,
+        }
+    }
+
+    pub fn builder(
+        context: BrowsingContext,
+        state: SimulateAdapterState,
+    ) -> SimulateAdapterParametersBuilder {
+        SimulateAdapterParametersBuilder {
+            inner: Self::new(context, None, state),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SimulateAdapterParametersBuilder {
+    inner: SimulateAdapterParameters,
+}
+
+impl SimulateAdapterParametersBuilder {
+    pub fn le_supported(mut self, le_supported: bool) -> Self {
+        self.inner.le_supported = Some(le_supported);
+        self
+    }
+
+    pub fn build(self) -> SimulateAdapterParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SimulateAdapterParameters {
+    const METHOD: &'static str = "bluetooth.simulateAdapter";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SimulateAdapterState {
+    Absent,
+    PoweredOff,
+    PoweredOn,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisableSimulation {
+    pub method: String,
+    pub params: DisableSimulationParameters,
+}
+
+impl DisableSimulation {
+    pub fn new(params: DisableSimulationParameters) -> Self {
+        Self {
+            method: DisableSimulationParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisableSimulationParameters {
+    pub context: BrowsingContext,
+}
+
+impl DisableSimulationParameters {
+    pub fn new(context: BrowsingContext) -> Self {
+        Self { context }
+    }
+}
+
+impl BidiCommand for DisableSimulationParameters {
+    const METHOD: &'static str = "bluetooth.disableSimulation";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulatePreconnectedPeripheral {
+    pub method: String,
+    pub params: SimulatePreconnectedPeripheralParameters,
+}
+
+impl SimulatePreconnectedPeripheral {
+    pub fn new(params: SimulatePreconnectedPeripheralParameters) -> Self {
+        Self {
+            method: SimulatePreconnectedPeripheralParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulatePreconnectedPeripheralParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+    pub name: String,
+    #[serde(rename = "manufacturerData")]
+    pub manufacturer_data: Vec<BluetoothManufacturerData>,
+    #[serde(rename = "knownServiceUuids")]
+    pub known_service_uuids: Vec<BluetoothUuid>,
+}
+
+impl SimulatePreconnectedPeripheralParameters {
+    pub fn new(
+        context: BrowsingContext,
+        address: String,
+        name: String,
+        manufacturer_data: Vec<BluetoothManufacturerData>,
+        known_service_uuids: Vec<BluetoothUuid>,
+    ) -> Self {
+        Self {
+            context,
+            address,
+            name,
+            manufacturer_data,
+            known_service_uuids,
+        }
+    }
+}
+
+impl BidiCommand for SimulatePreconnectedPeripheralParameters {
+    const METHOD: &'static str = "bluetooth.simulatePreconnectedPeripheral";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateAdvertisement {
+    pub method: String,
+    pub params: SimulateAdvertisementParameters,
+}
+
+impl SimulateAdvertisement {
+    pub fn new(params: SimulateAdvertisementParameters) -> Self {
+        Self {
+            method: SimulateAdvertisementParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateAdvertisementParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "scanEntry")]
+    pub scan_entry: SimulateAdvertisementScanEntryParameters,
+}
+
+impl SimulateAdvertisementParameters {
+    pub fn new(
+        context: BrowsingContext,
+        scan_entry: SimulateAdvertisementScanEntryParameters,
+    ) -> Self {
+        Self {
+            context,
+            scan_entry,
+        }
+    }
+}
+
+impl BidiCommand for SimulateAdvertisementParameters {
+    const METHOD: &'static str = "bluetooth.simulateAdvertisement";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateAdvertisementScanEntryParameters {
+    #[serde(rename = "deviceAddress")]
+    pub device_address: String,
+    pub rssi: f64,
+    #[serde(rename = "scanRecord")]
+    pub scan_record: ScanRecord,
+}
+
+impl SimulateAdvertisementScanEntryParameters {
+    pub fn new(device_address: String, rssi: f64, scan_record: ScanRecord) -> Self {
+        Self {
+            device_address,
+            rssi,
+            scan_record,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateGattConnectionResponse {
+    pub method: String,
+    pub params: SimulateGattConnectionResponseParameters,
+}
+
+impl SimulateGattConnectionResponse {
+    pub fn new(params: SimulateGattConnectionResponseParameters) -> Self {
+        Self {
+            method: SimulateGattConnectionResponseParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateGattConnectionResponseParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+    pub code: JsUint,
+}
+
+impl SimulateGattConnectionResponseParameters {
+    pub fn new(context: BrowsingContext, address: String, code: JsUint) -> Self {
+        Self {
+            context,
+            address,
+            code,
+        }
+    }
+}
+
+impl BidiCommand for SimulateGattConnectionResponseParameters {
+    const METHOD: &'static str = "bluetooth.simulateGattConnectionResponse";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateGattDisconnection {
+    pub method: String,
+    pub params: SimulateGattDisconnectionParameters,
+}
+
+impl SimulateGattDisconnection {
+    pub fn new(params: SimulateGattDisconnectionParameters) -> Self {
+        Self {
+            method: SimulateGattDisconnectionParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateGattDisconnectionParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+}
+
+impl SimulateGattDisconnectionParameters {
+    pub fn new(context: BrowsingContext, address: String) -> Self {
+        Self { context, address }
+    }
+}
+
+impl BidiCommand for SimulateGattDisconnectionParameters {
+    const METHOD: &'static str = "bluetooth.simulateGattDisconnection";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SimulationType {
+    Add,
+    Remove,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateService {
+    pub method: String,
+    pub params: SimulateServiceParameters,
+}
+
+impl SimulateService {
+    pub fn new(params: SimulateServiceParameters) -> Self {
+        Self {
+            method: SimulateServiceParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateServiceParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+    pub uuid: BluetoothUuid,
+    #[serde(rename = "type")]
+    pub simulation_type: SimulationType,
+}
+
+impl SimulateServiceParameters {
+    pub fn new(
+        context: BrowsingContext,
+        address: String,
+        uuid: BluetoothUuid,
+        simulation_type: SimulationType,
+    ) -> Self {
+        Self {
+            context,
+            address,
+            uuid,
+            simulation_type,
+        }
+    }
+}
+
+impl BidiCommand for SimulateServiceParameters {
+    const METHOD: &'static str = "bluetooth.simulateService";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateCharacteristic {
+    pub method: String,
+    pub params: SimulateCharacteristicParameters,
+}
+
+impl SimulateCharacteristic {
+    pub fn new(params: SimulateCharacteristicParameters) -> Self {
+        Self {
+            method: SimulateCharacteristicParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateCharacteristicParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+    #[serde(rename = "serviceUuid")]
+    pub service_uuid: BluetoothUuid,
+    #[serde(rename = "characteristicUuid")]
+    pub characteristic_uuid: BluetoothUuid,
+    #[serde(
+        rename = "characteristicProperties",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub characteristic_properties: Option<CharacteristicProperties>,
+    #[serde(rename = "type")]
+    pub simulation_type: SimulationType,
+}
+
+impl SimulateCharacteristicParameters {
+    pub fn new(
+        context: BrowsingContext,
+        address: String,
+        service_uuid: BluetoothUuid,
+        characteristic_uuid: BluetoothUuid,
+        characteristic_properties: Option<CharacteristicProperties>,
+        simulation_type: SimulationType,
+    ) -> Self {
+        Self {
+            context,
+            address,
+            service_uuid,
+            characteristic_uuid,
+            characteristic_properties,
+            simulation_type,
+        }
+    }
+
+    pub fn builder(
+        context: BrowsingContext,
+        address: String,
+        service_uuid: BluetoothUuid,
+        characteristic_uuid: BluetoothUuid,
+        simulation_type: SimulationType,
+    ) -> SimulateCharacteristicParametersBuilder {
+        SimulateCharacteristicParametersBuilder {
+            inner: Self::new(
+                context,
+                address,
+                service_uuid,
+                characteristic_uuid,
+                None,
+                simulation_type,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SimulateCharacteristicParametersBuilder {
+    inner: SimulateCharacteristicParameters,
+}
+
+impl SimulateCharacteristicParametersBuilder {
+    pub fn characteristic_properties(
+        mut self,
+        characteristic_properties: CharacteristicProperties,
+    ) -> Self {
+        self.inner.characteristic_properties = Some(characteristic_properties);
+        self
+    }
+
+    pub fn build(self) -> SimulateCharacteristicParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SimulateCharacteristicParameters {
+    const METHOD: &'static str = "bluetooth.simulateCharacteristic";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateCharacteristicResponse {
+    pub method: String,
+    pub params: SimulateCharacteristicResponseParameters,
+}
+
+impl SimulateCharacteristicResponse {
+    pub fn new(params: SimulateCharacteristicResponseParameters) -> Self {
+        Self {
+            method: SimulateCharacteristicResponseParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateCharacteristicResponseParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+    #[serde(rename = "serviceUuid")]
+    pub service_uuid: BluetoothUuid,
+    #[serde(rename = "characteristicUuid")]
+    pub characteristic_uuid: BluetoothUuid,
+    #[serde(rename = "type")]
+    pub response_type: CharacteristicResponseType,
+    pub code: JsUint,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<JsUint>>,
+}
+
+impl SimulateCharacteristicResponseParameters {
+    pub fn new(
+        context: BrowsingContext,
+        address: String,
+        service_uuid: BluetoothUuid,
+        characteristic_uuid: BluetoothUuid,
+        response_type: CharacteristicResponseType,
+        code: JsUint,
+        data: Option<Vec<JsUint>>,
+    ) -> Self {
+        Self {
+            context,
+            address,
+            service_uuid,
+            characteristic_uuid,
+            response_type,
+            code,
+            data,
+        }
+    }
+
+    pub fn builder(
+        context: BrowsingContext,
+        address: String,
+        service_uuid: BluetoothUuid,
+        characteristic_uuid: BluetoothUuid,
+        response_type: CharacteristicResponseType,
+        code: JsUint,
+    ) -> SimulateCharacteristicResponseParametersBuilder {
+        SimulateCharacteristicResponseParametersBuilder {
+            inner: Self::new(
+                context,
+                address,
+                service_uuid,
+                characteristic_uuid,
+                response_type,
+                code,
+                None,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SimulateCharacteristicResponseParametersBuilder {
+    inner: SimulateCharacteristicResponseParameters,
+}
+
+impl SimulateCharacteristicResponseParametersBuilder {
+    pub fn data(mut self, data: Vec<JsUint>) -> Self {
+        self.inner.data = Some(data);
+        self
+    }
+
+    pub fn build(self) -> SimulateCharacteristicResponseParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SimulateCharacteristicResponseParameters {
+    const METHOD: &'static str = "bluetooth.simulateCharacteristicResponse";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CharacteristicResponseType {
+    Read,
+    Write,
+    SubscribeToNotifications,
+    UnsubscribeFromNotifications,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateDescriptor {
+    pub method: String,
+    pub params: SimulateDescriptorParameters,
+}
+
+impl SimulateDescriptor {
+    pub fn new(params: SimulateDescriptorParameters) -> Self {
+        Self {
+            method: SimulateDescriptorParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateDescriptorParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+    #[serde(rename = "serviceUuid")]
+    pub service_uuid: BluetoothUuid,
+    #[serde(rename = "characteristicUuid")]
+    pub characteristic_uuid: BluetoothUuid,
+    #[serde(rename = "descriptorUuid")]
+    pub descriptor_uuid: BluetoothUuid,
+    #[serde(rename = "type")]
+    pub simulation_type: SimulationType,
+}
+
+impl SimulateDescriptorParameters {
+    pub fn new(
+        context: BrowsingContext,
+        address: String,
+        service_uuid: BluetoothUuid,
+        characteristic_uuid: BluetoothUuid,
+        descriptor_uuid: BluetoothUuid,
+        simulation_type: SimulationType,
+    ) -> Self {
+        Self {
+            context,
+            address,
+            service_uuid,
+            characteristic_uuid,
+            descriptor_uuid,
+            simulation_type,
+        }
+    }
+}
+
+impl BidiCommand for SimulateDescriptorParameters {
+    const METHOD: &'static str = "bluetooth.simulateDescriptor";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateDescriptorResponse {
+    pub method: String,
+    pub params: SimulateDescriptorResponseParameters,
+}
+
+impl SimulateDescriptorResponse {
+    pub fn new(params: SimulateDescriptorResponseParameters) -> Self {
+        Self {
+            method: SimulateDescriptorResponseParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SimulateDescriptorResponseParameters {
+    pub context: BrowsingContext,
+    pub address: String,
+    #[serde(rename = "serviceUuid")]
+    pub service_uuid: BluetoothUuid,
+    #[serde(rename = "characteristicUuid")]
+    pub characteristic_uuid: BluetoothUuid,
+    #[serde(rename = "descriptorUuid")]
+    pub descriptor_uuid: BluetoothUuid,
+    #[serde(rename = "type")]
+    pub response_type: DescriptorResponseType,
+    pub code: JsUint,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<JsUint>>,
+}
+
+impl SimulateDescriptorResponseParameters {
+    pub fn new(
+        context: BrowsingContext,
+        address: String,
+        service_uuid: BluetoothUuid,
+        characteristic_uuid: BluetoothUuid,
+        descriptor_uuid: BluetoothUuid,
+        response_type: DescriptorResponseType,
+        code: JsUint,
+        data: Option<Vec<JsUint>>,
+    ) -> Self {
+        Self {
+            context,
+            address,
+            service_uuid,
+            characteristic_uuid,
+            descriptor_uuid,
+            response_type,
+            code,
+            data,
+        }
+    }
+
+    pub fn builder(
+        context: BrowsingContext,
+        address: String,
+        service_uuid: BluetoothUuid,
+        characteristic_uuid: BluetoothUuid,
+        descriptor_uuid: BluetoothUuid,
+        response_type: DescriptorResponseType,
+        code: JsUint,
+    ) -> SimulateDescriptorResponseParametersBuilder {
+        SimulateDescriptorResponseParametersBuilder {
+            inner: Self::new(
+                context,
+                address,
+                service_uuid,
+                characteristic_uuid,
+                descriptor_uuid,
+                response_type,
+                code,
+                None,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SimulateDescriptorResponseParametersBuilder {
+    inner: SimulateDescriptorResponseParameters,
+}
+
+impl SimulateDescriptorResponseParametersBuilder {
+    pub fn data(mut self, data: Vec<JsUint>) -> Self {
+        self.inner.data = Some(data);
+        self
+    }
+
+    pub fn build(self) -> SimulateDescriptorResponseParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SimulateDescriptorResponseParameters {
+    const METHOD: &'static str = "bluetooth.simulateDescriptorResponse";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DescriptorResponseType {
+    Read,
+    Write,
+}
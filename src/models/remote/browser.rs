@@ -1,4 +1,9 @@
-use crate::remote::{EmptyParams, JsInt, JsUint};
+#![allow(clippy::all)]
+
+use crate::local;
+use crate::remote::command::BidiCommand;
+use crate::remote::session::{ProxyConfiguration, UserPromptHandler};
+use crate::remote::{JsInt, JsUint};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,7 +23,7 @@ pub struct ClientWindowInfo {
     #[serde(rename = "clientWindow")]
     pub client_window: ClientWindow,
     pub height: JsUint,
-    pub state: ClientWindowState,
+    pub state: ClientWindowInfoState,
     pub width: JsUint,
     pub x: JsInt,
     pub y: JsInt,
@@ -29,7 +34,7 @@ impl ClientWindowInfo {
         active: bool,
         client_window: ClientWindow,
         height: JsUint,
-        state: ClientWindowState,
+        state: ClientWindowInfoState,
         width: JsUint,
         x: JsInt,
         y: JsInt,
@@ -48,15 +53,23 @@ impl ClientWindowInfo {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
-pub enum ClientWindowState {
+pub enum ClientWindowInfoState {
     Fullscreen,
     Maximized,
     Minimized,
     Normal,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClientWindowState {
+    Fullscreen,
+    Maximized,
+    Minimized,
+}
+
 pub type ClientWindow = String;
-pub type UserContext = String;
+pub use crate::models::ids::UserContext;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserContextInfo {
@@ -73,63 +86,175 @@ impl UserContextInfo {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Close {
     pub method: String,
-    pub params: EmptyParams,
+    pub params: CloseParameters,
 }
 
 impl Close {
-    pub fn new(params: EmptyParams) -> Self {
+    pub fn new(params: CloseParameters) -> Self {
         Self {
-            method: "browser.close".to_string(),
+            method: CloseParameters::METHOD.to_string(),
             params,
         }
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CloseParameters {}
+
+impl CloseParameters {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BidiCommand for CloseParameters {
+    const METHOD: &'static str = "browser.close";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CreateUserContext {
     pub method: String,
-    pub params: EmptyParams,
+    pub params: CreateUserContextParameters,
 }
 
 impl CreateUserContext {
-    pub fn new(params: EmptyParams) -> Self {
+    pub fn new(params: CreateUserContextParameters) -> Self {
         Self {
-            method: "browser.createUserContext".to_string(),
+            method: CreateUserContextParameters::METHOD.to_string(),
             params,
         }
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateUserContextParameters {
+    #[serde(
+        rename = "acceptInsecureCerts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub accept_insecure_certs: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfiguration>,
+    #[serde(
+        rename = "unhandledPromptBehavior",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub unhandled_prompt_behavior: Option<UserPromptHandler>,
+}
+
+impl CreateUserContextParameters {
+    pub fn new(
+        accept_insecure_certs: Option<bool>,
+        proxy: Option<ProxyConfiguration>,
+        unhandled_prompt_behavior: Option<UserPromptHandler>,
+    ) -> Self {
+        Self {
+            accept_insecure_certs,
+            proxy,
+            unhandled_prompt_behavior,
+        }
+    }
+
+    pub fn builder() -> CreateUserContextParametersBuilder {
+        CreateUserContextParametersBuilder {
+            inner: Self::new(None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateUserContextParametersBuilder {
+    inner: CreateUserContextParameters,
+}
+
+impl CreateUserContextParametersBuilder {
+    pub fn accept_insecure_certs(mut self, accept_insecure_certs: bool) -> Self {
+        self.inner.accept_insecure_certs = Some(accept_insecure_certs);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: ProxyConfiguration) -> Self {
+        self.inner.proxy = Some(proxy);
+        self
+    }
+
+    pub fn unhandled_prompt_behavior(
+        mut self,
+        unhandled_prompt_behavior: UserPromptHandler,
+    ) -> Self {
+        self.inner.unhandled_prompt_behavior = Some(unhandled_prompt_behavior);
+        self
+    }
+
+    pub fn build(self) -> CreateUserContextParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for CreateUserContextParameters {
+    const METHOD: &'static str = "browser.createUserContext";
+    type Result = local::browser::CreateUserContextResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetClientWindows {
     pub method: String,
-    pub params: EmptyParams,
+    pub params: GetClientWindowsParameters,
 }
 
 impl GetClientWindows {
-    pub fn new(params: EmptyParams) -> Self {
+    pub fn new(params: GetClientWindowsParameters) -> Self {
         Self {
-            method: "browser.getClientWindows".to_string(),
+            method: GetClientWindowsParameters::METHOD.to_string(),
             params,
         }
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetClientWindowsParameters {}
+
+impl GetClientWindowsParameters {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BidiCommand for GetClientWindowsParameters {
+    const METHOD: &'static str = "browser.getClientWindows";
+    type Result = local::browser::GetClientWindowsResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetUserContexts {
     pub method: String,
-    pub params: EmptyParams,
+    pub params: GetUserContextsParameters,
 }
 
 impl GetUserContexts {
-    pub fn new(params: EmptyParams) -> Self {
+    pub fn new(params: GetUserContextsParameters) -> Self {
         Self {
-            method: "browser.getUserContexts".to_string(),
+            method: GetUserContextsParameters::METHOD.to_string(),
             params,
         }
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GetUserContextsParameters {}
+
+impl GetUserContextsParameters {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BidiCommand for GetUserContextsParameters {
+    const METHOD: &'static str = "browser.getUserContexts";
+    type Result = local::browser::GetUserContextsResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RemoveUserContext {
     pub method: String,
@@ -139,7 +264,7 @@ pub struct RemoveUserContext {
 impl RemoveUserContext {
     pub fn new(params: RemoveUserContextParameters) -> Self {
         Self {
-            method: "browser.removeUserContext".to_string(),
+            method: RemoveUserContextParameters::METHOD.to_string(),
             params,
         }
     }
@@ -157,6 +282,11 @@ impl RemoveUserContextParameters {
     }
 }
 
+impl BidiCommand for RemoveUserContextParameters {
+    const METHOD: &'static str = "browser.removeUserContext";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetClientWindowState {
     pub method: String,
@@ -166,7 +296,7 @@ pub struct SetClientWindowState {
 impl SetClientWindowState {
     pub fn new(params: SetClientWindowStateParameters) -> Self {
         Self {
-            method: "browser.setClientWindowState".to_string(),
+            method: SetClientWindowStateParameters::METHOD.to_string(),
             params,
         }
     }
@@ -176,7 +306,7 @@ impl SetClientWindowState {
 pub struct SetClientWindowStateParameters {
     #[serde(rename = "clientWindow")]
     pub client_window: ClientWindow,
-    #[serde(rename = "clientWindowNamedState")]
+    #[serde(flatten)]
     pub client_window_named_state: ClientWindowNamedOrRectState,
 }
 
@@ -192,6 +322,11 @@ impl SetClientWindowStateParameters {
     }
 }
 
+impl BidiCommand for SetClientWindowStateParameters {
+    const METHOD: &'static str = "browser.setClientWindowState";
+    type Result = local::browser::ClientWindowInfo;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ClientWindowNamedOrRectState {
@@ -210,9 +345,10 @@ impl ClientWindowNamedState {
     }
 }
 
+/// The `normal` state, optionally with the window's new size and position.
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "state", rename = "normal")]
 pub struct ClientWindowRectState {
-    pub state: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<JsUint>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,18 +361,53 @@ pub struct ClientWindowRectState {
 
 impl ClientWindowRectState {
     pub fn new(
-        state: String,
         width: Option<JsUint>,
         height: Option<JsUint>,
         x: Option<JsInt>,
         y: Option<JsInt>,
     ) -> Self {
         Self {
-            state,
             width,
             height,
             x,
             y,
         }
     }
+
+    pub fn builder() -> ClientWindowRectStateBuilder {
+        ClientWindowRectStateBuilder {
+            inner: Self::new(None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ClientWindowRectStateBuilder {
+    inner: ClientWindowRectState,
+}
+
+impl ClientWindowRectStateBuilder {
+    pub fn width(mut self, width: JsUint) -> Self {
+        self.inner.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: JsUint) -> Self {
+        self.inner.height = Some(height);
+        self
+    }
+
+    pub fn x(mut self, x: JsInt) -> Self {
+        self.inner.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: JsInt) -> Self {
+        self.inner.y = Some(y);
+        self
+    }
+
+    pub fn build(self) -> ClientWindowRectState {
+        self.inner
+    }
 }
@@ -1,11 +1,13 @@
 #![allow(clippy::all)]
 
+use crate::local;
 use crate::remote::browser;
+use crate::remote::command::BidiCommand;
 use crate::remote::script::{SerializationOptions, SharedReference};
 use crate::remote::{JsInt, JsUint};
 use serde::{Deserialize, Serialize};
 
-pub type BrowsingContext = String;
+pub use crate::models::ids::BrowsingContext;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -25,28 +27,28 @@ pub enum BrowsingContextCommand {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum Locator {
+    #[serde(rename = "accessibility")]
     AccessibilityLocator(AccessibilityLocator),
+    #[serde(rename = "css")]
     CssLocator(CssLocator),
+    #[serde(rename = "context")]
     ContextLocator(ContextLocator),
+    #[serde(rename = "innerText")]
     InnerTextLocator(InnerTextLocator),
+    #[serde(rename = "xpath")]
     XPathLocator(XPathLocator),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AccessibilityLocator {
-    #[serde(rename = "type")]
-    pub locator_type: String,
     pub value: AccessibilityLocatorValue,
 }
 
 impl AccessibilityLocator {
     pub fn new(value: AccessibilityLocatorValue) -> Self {
-        Self {
-            locator_type: "accessibility".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -62,37 +64,54 @@ impl AccessibilityLocatorValue {
     pub fn new(name: Option<String>, role: Option<String>) -> Self {
         Self { name, role }
     }
+
+    pub fn builder() -> AccessibilityLocatorValueBuilder {
+        AccessibilityLocatorValueBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AccessibilityLocatorValueBuilder {
+    inner: AccessibilityLocatorValue,
+}
+
+impl AccessibilityLocatorValueBuilder {
+    pub fn name(mut self, name: String) -> Self {
+        self.inner.name = Some(name);
+        self
+    }
+
+    pub fn role(mut self, role: String) -> Self {
+        self.inner.role = Some(role);
+        self
+    }
+
+    pub fn build(self) -> AccessibilityLocatorValue {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CssLocator {
-    #[serde(rename = "type")]
-    pub locator_type: String,
     pub value: String,
 }
 
 impl CssLocator {
     pub fn new(value: String) -> Self {
-        Self {
-            locator_type: "css".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ContextLocator {
-    #[serde(rename = "type")]
-    pub locator_type: String,
     pub value: ContextValue,
 }
 
 impl ContextLocator {
     pub fn new(value: ContextValue) -> Self {
-        Self {
-            locator_type: "context".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -109,8 +128,6 @@ impl ContextValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InnerTextLocator {
-    #[serde(rename = "type")]
-    pub locator_type: String,
     pub value: String,
     #[serde(rename = "ignoreCase", skip_serializing_if = "Option::is_none")]
     pub ignore_case: Option<bool>,
@@ -128,13 +145,44 @@ impl InnerTextLocator {
         max_depth: Option<JsUint>,
     ) -> Self {
         Self {
-            locator_type: "innerText".to_string(),
             value,
             ignore_case,
             match_type,
             max_depth,
         }
     }
+
+    pub fn builder(value: String) -> InnerTextLocatorBuilder {
+        InnerTextLocatorBuilder {
+            inner: Self::new(value, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InnerTextLocatorBuilder {
+    inner: InnerTextLocator,
+}
+
+impl InnerTextLocatorBuilder {
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.inner.ignore_case = Some(ignore_case);
+        self
+    }
+
+    pub fn match_type(mut self, match_type: InnerTextLocatorMatchType) -> Self {
+        self.inner.match_type = Some(match_type);
+        self
+    }
+
+    pub fn max_depth(mut self, max_depth: JsUint) -> Self {
+        self.inner.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn build(self) -> InnerTextLocator {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -146,17 +194,12 @@ pub enum InnerTextLocatorMatchType {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct XPathLocator {
-    #[serde(rename = "type")]
-    pub locator_type: String,
     pub value: String,
 }
 
 impl XPathLocator {
     pub fn new(value: String) -> Self {
-        Self {
-            locator_type: "xpath".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -186,7 +229,7 @@ pub struct Activate {
 impl Activate {
     pub fn new(params: ActivateParameters) -> Self {
         Self {
-            method: "browsingContext.activate".to_string(),
+            method: ActivateParameters::METHOD.to_string(),
             params,
         }
     }
@@ -203,6 +246,11 @@ impl ActivateParameters {
     }
 }
 
+impl BidiCommand for ActivateParameters {
+    const METHOD: &'static str = "browsingContext.activate";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CaptureScreenshot {
     pub method: String,
@@ -212,7 +260,7 @@ pub struct CaptureScreenshot {
 impl CaptureScreenshot {
     pub fn new(params: CaptureScreenshotParameters) -> Self {
         Self {
-            method: "browsingContext.captureScreenshot".to_string(),
+            method: CaptureScreenshotParameters::METHOD.to_string(),
             params,
         }
     }
@@ -243,6 +291,43 @@ impl CaptureScreenshotParameters {
             clip,
         }
     }
+
+    pub fn builder(context: BrowsingContext) -> CaptureScreenshotParametersBuilder {
+        CaptureScreenshotParametersBuilder {
+            inner: Self::new(context, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CaptureScreenshotParametersBuilder {
+    inner: CaptureScreenshotParameters,
+}
+
+impl CaptureScreenshotParametersBuilder {
+    pub fn origin(mut self, origin: CaptureScreenshotParametersOrigin) -> Self {
+        self.inner.origin = Some(origin);
+        self
+    }
+
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.inner.format = Some(format);
+        self
+    }
+
+    pub fn clip(mut self, clip: ClipRectangle) -> Self {
+        self.inner.clip = Some(clip);
+        self
+    }
+
+    pub fn build(self) -> CaptureScreenshotParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for CaptureScreenshotParameters {
+    const METHOD: &'static str = "browsingContext.captureScreenshot";
+    type Result = local::browsing_context::CaptureScreenshotResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -270,32 +355,27 @@ impl ImageFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum ClipRectangle {
+    #[serde(rename = "box")]
     BoxClipRectangle(BoxClipRectangle),
+    #[serde(rename = "element")]
     ElementClipRectangle(ElementClipRectangle),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ElementClipRectangle {
-    #[serde(rename = "type")]
-    pub clip_rectangle_type: String,
     pub element: SharedReference,
 }
 
 impl ElementClipRectangle {
     pub fn new(element: SharedReference) -> Self {
-        Self {
-            clip_rectangle_type: "element".to_string(),
-            element,
-        }
+        Self { element }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BoxClipRectangle {
-    #[serde(rename = "type")]
-    pub clip_rectangle_type: String,
     pub x: f32,
     pub y: f32,
     pub width: f32,
@@ -305,7 +385,6 @@ pub struct BoxClipRectangle {
 impl BoxClipRectangle {
     pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
         Self {
-            clip_rectangle_type: "box".to_string(),
             x,
             y,
             width,
@@ -323,7 +402,7 @@ pub struct Close {
 impl Close {
     pub fn new(params: CloseParameters) -> Self {
         Self {
-            method: "browsingContext.close".to_string(),
+            method: CloseParameters::METHOD.to_string(),
             params,
         }
     }
@@ -343,6 +422,33 @@ impl CloseParameters {
             prompt_unload,
         }
     }
+
+    pub fn builder(context: BrowsingContext) -> CloseParametersBuilder {
+        CloseParametersBuilder {
+            inner: Self::new(context, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CloseParametersBuilder {
+    inner: CloseParameters,
+}
+
+impl CloseParametersBuilder {
+    pub fn prompt_unload(mut self, prompt_unload: bool) -> Self {
+        self.inner.prompt_unload = Some(prompt_unload);
+        self
+    }
+
+    pub fn build(self) -> CloseParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for CloseParameters {
+    const METHOD: &'static str = "browsingContext.close";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -354,7 +460,7 @@ pub struct Create {
 impl Create {
     pub fn new(params: CreateParameters) -> Self {
         Self {
-            method: "browsingContext.create".to_string(),
+            method: CreateParameters::METHOD.to_string(),
             params,
         }
     }
@@ -393,6 +499,43 @@ impl CreateParameters {
             user_context,
         }
     }
+
+    pub fn builder(create_type: CreateType) -> CreateParametersBuilder {
+        CreateParametersBuilder {
+            inner: Self::new(create_type, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CreateParametersBuilder {
+    inner: CreateParameters,
+}
+
+impl CreateParametersBuilder {
+    pub fn reference_context(mut self, reference_context: BrowsingContext) -> Self {
+        self.inner.reference_context = Some(reference_context);
+        self
+    }
+
+    pub fn background(mut self, background: bool) -> Self {
+        self.inner.background = Some(background);
+        self
+    }
+
+    pub fn user_context(mut self, user_context: browser::UserContext) -> Self {
+        self.inner.user_context = Some(user_context);
+        self
+    }
+
+    pub fn build(self) -> CreateParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for CreateParameters {
+    const METHOD: &'static str = "browsingContext.create";
+    type Result = local::browsing_context::CreateResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -404,7 +547,7 @@ pub struct GetTree {
 impl GetTree {
     pub fn new(params: GetTreeParameters) -> Self {
         Self {
-            method: "browsingContext.getTree".to_string(),
+            method: GetTreeParameters::METHOD.to_string(),
             params,
         }
     }
@@ -422,6 +565,38 @@ impl GetTreeParameters {
     pub fn new(max_depth: Option<JsUint>, root: Option<BrowsingContext>) -> Self {
         Self { max_depth, root }
     }
+
+    pub fn builder() -> GetTreeParametersBuilder {
+        GetTreeParametersBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetTreeParametersBuilder {
+    inner: GetTreeParameters,
+}
+
+impl GetTreeParametersBuilder {
+    pub fn max_depth(mut self, max_depth: JsUint) -> Self {
+        self.inner.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn root(mut self, root: BrowsingContext) -> Self {
+        self.inner.root = Some(root);
+        self
+    }
+
+    pub fn build(self) -> GetTreeParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for GetTreeParameters {
+    const METHOD: &'static str = "browsingContext.getTree";
+    type Result = local::browsing_context::GetTreeResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -433,7 +608,7 @@ pub struct HandleUserPrompt {
 impl HandleUserPrompt {
     pub fn new(params: HandleUserPromptParameters) -> Self {
         Self {
-            method: "browsingContext.handleUserPrompt".to_string(),
+            method: HandleUserPromptParameters::METHOD.to_string(),
             params,
         }
     }
@@ -444,7 +619,7 @@ pub struct HandleUserPromptParameters {
     pub context: BrowsingContext,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accept: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "userText", skip_serializing_if = "Option::is_none")]
     pub user_text: Option<String>,
 }
 
@@ -456,6 +631,38 @@ impl HandleUserPromptParameters {
             user_text,
         }
     }
+
+    pub fn builder(context: BrowsingContext) -> HandleUserPromptParametersBuilder {
+        HandleUserPromptParametersBuilder {
+            inner: Self::new(context, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct HandleUserPromptParametersBuilder {
+    inner: HandleUserPromptParameters,
+}
+
+impl HandleUserPromptParametersBuilder {
+    pub fn accept(mut self, accept: bool) -> Self {
+        self.inner.accept = Some(accept);
+        self
+    }
+
+    pub fn user_text(mut self, user_text: String) -> Self {
+        self.inner.user_text = Some(user_text);
+        self
+    }
+
+    pub fn build(self) -> HandleUserPromptParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for HandleUserPromptParameters {
+    const METHOD: &'static str = "browsingContext.handleUserPrompt";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -467,7 +674,7 @@ pub struct LocateNodes {
 impl LocateNodes {
     pub fn new(params: LocateNodesParameters) -> Self {
         Self {
-            method: "browsingContext.locateNodes".to_string(),
+            method: LocateNodesParameters::METHOD.to_string(),
             params,
         }
     }
@@ -504,6 +711,43 @@ impl LocateNodesParameters {
             start_nodes,
         }
     }
+
+    pub fn builder(context: BrowsingContext, locator: Locator) -> LocateNodesParametersBuilder {
+        LocateNodesParametersBuilder {
+            inner: Self::new(context, locator, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LocateNodesParametersBuilder {
+    inner: LocateNodesParameters,
+}
+
+impl LocateNodesParametersBuilder {
+    pub fn max_node_count(mut self, max_node_count: JsUint) -> Self {
+        self.inner.max_node_count = Some(max_node_count);
+        self
+    }
+
+    pub fn serialization_options(mut self, serialization_options: SerializationOptions) -> Self {
+        self.inner.serialization_options = Some(serialization_options);
+        self
+    }
+
+    pub fn start_nodes(mut self, start_nodes: Vec<SharedReference>) -> Self {
+        self.inner.start_nodes = Some(start_nodes);
+        self
+    }
+
+    pub fn build(self) -> LocateNodesParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for LocateNodesParameters {
+    const METHOD: &'static str = "browsingContext.locateNodes";
+    type Result = local::browsing_context::LocateNodesResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -515,7 +759,7 @@ pub struct Navigate {
 impl Navigate {
     pub fn new(params: NavigateParameters) -> Self {
         Self {
-            method: "browsingContext.navigate".to_string(),
+            method: NavigateParameters::METHOD.to_string(),
             params,
         }
     }
@@ -533,6 +777,33 @@ impl NavigateParameters {
     pub fn new(context: BrowsingContext, url: String, wait: Option<ReadinessState>) -> Self {
         Self { context, url, wait }
     }
+
+    pub fn builder(context: BrowsingContext, url: String) -> NavigateParametersBuilder {
+        NavigateParametersBuilder {
+            inner: Self::new(context, url, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NavigateParametersBuilder {
+    inner: NavigateParameters,
+}
+
+impl NavigateParametersBuilder {
+    pub fn wait(mut self, wait: ReadinessState) -> Self {
+        self.inner.wait = Some(wait);
+        self
+    }
+
+    pub fn build(self) -> NavigateParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for NavigateParameters {
+    const METHOD: &'static str = "browsingContext.navigate";
+    type Result = local::browsing_context::NavigateResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -544,7 +815,7 @@ pub struct Print {
 impl Print {
     pub fn new(params: PrintParameters) -> Self {
         Self {
-            method: "browsingContext.print".to_string(),
+            method: PrintParameters::METHOD.to_string(),
             params,
         }
     }
@@ -591,6 +862,63 @@ impl PrintParameters {
             shrink_to_fit,
         }
     }
+
+    pub fn builder(context: BrowsingContext) -> PrintParametersBuilder {
+        PrintParametersBuilder {
+            inner: Self::new(context, None, None, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PrintParametersBuilder {
+    inner: PrintParameters,
+}
+
+impl PrintParametersBuilder {
+    pub fn background(mut self, background: bool) -> Self {
+        self.inner.background = Some(background);
+        self
+    }
+
+    pub fn margin(mut self, margin: PrintMarginParameters) -> Self {
+        self.inner.margin = Some(margin);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: PrintParametersOrientation) -> Self {
+        self.inner.orientation = Some(orientation);
+        self
+    }
+
+    pub fn page(mut self, page: PrintPageParameters) -> Self {
+        self.inner.page = Some(page);
+        self
+    }
+
+    pub fn page_ranges(mut self, page_ranges: Vec<JsUintOrText>) -> Self {
+        self.inner.page_ranges = Some(page_ranges);
+        self
+    }
+
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.inner.scale = Some(scale);
+        self
+    }
+
+    pub fn shrink_to_fit(mut self, shrink_to_fit: bool) -> Self {
+        self.inner.shrink_to_fit = Some(shrink_to_fit);
+        self
+    }
+
+    pub fn build(self) -> PrintParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for PrintParameters {
+    const METHOD: &'static str = "browsingContext.print";
+    type Result = local::browsing_context::PrintResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -633,6 +961,43 @@ impl PrintMarginParameters {
             top,
         }
     }
+
+    pub fn builder() -> PrintMarginParametersBuilder {
+        PrintMarginParametersBuilder {
+            inner: Self::new(None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PrintMarginParametersBuilder {
+    inner: PrintMarginParameters,
+}
+
+impl PrintMarginParametersBuilder {
+    pub fn bottom(mut self, bottom: f32) -> Self {
+        self.inner.bottom = Some(bottom);
+        self
+    }
+
+    pub fn left(mut self, left: f32) -> Self {
+        self.inner.left = Some(left);
+        self
+    }
+
+    pub fn right(mut self, right: f32) -> Self {
+        self.inner.right = Some(right);
+        self
+    }
+
+    pub fn top(mut self, top: f32) -> Self {
+        self.inner.top = Some(top);
+        self
+    }
+
+    pub fn build(self) -> PrintMarginParameters {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -647,6 +1012,33 @@ impl PrintPageParameters {
     pub fn new(height: Option<f32>, width: Option<f32>) -> Self {
         Self { height, width }
     }
+
+    pub fn builder() -> PrintPageParametersBuilder {
+        PrintPageParametersBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PrintPageParametersBuilder {
+    inner: PrintPageParameters,
+}
+
+impl PrintPageParametersBuilder {
+    pub fn height(mut self, height: f32) -> Self {
+        self.inner.height = Some(height);
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.inner.width = Some(width);
+        self
+    }
+
+    pub fn build(self) -> PrintPageParameters {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -658,7 +1050,7 @@ pub struct Reload {
 impl Reload {
     pub fn new(params: ReloadParameters) -> Self {
         Self {
-            method: "browsingContext.reload".to_string(),
+            method: ReloadParameters::METHOD.to_string(),
             params,
         }
     }
@@ -685,6 +1077,38 @@ impl ReloadParameters {
             wait,
         }
     }
+
+    pub fn builder(context: BrowsingContext) -> ReloadParametersBuilder {
+        ReloadParametersBuilder {
+            inner: Self::new(context, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReloadParametersBuilder {
+    inner: ReloadParameters,
+}
+
+impl ReloadParametersBuilder {
+    pub fn ignore_cache(mut self, ignore_cache: bool) -> Self {
+        self.inner.ignore_cache = Some(ignore_cache);
+        self
+    }
+
+    pub fn wait(mut self, wait: ReadinessState) -> Self {
+        self.inner.wait = Some(wait);
+        self
+    }
+
+    pub fn build(self) -> ReloadParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for ReloadParameters {
+    const METHOD: &'static str = "browsingContext.reload";
+    type Result = local::browsing_context::NavigateResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -696,7 +1120,7 @@ pub struct SetViewport {
 impl SetViewport {
     pub fn new(params: SetViewportParameters) -> Self {
         Self {
-            method: "browsingContext.setViewport".to_string(),
+            method: SetViewportParameters::METHOD.to_string(),
             params,
         }
     }
@@ -723,6 +1147,38 @@ impl SetViewportParameters {
             device_pixel_ratio,
         }
     }
+
+    pub fn builder(context: BrowsingContext) -> SetViewportParametersBuilder {
+        SetViewportParametersBuilder {
+            inner: Self::new(context, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SetViewportParametersBuilder {
+    inner: SetViewportParameters,
+}
+
+impl SetViewportParametersBuilder {
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.inner.viewport = Some(viewport);
+        self
+    }
+
+    pub fn device_pixel_ratio(mut self, device_pixel_ratio: f32) -> Self {
+        self.inner.device_pixel_ratio = Some(device_pixel_ratio);
+        self
+    }
+
+    pub fn build(self) -> SetViewportParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SetViewportParameters {
+    const METHOD: &'static str = "browsingContext.setViewport";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -746,7 +1202,7 @@ pub struct TraverseHistory {
 impl TraverseHistory {
     pub fn new(params: TraverseHistoryParameters) -> Self {
         Self {
-            method: "browsingContext.traverseHistory".into(),
+            method: TraverseHistoryParameters::METHOD.into(),
             params,
         }
     }
@@ -759,7 +1215,12 @@ pub struct TraverseHistoryParameters {
 }
 
 impl TraverseHistoryParameters {
-    pub fn new(context: String, delta: i64) -> Self {
+    pub fn new(context: BrowsingContext, delta: JsInt) -> Self {
         Self { context, delta }
     }
 }
+
+impl BidiCommand for TraverseHistoryParameters {
+    const METHOD: &'static str = "browsingContext.traverseHistory";
+    type Result = local::browsing_context::TraverseHistoryResult;
+}
@@ -1,18 +1,63 @@
+use crate::remote::bluetooth::BluetoothCommand;
 use crate::remote::browser::BrowserCommand;
 use crate::remote::browsing_context::BrowsingContextCommand;
 use crate::remote::input::InputCommand;
 use crate::remote::network::NetworkCommand;
+use crate::remote::permissions::PermissionsCommand;
 use crate::remote::script::ScriptCommand;
 use crate::remote::session::SessionCommand;
 use crate::remote::storage::StorageCommand;
+use crate::remote::web_authn::WebAuthnCommand;
 use crate::remote::web_extension::WebExtensionCommand;
 use crate::remote::Extensible;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+/// Binds the parameters of a command to its method name and result type.
+///
+/// Implemented for every `*Parameters` type, so that the parameters alone
+/// determine which command is sent and how its result is deserialized.
+pub trait BidiCommand: Serialize {
+    /// The method name of the command, e.g. `browsingContext.navigate`.
+    const METHOD: &'static str;
+    /// The type of the `result` field of a successful response.
+    type Result: DeserializeOwned;
+
+    /// Attaches extension parameters (e.g. `goog:*` or `moz:*`), which are
+    /// flattened into the command's `params` object.
+    fn with_extensions(self, extensible: Extensible) -> WithExtensions<Self>
+    where
+        Self: Sized,
+    {
+        WithExtensions::new(self, extensible)
+    }
+}
+
+/// Command parameters extended with additional, flattened extension parameters.
+#[derive(Debug, Serialize)]
+pub struct WithExtensions<P> {
+    #[serde(flatten)]
+    pub params: P,
+    #[serde(flatten)]
+    pub extensible: Extensible,
+}
+
+impl<P> WithExtensions<P> {
+    pub fn new(params: P, extensible: Extensible) -> Self {
+        Self { params, extensible }
+    }
+}
+
+impl<P: BidiCommand> BidiCommand for WithExtensions<P> {
+    const METHOD: &'static str = P::METHOD;
+    type Result = P::Result;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Command {
     pub id: u64,
     pub command_data: CommandData,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
@@ -29,12 +74,15 @@ impl Command {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum CommandData {
+    BluetoothCommand(BluetoothCommand),
     BrowserCommand(BrowserCommand),
     BrowsingContextCommand(BrowsingContextCommand),
     InputCommand(InputCommand),
     NetworkCommand(NetworkCommand),
+    PermissionsCommand(PermissionsCommand),
     ScriptCommand(ScriptCommand),
     SessionCommand(SessionCommand),
     StorageCommand(StorageCommand),
+    WebAuthnCommand(WebAuthnCommand),
     WebExtensionCommand(WebExtensionCommand),
 }
@@ -1,9 +1,10 @@
 #![allow(clippy::all)]
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
-pub type Extensible = HashMap<String, String>;
+pub type Extensible = HashMap<String, Value>;
 
 // -9007199254740991..9007199254740991
 pub type JsInt = i64;
@@ -12,6 +13,7 @@ pub type JsUint = u64;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EmptyParams {
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
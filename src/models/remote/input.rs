@@ -1,4 +1,6 @@
+use crate::local;
 use crate::remote::browsing_context::BrowsingContext;
+use crate::remote::command::BidiCommand;
 use crate::remote::script::SharedReference;
 use crate::remote::{JsInt, JsUint};
 use serde::{Deserialize, Serialize};
@@ -12,18 +14,14 @@ pub enum InputCommand {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "element")]
 pub struct ElementOrigin {
-    #[serde(rename = "type")]
-    pub element_origin_type: String,
     pub element: SharedReference,
 }
 
 impl ElementOrigin {
     pub fn new(element: SharedReference) -> Self {
-        Self {
-            element_origin_type: "element".to_string(),
-            element,
-        }
+        Self { element }
     }
 }
 
@@ -36,7 +34,7 @@ pub struct PerformActions {
 impl PerformActions {
     pub fn new(params: PerformActionsParameters) -> Self {
         Self {
-            method: "input.performActions".to_string(),
+            method: PerformActionsParameters::METHOD.to_string(),
             params,
         }
     }
@@ -54,66 +52,70 @@ impl PerformActionsParameters {
     }
 }
 
+impl BidiCommand for PerformActionsParameters {
+    const METHOD: &'static str = "input.performActions";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum SourceActions {
+    #[serde(rename = "none")]
     NoneSourceActions(NoneSourceActions),
+    #[serde(rename = "key")]
     KeySourceActions(KeySourceActions),
+    #[serde(rename = "pointer")]
     PointerSourceActions(PointerSourceActions),
+    #[serde(rename = "wheel")]
     WheelSourceActions(WheelSourceActions),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NoneSourceActions {
-    #[serde(rename = "type")]
-    pub none_source_actions_type: String,
     pub id: String,
     pub actions: Vec<NoneSourceAction>,
 }
 
 impl NoneSourceActions {
     pub fn new(id: String, actions: Vec<NoneSourceAction>) -> Self {
-        Self {
-            none_source_actions_type: "none".to_string(),
-            id,
-            actions,
-        }
+        Self { id, actions }
     }
 }
 
-pub type NoneSourceAction = PauseAction;
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum NoneSourceAction {
+    #[serde(rename = "pause")]
+    PauseAction(PauseAction),
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct KeySourceActions {
-    #[serde(rename = "type")]
-    pub key_source_actions_type: String,
     pub id: String,
     pub actions: Vec<KeySourceAction>,
 }
 
 impl KeySourceActions {
     pub fn new(id: String, actions: Vec<KeySourceAction>) -> Self {
-        Self {
-            key_source_actions_type: "key".to_string(),
-            id,
-            actions,
-        }
+        Self { id, actions }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum KeySourceAction {
+    #[serde(rename = "pause")]
     PauseAction(PauseAction),
+    #[serde(rename = "keyDown")]
     KeyDownAction(KeyDownAction),
+    #[serde(rename = "keyUp")]
     KeyUpAction(KeyUpAction),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PointerSourceActions {
-    #[serde(rename = "type")]
-    pub pointer_source_actions_type: String,
     pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<PointerParameters>,
     pub actions: Vec<PointerSourceAction>,
 }
@@ -125,7 +127,6 @@ impl PointerSourceActions {
         actions: Vec<PointerSourceAction>,
     ) -> Self {
         Self {
-            pointer_source_actions_type: "pointer".to_string(),
             id,
             parameters,
             actions,
@@ -151,111 +152,111 @@ impl PointerParameters {
     pub fn new(pointer_type: Option<PointerType>) -> Self {
         Self { pointer_type }
     }
+
+    pub fn builder() -> PointerParametersBuilder {
+        PointerParametersBuilder {
+            inner: Self::new(None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PointerParametersBuilder {
+    inner: PointerParameters,
+}
+
+impl PointerParametersBuilder {
+    pub fn pointer_type(mut self, pointer_type: PointerType) -> Self {
+        self.inner.pointer_type = Some(pointer_type);
+        self
+    }
+
+    pub fn build(self) -> PointerParameters {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum PointerSourceAction {
+    #[serde(rename = "pause")]
     PauseAction(PauseAction),
+    #[serde(rename = "pointerDown")]
     PointerDownAction(PointerDownAction),
+    #[serde(rename = "pointerUp")]
     PointerUpAction(PointerUpAction),
+    #[serde(rename = "pointerMove")]
     PointerMoveAction(PointerMoveAction),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WheelSourceActions {
-    #[serde(rename = "type")]
-    pub wheel_source_actions_type: String,
     pub id: String,
     pub actions: Vec<WheelSourceAction>,
 }
 
 impl WheelSourceActions {
     pub fn new(id: String, actions: Vec<WheelSourceAction>) -> Self {
-        Self {
-            wheel_source_actions_type: "wheel".to_string(),
-            id,
-            actions,
-        }
+        Self { id, actions }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum WheelSourceAction {
+    #[serde(rename = "pause")]
     PauseAction(PauseAction),
+    #[serde(rename = "scroll")]
     WheelScrollAction(WheelScrollAction),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PauseAction {
-    #[serde(rename = "type")]
-    pub pause_action_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<JsUint>,
 }
 
 impl PauseAction {
     pub fn new(duration: Option<JsUint>) -> Self {
-        Self {
-            pause_action_type: "pause".to_string(),
-            duration,
-        }
+        Self { duration }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct KeyDownAction {
-    #[serde(rename = "type")]
-    pub key_down_action_type: String,
     pub value: String,
 }
 
 impl KeyDownAction {
     pub fn new(value: String) -> Self {
-        Self {
-            key_down_action_type: "keyDown".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct KeyUpAction {
-    #[serde(rename = "type")]
-    pub key_up_action_type: String,
     pub value: String,
 }
 
 impl KeyUpAction {
     pub fn new(value: String) -> Self {
-        Self {
-            key_up_action_type: "keyUp".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PointerUpAction {
-    #[serde(rename = "type")]
-    pub pointer_up_action_type: String,
     pub button: JsUint,
 }
 
 impl PointerUpAction {
     pub fn new(button: JsUint) -> Self {
-        Self {
-            pointer_up_action_type: "pointerUp".to_string(),
-            button,
-        }
+        Self { button }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PointerDownAction {
-    #[serde(rename = "type")]
-    pub pointer_down_action_type: String,
     pub button: JsUint,
     #[serde(flatten)]
     pub pointer_common_properties: PointerCommonProperties,
@@ -264,7 +265,6 @@ pub struct PointerDownAction {
 impl PointerDownAction {
     pub fn new(button: JsUint, pointer_common_properties: PointerCommonProperties) -> Self {
         Self {
-            pointer_down_action_type: "pointerDown".to_string(),
             button,
             pointer_common_properties,
         }
@@ -273,8 +273,6 @@ impl PointerDownAction {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PointerMoveAction {
-    #[serde(rename = "type")]
-    pub pointer_move_action_type: String,
     pub x: JsInt,
     pub y: JsInt,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -294,7 +292,6 @@ impl PointerMoveAction {
         pointer_common_properties: PointerCommonProperties,
     ) -> Self {
         Self {
-            pointer_move_action_type: "pointerMove".to_string(),
             x,
             y,
             duration,
@@ -302,12 +299,41 @@ impl PointerMoveAction {
             pointer_common_properties,
         }
     }
+
+    pub fn builder(
+        x: JsInt,
+        y: JsInt,
+        pointer_common_properties: PointerCommonProperties,
+    ) -> PointerMoveActionBuilder {
+        PointerMoveActionBuilder {
+            inner: Self::new(x, y, None, None, pointer_common_properties),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PointerMoveActionBuilder {
+    inner: PointerMoveAction,
+}
+
+impl PointerMoveActionBuilder {
+    pub fn duration(mut self, duration: JsUint) -> Self {
+        self.inner.duration = Some(duration);
+        self
+    }
+
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.inner.origin = Some(origin);
+        self
+    }
+
+    pub fn build(self) -> PointerMoveAction {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WheelScrollAction {
-    #[serde(rename = "type")]
-    pub wheel_scroll_action_type: String,
     pub x: JsInt,
     pub y: JsInt,
     #[serde(rename = "deltaX")]
@@ -330,7 +356,6 @@ impl WheelScrollAction {
         origin: Option<Origin>,
     ) -> Self {
         Self {
-            wheel_scroll_action_type: "scroll".to_string(),
             x,
             y,
             delta_x,
@@ -339,6 +364,33 @@ impl WheelScrollAction {
             origin,
         }
     }
+
+    pub fn builder(x: JsInt, y: JsInt, delta_x: JsInt, delta_y: JsInt) -> WheelScrollActionBuilder {
+        WheelScrollActionBuilder {
+            inner: Self::new(x, y, delta_x, delta_y, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct WheelScrollActionBuilder {
+    inner: WheelScrollAction,
+}
+
+impl WheelScrollActionBuilder {
+    pub fn duration(mut self, duration: JsUint) -> Self {
+        self.inner.duration = Some(duration);
+        self
+    }
+
+    pub fn origin(mut self, origin: Origin) -> Self {
+        self.inner.origin = Some(origin);
+        self
+    }
+
+    pub fn build(self) -> WheelScrollAction {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -379,13 +431,66 @@ impl PointerCommonProperties {
             azimuth_angle,
         }
     }
+
+    pub fn builder() -> PointerCommonPropertiesBuilder {
+        PointerCommonPropertiesBuilder {
+            inner: Self::new(None, None, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PointerCommonPropertiesBuilder {
+    inner: PointerCommonProperties,
+}
+
+impl PointerCommonPropertiesBuilder {
+    pub fn width(mut self, width: JsUint) -> Self {
+        self.inner.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: JsUint) -> Self {
+        self.inner.height = Some(height);
+        self
+    }
+
+    pub fn pressure(mut self, pressure: f64) -> Self {
+        self.inner.pressure = Some(pressure);
+        self
+    }
+
+    pub fn tangential_pressure(mut self, tangential_pressure: f64) -> Self {
+        self.inner.tangential_pressure = Some(tangential_pressure);
+        self
+    }
+
+    pub fn twist(mut self, twist: JsUint) -> Self {
+        self.inner.twist = Some(twist);
+        self
+    }
+
+    pub fn altitude_angle(mut self, altitude_angle: f64) -> Self {
+        self.inner.altitude_angle = Some(altitude_angle);
+        self
+    }
+
+    pub fn azimuth_angle(mut self, azimuth_angle: f64) -> Self {
+        self.inner.azimuth_angle = Some(azimuth_angle);
+        self
+    }
+
+    pub fn build(self) -> PointerCommonProperties {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(rename_all = "lowercase")]
 pub enum Origin {
-    Viewport(String),
-    Pointer(String),
+    Viewport,
+    Pointer,
+    #[serde(untagged)]
     ElementOrigin(ElementOrigin),
 }
 
@@ -398,7 +503,7 @@ pub struct ReleaseActions {
 impl ReleaseActions {
     pub fn new(params: ReleaseActionsParameters) -> Self {
         Self {
-            method: "input.releaseActions".to_string(),
+            method: ReleaseActionsParameters::METHOD.to_string(),
             params,
         }
     }
@@ -415,6 +520,11 @@ impl ReleaseActionsParameters {
     }
 }
 
+impl BidiCommand for ReleaseActionsParameters {
+    const METHOD: &'static str = "input.releaseActions";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetFiles {
     pub method: String,
@@ -424,7 +534,7 @@ pub struct SetFiles {
 impl SetFiles {
     pub fn new(params: SetFilesParameters) -> Self {
         Self {
-            method: "input.setFiles".to_string(),
+            method: SetFilesParameters::METHOD.to_string(),
             params,
         }
     }
@@ -446,3 +556,8 @@ impl SetFilesParameters {
         }
     }
 }
+
+impl BidiCommand for SetFilesParameters {
+    const METHOD: &'static str = "input.setFiles";
+    type Result = local::result_data::EmptyResult;
+}
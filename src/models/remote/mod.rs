@@ -1,21 +1,27 @@
+pub mod bluetooth;
 pub mod browser;
 pub mod browsing_context;
 pub mod command;
 pub mod common;
 pub mod input;
 pub mod network;
+pub mod permissions;
 pub mod script;
 pub mod session;
 pub mod storage;
+pub mod web_authn;
 pub mod web_extension;
 
 // pub use command::*;
 pub use common::*;
 // pub use session::*;
 // pub use browser::*;
+// pub use bluetooth::*;
 // pub use browsing_context::*;
 // pub use network::*;
+// pub use permissions::*;
 // pub use script::*;
 // pub use storage::*;
 // pub use input::*;
+// pub use web_authn::*;
 // pub use web_extension::*;
@@ -1,6 +1,8 @@
 #![allow(clippy::all)]
 
+use crate::local;
 use crate::remote::browsing_context::BrowsingContext;
+use crate::remote::command::BidiCommand;
 use crate::remote::{Extensible, JsInt, JsUint};
 use serde::{Deserialize, Serialize};
 
@@ -18,59 +20,46 @@ pub enum NetworkCommand {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "password")]
 pub struct AuthCredentials {
-    #[serde(rename = "type")]
-    pub auth_credentials_type: String,
     pub username: String,
     pub password: String,
 }
 
 impl AuthCredentials {
     pub fn new(username: String, password: String) -> Self {
-        Self {
-            auth_credentials_type: "password".to_string(),
-            username,
-            password,
-        }
+        Self { username, password }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum BytesValue {
+    #[serde(rename = "string")]
     StringValue(StringValue),
+    #[serde(rename = "base64")]
     Base64Value(Base64Value),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StringValue {
-    #[serde(rename = "type")]
-    pub string_value_type: String,
     pub value: String,
 }
 
 impl StringValue {
     pub fn new(value: String) -> Self {
-        Self {
-            string_value_type: "string".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Base64Value {
-    #[serde(rename = "type")]
-    pub base64_value_type: String,
     pub value: String,
 }
 
 impl Base64Value {
     pub fn new(value: String) -> Self {
-        Self {
-            base64_value_type: "base64".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -96,6 +85,7 @@ pub struct Cookie {
     pub same_site: SameSite,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expiry: Option<JsUint>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
@@ -151,8 +141,8 @@ impl Header {
     }
 }
 
-pub type Intercept = String;
-pub type Request = String;
+pub use crate::models::ids::Intercept;
+pub use crate::models::ids::Request;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetCookieHeader {
@@ -198,19 +188,71 @@ impl SetCookieHeader {
             secure,
         }
     }
+
+    pub fn builder(name: String, value: BytesValue) -> SetCookieHeaderBuilder {
+        SetCookieHeaderBuilder {
+            inner: Self::new(name, value, None, None, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SetCookieHeaderBuilder {
+    inner: SetCookieHeader,
+}
+
+impl SetCookieHeaderBuilder {
+    pub fn domain(mut self, domain: String) -> Self {
+        self.inner.domain = Some(domain);
+        self
+    }
+
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.inner.http_only = Some(http_only);
+        self
+    }
+
+    pub fn expiry(mut self, expiry: String) -> Self {
+        self.inner.expiry = Some(expiry);
+        self
+    }
+
+    pub fn max_age(mut self, max_age: JsInt) -> Self {
+        self.inner.max_age = Some(max_age);
+        self
+    }
+
+    pub fn path(mut self, path: String) -> Self {
+        self.inner.path = Some(path);
+        self
+    }
+
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.inner.same_site = Some(same_site);
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.inner.secure = Some(secure);
+        self
+    }
+
+    pub fn build(self) -> SetCookieHeader {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum UrlPattern {
+    #[serde(rename = "pattern")]
     UrlPatternPattern(UrlPatternPattern),
+    #[serde(rename = "string")]
     UrlPatternString(UrlPatternString),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UrlPatternPattern {
-    #[serde(rename = "type")]
-    pub url_pattern_pattern_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protocol: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -232,7 +274,6 @@ impl UrlPatternPattern {
         search: Option<String>,
     ) -> Self {
         Self {
-            url_pattern_pattern_type: "pattern".to_string(),
             protocol,
             hostname,
             port,
@@ -240,21 +281,58 @@ impl UrlPatternPattern {
             search,
         }
     }
+
+    pub fn builder() -> UrlPatternPatternBuilder {
+        UrlPatternPatternBuilder {
+            inner: Self::new(None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UrlPatternPatternBuilder {
+    inner: UrlPatternPattern,
+}
+
+impl UrlPatternPatternBuilder {
+    pub fn protocol(mut self, protocol: String) -> Self {
+        self.inner.protocol = Some(protocol);
+        self
+    }
+
+    pub fn hostname(mut self, hostname: String) -> Self {
+        self.inner.hostname = Some(hostname);
+        self
+    }
+
+    pub fn port(mut self, port: String) -> Self {
+        self.inner.port = Some(port);
+        self
+    }
+
+    pub fn pathname(mut self, pathname: String) -> Self {
+        self.inner.pathname = Some(pathname);
+        self
+    }
+
+    pub fn search(mut self, search: String) -> Self {
+        self.inner.search = Some(search);
+        self
+    }
+
+    pub fn build(self) -> UrlPatternPattern {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UrlPatternString {
-    #[serde(rename = "type")]
-    pub url_pattern_string_type: String,
     pub pattern: String,
 }
 
 impl UrlPatternString {
     pub fn new(pattern: String) -> Self {
-        Self {
-            url_pattern_string_type: "string".to_string(),
-            pattern,
-        }
+        Self { pattern }
     }
 }
 
@@ -267,7 +345,7 @@ pub struct AddIntercept {
 impl AddIntercept {
     pub fn new(params: AddInterceptParameters) -> Self {
         Self {
-            method: "network.addIntercept".to_string(),
+            method: AddInterceptParameters::METHOD.to_string(),
             params,
         }
     }
@@ -278,7 +356,7 @@ pub struct AddInterceptParameters {
     pub phases: Vec<InterceptPhase>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contexts: Option<Vec<BrowsingContext>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "urlPatterns", skip_serializing_if = "Option::is_none")]
     pub url_patterns: Option<Vec<UrlPattern>>,
 }
 
@@ -294,6 +372,38 @@ impl AddInterceptParameters {
             url_patterns,
         }
     }
+
+    pub fn builder(phases: Vec<InterceptPhase>) -> AddInterceptParametersBuilder {
+        AddInterceptParametersBuilder {
+            inner: Self::new(phases, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AddInterceptParametersBuilder {
+    inner: AddInterceptParameters,
+}
+
+impl AddInterceptParametersBuilder {
+    pub fn contexts(mut self, contexts: Vec<BrowsingContext>) -> Self {
+        self.inner.contexts = Some(contexts);
+        self
+    }
+
+    pub fn url_patterns(mut self, url_patterns: Vec<UrlPattern>) -> Self {
+        self.inner.url_patterns = Some(url_patterns);
+        self
+    }
+
+    pub fn build(self) -> AddInterceptParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for AddInterceptParameters {
+    const METHOD: &'static str = "network.addIntercept";
+    type Result = local::network::AddInterceptResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -315,7 +425,7 @@ pub struct ContinueRequest {
 impl ContinueRequest {
     pub fn new(params: ContinueRequestParameters) -> Self {
         Self {
-            method: "network.continueRequest".to_string(),
+            method: ContinueRequestParameters::METHOD.to_string(),
             params,
         }
     }
@@ -354,6 +464,53 @@ impl ContinueRequestParameters {
             url,
         }
     }
+
+    pub fn builder(request: Request) -> ContinueRequestParametersBuilder {
+        ContinueRequestParametersBuilder {
+            inner: Self::new(request, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ContinueRequestParametersBuilder {
+    inner: ContinueRequestParameters,
+}
+
+impl ContinueRequestParametersBuilder {
+    pub fn body(mut self, body: BytesValue) -> Self {
+        self.inner.body = Some(body);
+        self
+    }
+
+    pub fn cookies(mut self, cookies: Vec<CookieHeader>) -> Self {
+        self.inner.cookies = Some(cookies);
+        self
+    }
+
+    pub fn headers(mut self, headers: Vec<Header>) -> Self {
+        self.inner.headers = Some(headers);
+        self
+    }
+
+    pub fn method(mut self, method: String) -> Self {
+        self.inner.method = Some(method);
+        self
+    }
+
+    pub fn url(mut self, url: String) -> Self {
+        self.inner.url = Some(url);
+        self
+    }
+
+    pub fn build(self) -> ContinueRequestParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for ContinueRequestParameters {
+    const METHOD: &'static str = "network.continueRequest";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -365,7 +522,7 @@ pub struct ContinueResponse {
 impl ContinueResponse {
     pub fn new(params: ContinueResponseParameters) -> Self {
         Self {
-            method: "network.continueResponse".to_string(),
+            method: ContinueResponseParameters::METHOD.to_string(),
             params,
         }
     }
@@ -404,6 +561,53 @@ impl ContinueResponseParameters {
             status_code,
         }
     }
+
+    pub fn builder(request: Request) -> ContinueResponseParametersBuilder {
+        ContinueResponseParametersBuilder {
+            inner: Self::new(request, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ContinueResponseParametersBuilder {
+    inner: ContinueResponseParameters,
+}
+
+impl ContinueResponseParametersBuilder {
+    pub fn cookies(mut self, cookies: Vec<SetCookieHeader>) -> Self {
+        self.inner.cookies = Some(cookies);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: AuthCredentials) -> Self {
+        self.inner.credentials = Some(credentials);
+        self
+    }
+
+    pub fn headers(mut self, headers: Vec<Header>) -> Self {
+        self.inner.headers = Some(headers);
+        self
+    }
+
+    pub fn reason_phrase(mut self, reason_phrase: String) -> Self {
+        self.inner.reason_phrase = Some(reason_phrase);
+        self
+    }
+
+    pub fn status_code(mut self, status_code: JsUint) -> Self {
+        self.inner.status_code = Some(status_code);
+        self
+    }
+
+    pub fn build(self) -> ContinueResponseParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for ContinueResponseParameters {
+    const METHOD: &'static str = "network.continueResponse";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -415,7 +619,7 @@ pub struct ContinueWithAuth {
 impl ContinueWithAuth {
     pub fn new(params: ContinueWithAuthParameters) -> Self {
         Self {
-            method: "network.continueWithAuth".to_string(),
+            method: ContinueWithAuthParameters::METHOD.to_string(),
             params,
         }
     }
@@ -435,27 +639,52 @@ impl ContinueWithAuthParameters {
             auth_option,
         }
     }
+
+    pub fn builder(request: Request) -> ContinueWithAuthParametersBuilder {
+        ContinueWithAuthParametersBuilder {
+            inner: Self::new(request, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ContinueWithAuthParametersBuilder {
+    inner: ContinueWithAuthParameters,
+}
+
+impl ContinueWithAuthParametersBuilder {
+    pub fn auth_option(mut self, auth_option: ContinueWithAuthOption) -> Self {
+        self.inner.auth_option = Some(auth_option);
+        self
+    }
+
+    pub fn build(self) -> ContinueWithAuthParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for ContinueWithAuthParameters {
+    const METHOD: &'static str = "network.continueWithAuth";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "action")]
 pub enum ContinueWithAuthOption {
+    #[serde(rename = "provideCredentials")]
     Credentials(ContinueWithAuthCredentials),
+    #[serde(untagged)]
     NoCredentials(ContinueWithAuthNoCredentials),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ContinueWithAuthCredentials {
-    pub action: String,
     pub credentials: AuthCredentials,
 }
 
 impl ContinueWithAuthCredentials {
-    pub fn new(action: String, credentials: AuthCredentials) -> Self {
-        Self {
-            action,
-            credentials,
-        }
+    pub fn new(credentials: AuthCredentials) -> Self {
+        Self { credentials }
     }
 }
 
@@ -486,7 +715,7 @@ pub struct FailRequest {
 impl FailRequest {
     pub fn new(params: FailRequestParameters) -> Self {
         Self {
-            method: "network.failRequest".to_string(),
+            method: FailRequestParameters::METHOD.to_string(),
             params,
         }
     }
@@ -503,6 +732,11 @@ impl FailRequestParameters {
     }
 }
 
+impl BidiCommand for FailRequestParameters {
+    const METHOD: &'static str = "network.failRequest";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProvideResponse {
     pub method: String,
@@ -512,7 +746,7 @@ pub struct ProvideResponse {
 impl ProvideResponse {
     pub fn new(params: ProvideResponseParameters) -> Self {
         Self {
-            method: "network.provideResponse".to_string(),
+            method: ProvideResponseParameters::METHOD.to_string(),
             params,
         }
     }
@@ -551,6 +785,53 @@ impl ProvideResponseParameters {
             status_code,
         }
     }
+
+    pub fn builder(request: Request) -> ProvideResponseParametersBuilder {
+        ProvideResponseParametersBuilder {
+            inner: Self::new(request, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ProvideResponseParametersBuilder {
+    inner: ProvideResponseParameters,
+}
+
+impl ProvideResponseParametersBuilder {
+    pub fn body(mut self, body: BytesValue) -> Self {
+        self.inner.body = Some(body);
+        self
+    }
+
+    pub fn cookies(mut self, cookies: Vec<SetCookieHeader>) -> Self {
+        self.inner.cookies = Some(cookies);
+        self
+    }
+
+    pub fn headers(mut self, headers: Vec<Header>) -> Self {
+        self.inner.headers = Some(headers);
+        self
+    }
+
+    pub fn reason_phrase(mut self, reason_phrase: String) -> Self {
+        self.inner.reason_phrase = Some(reason_phrase);
+        self
+    }
+
+    pub fn status_code(mut self, status_code: JsUint) -> Self {
+        self.inner.status_code = Some(status_code);
+        self
+    }
+
+    pub fn build(self) -> ProvideResponseParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for ProvideResponseParameters {
+    const METHOD: &'static str = "network.provideResponse";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -562,7 +843,7 @@ pub struct RemoveIntercept {
 impl RemoveIntercept {
     pub fn new(params: RemoveInterceptParameters) -> Self {
         Self {
-            method: "network.removeIntercept".to_string(),
+            method: RemoveInterceptParameters::METHOD.to_string(),
             params,
         }
     }
@@ -579,6 +860,11 @@ impl RemoveInterceptParameters {
     }
 }
 
+impl BidiCommand for RemoveInterceptParameters {
+    const METHOD: &'static str = "network.removeIntercept";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetCacheBehavior {
     pub method: String,
@@ -588,7 +874,7 @@ pub struct SetCacheBehavior {
 impl SetCacheBehavior {
     pub fn new(params: SetCacheBehaviorParameters) -> Self {
         Self {
-            method: "network.setCacheBehavior".to_string(),
+            method: SetCacheBehaviorParameters::METHOD.to_string(),
             params,
         }
     }
@@ -596,6 +882,7 @@ impl SetCacheBehavior {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetCacheBehaviorParameters {
+    #[serde(rename = "cacheBehavior")]
     pub cache_behavior: CacheBehavior,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contexts: Option<Vec<BrowsingContext>>,
@@ -608,6 +895,33 @@ impl SetCacheBehaviorParameters {
             contexts,
         }
     }
+
+    pub fn builder(cache_behavior: CacheBehavior) -> SetCacheBehaviorParametersBuilder {
+        SetCacheBehaviorParametersBuilder {
+            inner: Self::new(cache_behavior, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SetCacheBehaviorParametersBuilder {
+    inner: SetCacheBehaviorParameters,
+}
+
+impl SetCacheBehaviorParametersBuilder {
+    pub fn contexts(mut self, contexts: Vec<BrowsingContext>) -> Self {
+        self.inner.contexts = Some(contexts);
+        self
+    }
+
+    pub fn build(self) -> SetCacheBehaviorParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SetCacheBehaviorParameters {
+    const METHOD: &'static str = "network.setCacheBehavior";
+    type Result = local::result_data::EmptyResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
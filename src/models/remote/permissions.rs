@@ -0,0 +1,100 @@
+use crate::local;
+use crate::remote::browser::UserContext;
+use crate::remote::command::BidiCommand;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PermissionsCommand {
+    SetPermission(SetPermission),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PermissionDescriptor {
+    pub name: String,
+}
+
+impl PermissionDescriptor {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionState {
+    Granted,
+    Denied,
+    Prompt,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetPermission {
+    pub method: String,
+    pub params: SetPermissionParameters,
+}
+
+impl SetPermission {
+    pub fn new(params: SetPermissionParameters) -> Self {
+        Self {
+            method: SetPermissionParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetPermissionParameters {
+    pub descriptor: PermissionDescriptor,
+    pub state: PermissionState,
+    pub origin: String,
+    #[serde(rename = "userContext", skip_serializing_if = "Option::is_none")]
+    pub user_context: Option<UserContext>,
+}
+
+impl SetPermissionParameters {
+    pub fn new(
+        descriptor: PermissionDescriptor,
+        state: PermissionState,
+        origin: String,
+        user_context: Option<UserContext>,
+    ) -> Self {
+        Self {
+            descriptor,
+            state,
+            origin,
+            user_context,
+        }
+    }
+
+    pub fn builder(
+        descriptor: PermissionDescriptor,
+        state: PermissionState,
+        origin: String,
+    ) -> SetPermissionParametersBuilder {
+        SetPermissionParametersBuilder {
+            inner: Self::new(descriptor, state, origin, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SetPermissionParametersBuilder {
+    inner: SetPermissionParameters,
+}
+
+impl SetPermissionParametersBuilder {
+    pub fn user_context(mut self, user_context: UserContext) -> Self {
+        self.inner.user_context = Some(user_context);
+        self
+    }
+
+    pub fn build(self) -> SetPermissionParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SetPermissionParameters {
+    const METHOD: &'static str = "permissions.setPermission";
+    type Result = local::result_data::EmptyResult;
+}
@@ -1,7 +1,9 @@
 #![allow(clippy::all)]
 
+use crate::local;
 use crate::remote::browser::UserContext;
 use crate::remote::browsing_context::BrowsingContext;
+use crate::remote::command::BidiCommand;
 use crate::remote::{Extensible, JsUint};
 use serde::{Deserialize, Serialize};
 
@@ -19,18 +21,14 @@ pub enum ScriptCommand {
 pub type Channel = String;
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "channel")]
 pub struct ChannelValue {
-    #[serde(rename = "type")]
-    pub channel_value_type: String,
     pub value: ChannelProperties,
 }
 
 impl ChannelValue {
-    pub fn new(channel_value_type: String, value: ChannelProperties) -> Self {
-        Self {
-            channel_value_type,
-            value,
-        }
+    pub fn new(value: ChannelProperties) -> Self {
+        Self { value }
     }
 }
 
@@ -58,37 +56,58 @@ impl ChannelProperties {
             ownership,
         }
     }
+
+    pub fn builder(channel: Channel) -> ChannelPropertiesBuilder {
+        ChannelPropertiesBuilder {
+            inner: Self::new(channel, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ChannelPropertiesBuilder {
+    inner: ChannelProperties,
+}
+
+impl ChannelPropertiesBuilder {
+    pub fn serialization_options(mut self, serialization_options: SerializationOptions) -> Self {
+        self.inner.serialization_options = Some(serialization_options);
+        self
+    }
+
+    pub fn ownership(mut self, ownership: ResultOwnership) -> Self {
+        self.inner.ownership = Some(ownership);
+        self
+    }
+
+    pub fn build(self) -> ChannelProperties {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum EvaluateResult {
+    #[serde(rename = "success")]
     EvaluateResultSuccess(EvaluateResultSuccess),
+    #[serde(rename = "exception")]
     EvaluateResultException(EvaluateResultException),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EvaluateResultSuccess {
-    #[serde(rename = "type")]
-    pub evaluate_result_success_type: String,
     pub result: RemoteValue,
     pub realm: Realm,
 }
 
 impl EvaluateResultSuccess {
     pub fn new(result: RemoteValue, realm: Realm) -> Self {
-        Self {
-            evaluate_result_success_type: "success".to_string(),
-            result,
-            realm,
-        }
+        Self { result, realm }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EvaluateResultException {
-    #[serde(rename = "type")]
-    pub evaluate_result_exception_type: String,
     #[serde(rename = "exceptionDetails")]
     pub exception_details: ExceptionDetails,
     pub realm: Realm,
@@ -97,7 +116,6 @@ pub struct EvaluateResultException {
 impl EvaluateResultException {
     pub fn new(exception_details: ExceptionDetails, realm: Realm) -> Self {
         Self {
-            evaluate_result_exception_type: "exception".to_string(),
             exception_details,
             realm,
         }
@@ -134,54 +152,53 @@ impl ExceptionDetails {
     }
 }
 
-pub type Handle = String;
+pub use crate::models::ids::Handle;
 pub type InternalId = String;
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum LocalValue {
-    RemoteReference(RemoteReference),
-    PrimitiveProtocolValue(PrimitiveProtocolValue),
-    ChannelValue(ChannelValue),
+    #[serde(rename = "array")]
     ArrayLocalValue(ArrayLocalValue),
+    #[serde(rename = "date")]
     DateLocalValue(DateLocalValue),
+    #[serde(rename = "map")]
     MapLocalValue(MapLocalValue),
+    #[serde(rename = "object")]
     ObjectLocalValue(ObjectLocalValue),
+    #[serde(rename = "regexp")]
     RegExpLocalValue(RegExpLocalValue),
+    #[serde(rename = "set")]
     SetLocalValue(SetLocalValue),
+    #[serde(untagged)]
+    ChannelValue(ChannelValue),
+    #[serde(untagged)]
+    RemoteReference(RemoteReference),
+    #[serde(untagged)]
+    PrimitiveProtocolValue(PrimitiveProtocolValue),
 }
 
 pub type ListLocalValue = Vec<LocalValue>;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ArrayLocalValue {
-    #[serde(rename = "type")]
-    pub array_local_value_type: String,
     pub value: ListLocalValue,
 }
 
 impl ArrayLocalValue {
     pub fn new(value: ListLocalValue) -> Self {
-        Self {
-            array_local_value_type: "array".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DateLocalValue {
-    #[serde(rename = "type")]
-    pub date_local_value_type: String,
     pub value: String,
 }
 
 impl DateLocalValue {
     pub fn new(value: String) -> Self {
-        Self {
-            date_local_value_type: "date".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -196,33 +213,23 @@ pub enum LocalValueOrText {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MapLocalValue {
-    #[serde(rename = "type")]
-    pub map_local_value_type: String,
     pub value: MappingLocalValue,
 }
 
 impl MapLocalValue {
     pub fn new(value: MappingLocalValue) -> Self {
-        Self {
-            map_local_value_type: "map".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ObjectLocalValue {
-    #[serde(rename = "type")]
-    pub object_local_value_type: String,
     pub value: MappingLocalValue,
 }
 
 impl ObjectLocalValue {
     pub fn new(value: MappingLocalValue) -> Self {
-        Self {
-            object_local_value_type: "object".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -241,91 +248,72 @@ impl RegExpValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RegExpLocalValue {
-    #[serde(rename = "type")]
-    pub regexp_local_value_type: String,
     pub value: RegExpValue,
 }
 
 impl RegExpLocalValue {
     pub fn new(value: RegExpValue) -> Self {
-        Self {
-            regexp_local_value_type: "regexp".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetLocalValue {
-    #[serde(rename = "type")]
-    pub set_local_value_type: String,
     pub value: ListLocalValue,
 }
 
 impl SetLocalValue {
     pub fn new(value: ListLocalValue) -> Self {
-        Self {
-            set_local_value_type: "set".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
-pub type PreloadScript = String;
-pub type Realm = String;
+pub use crate::models::ids::PreloadScript;
+pub use crate::models::ids::Realm;
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum PrimitiveProtocolValue {
+    #[serde(rename = "undefined")]
     UndefinedValue(UndefinedValue),
+    #[serde(rename = "null")]
     NullValue(NullValue),
+    #[serde(rename = "string")]
     StringValue(StringValue),
+    #[serde(rename = "number")]
     NumberValue(NumberValue),
+    #[serde(rename = "boolean")]
     BooleanValue(BooleanValue),
+    #[serde(rename = "bigint")]
     BigIntValue(BigIntValue),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct UndefinedValue {
-    #[serde(rename = "type")]
-    pub undefined_value_type: String,
-}
+pub struct UndefinedValue {}
 
 impl UndefinedValue {
     pub fn new() -> Self {
-        Self {
-            undefined_value_type: "undefined".to_string(),
-        }
+        Self {}
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct NullValue {
-    #[serde(rename = "type")]
-    pub null_value_type: String,
-}
+pub struct NullValue {}
 
 impl NullValue {
     pub fn new() -> Self {
-        Self {
-            null_value_type: "null".to_string(),
-        }
+        Self {}
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StringValue {
-    #[serde(rename = "type")]
-    pub string_value_type: String,
     pub value: String,
 }
 
 impl StringValue {
     pub fn new(value: String) -> Self {
-        Self {
-            string_value_type: "string".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -337,21 +325,18 @@ pub enum SpecialNumber {
     Infinity,
     #[serde(rename = "-Infinity")]
     NegativeInfinity,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NumberValue {
-    #[serde(rename = "type")]
-    pub number_value_type: String,
     pub value: NumberOrSpecialNumber,
 }
 
 impl NumberValue {
     pub fn new(value: NumberOrSpecialNumber) -> Self {
-        Self {
-            number_value_type: "number".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -364,33 +349,23 @@ pub enum NumberOrSpecialNumber {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BooleanValue {
-    #[serde(rename = "type")]
-    pub boolean_value_type: String,
     pub value: bool,
 }
 
 impl BooleanValue {
     pub fn new(value: bool) -> Self {
-        Self {
-            boolean_value_type: "boolean".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BigIntValue {
-    #[serde(rename = "type")]
-    pub bigint_value_type: String,
     pub value: String,
 }
 
 impl BigIntValue {
     pub fn new(value: String) -> Self {
-        Self {
-            bigint_value_type: "bigint".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -412,6 +387,8 @@ pub enum RealmType {
     AudioWorklet,
     #[serde(rename = "worklet")]
     Worklet,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -427,6 +404,7 @@ pub struct SharedReference {
     pub shared_id: SharedId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
@@ -445,6 +423,7 @@ pub struct RemoteObjectReference {
     pub handle: Handle,
     #[serde(rename = "sharedId", skip_serializing_if = "Option::is_none")]
     pub shared_id: Option<SharedId>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
@@ -459,29 +438,50 @@ impl RemoteObjectReference {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum RemoteValue {
-    PrimitiveProtocolValue(PrimitiveProtocolValue),
+    #[serde(rename = "symbol")]
     SymbolRemoteValue(SymbolRemoteValue),
+    #[serde(rename = "array")]
     ArrayRemoteValue(ArrayRemoteValue),
+    #[serde(rename = "object")]
     ObjectRemoteValue(ObjectRemoteValue),
+    #[serde(rename = "function")]
     FunctionRemoteValue(FunctionRemoteValue),
+    #[serde(rename = "regexp")]
     RegExpRemoteValue(RegExpRemoteValue),
+    #[serde(rename = "date")]
     DateRemoteValue(DateRemoteValue),
+    #[serde(rename = "map")]
     MapRemoteValue(MapRemoteValue),
+    #[serde(rename = "set")]
     SetRemoteValue(SetRemoteValue),
+    #[serde(rename = "weakmap")]
     WeakMapRemoteValue(WeakMapRemoteValue),
+    #[serde(rename = "weakset")]
     WeakSetRemoteValue(WeakSetRemoteValue),
+    #[serde(rename = "generator")]
     GeneratorRemoteValue(GeneratorRemoteValue),
+    #[serde(rename = "error")]
     ErrorRemoteValue(ErrorRemoteValue),
+    #[serde(rename = "proxy")]
     ProxyRemoteValue(ProxyRemoteValue),
+    #[serde(rename = "promise")]
     PromiseRemoteValue(PromiseRemoteValue),
+    #[serde(rename = "typedarray")]
     TypedArrayRemoteValue(TypedArrayRemoteValue),
+    #[serde(rename = "arraybuffer")]
     ArrayBufferRemoteValue(ArrayBufferRemoteValue),
+    #[serde(rename = "nodelist")]
     NodeListRemoteValue(NodeListRemoteValue),
+    #[serde(rename = "htmlcollection")]
     HTMLCollectionRemoteValue(HTMLCollectionRemoteValue),
+    #[serde(rename = "node")]
     NodeRemoteValue(NodeRemoteValue),
+    #[serde(rename = "window")]
     WindowProxyRemoteValue(WindowProxyRemoteValue),
+    #[serde(untagged)]
+    PrimitiveProtocolValue(PrimitiveProtocolValue),
 }
 
 pub type ListRemoteValue = Vec<RemoteValue>;
@@ -496,8 +496,6 @@ pub enum RemoteValueOrText {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SymbolRemoteValue {
-    #[serde(rename = "type")]
-    pub symbol_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -505,13 +503,8 @@ pub struct SymbolRemoteValue {
 }
 
 impl SymbolRemoteValue {
-    pub fn new(
-        symbol_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            symbol_remote_value_type,
             handle,
             internal_id,
         }
@@ -520,8 +513,6 @@ impl SymbolRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ArrayRemoteValue {
-    #[serde(rename = "type")]
-    pub array_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -532,13 +523,11 @@ pub struct ArrayRemoteValue {
 
 impl ArrayRemoteValue {
     pub fn new(
-        array_remote_value_type: String,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
         value: Option<ListRemoteValue>,
     ) -> Self {
         Self {
-            array_remote_value_type,
             handle,
             internal_id,
             value,
@@ -548,8 +537,6 @@ impl ArrayRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ObjectRemoteValue {
-    #[serde(rename = "type")]
-    pub object_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -560,13 +547,11 @@ pub struct ObjectRemoteValue {
 
 impl ObjectRemoteValue {
     pub fn new(
-        object_remote_value_type: String,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
         value: Option<MappingRemoteValue>,
     ) -> Self {
         Self {
-            object_remote_value_type,
             handle,
             internal_id,
             value,
@@ -576,8 +561,6 @@ impl ObjectRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FunctionRemoteValue {
-    #[serde(rename = "type")]
-    pub function_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -585,13 +568,8 @@ pub struct FunctionRemoteValue {
 }
 
 impl FunctionRemoteValue {
-    pub fn new(
-        function_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            function_remote_value_type,
             handle,
             internal_id,
         }
@@ -648,8 +626,6 @@ impl DateRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MapRemoteValue {
-    #[serde(rename = "type")]
-    pub map_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -659,13 +635,11 @@ pub struct MapRemoteValue {
 
 impl MapRemoteValue {
     pub fn new(
-        map_remote_value_type: String,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
         value: Option<MappingRemoteValue>,
     ) -> Self {
         Self {
-            map_remote_value_type,
             handle,
             internal_id,
             value,
@@ -675,8 +649,6 @@ impl MapRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetRemoteValue {
-    #[serde(rename = "type")]
-    pub set_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -686,13 +658,11 @@ pub struct SetRemoteValue {
 
 impl SetRemoteValue {
     pub fn new(
-        set_remote_value_type: String,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
         value: Option<ListRemoteValue>,
     ) -> Self {
         Self {
-            set_remote_value_type,
             handle,
             internal_id,
             value,
@@ -702,8 +672,6 @@ impl SetRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WeakMapRemoteValue {
-    #[serde(rename = "type")]
-    pub weak_map_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -711,13 +679,8 @@ pub struct WeakMapRemoteValue {
 }
 
 impl WeakMapRemoteValue {
-    pub fn new(
-        weak_map_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            weak_map_remote_value_type,
             handle,
             internal_id,
         }
@@ -726,8 +689,6 @@ impl WeakMapRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WeakSetRemoteValue {
-    #[serde(rename = "type")]
-    pub weak_set_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -735,13 +696,8 @@ pub struct WeakSetRemoteValue {
 }
 
 impl WeakSetRemoteValue {
-    pub fn new(
-        weak_set_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            weak_set_remote_value_type,
             handle,
             internal_id,
         }
@@ -750,8 +706,6 @@ impl WeakSetRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GeneratorRemoteValue {
-    #[serde(rename = "type")]
-    pub generator_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -759,13 +713,8 @@ pub struct GeneratorRemoteValue {
 }
 
 impl GeneratorRemoteValue {
-    pub fn new(
-        generator_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            generator_remote_value_type,
             handle,
             internal_id,
         }
@@ -774,8 +723,6 @@ impl GeneratorRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ErrorRemoteValue {
-    #[serde(rename = "type")]
-    pub error_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -783,13 +730,8 @@ pub struct ErrorRemoteValue {
 }
 
 impl ErrorRemoteValue {
-    pub fn new(
-        error_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            error_remote_value_type,
             handle,
             internal_id,
         }
@@ -798,8 +740,6 @@ impl ErrorRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProxyRemoteValue {
-    #[serde(rename = "type")]
-    pub proxy_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -807,13 +747,8 @@ pub struct ProxyRemoteValue {
 }
 
 impl ProxyRemoteValue {
-    pub fn new(
-        proxy_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            proxy_remote_value_type,
             handle,
             internal_id,
         }
@@ -822,8 +757,6 @@ impl ProxyRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PromiseRemoteValue {
-    #[serde(rename = "type")]
-    pub promise_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -831,13 +764,8 @@ pub struct PromiseRemoteValue {
 }
 
 impl PromiseRemoteValue {
-    pub fn new(
-        promise_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            promise_remote_value_type,
             handle,
             internal_id,
         }
@@ -846,8 +774,6 @@ impl PromiseRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TypedArrayRemoteValue {
-    #[serde(rename = "type")]
-    pub typed_array_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -855,13 +781,8 @@ pub struct TypedArrayRemoteValue {
 }
 
 impl TypedArrayRemoteValue {
-    pub fn new(
-        typed_array_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            typed_array_remote_value_type,
             handle,
             internal_id,
         }
@@ -870,8 +791,6 @@ impl TypedArrayRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ArrayBufferRemoteValue {
-    #[serde(rename = "type")]
-    pub array_buffer_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -879,13 +798,8 @@ pub struct ArrayBufferRemoteValue {
 }
 
 impl ArrayBufferRemoteValue {
-    pub fn new(
-        array_buffer_remote_value_type: String,
-        handle: Option<Handle>,
-        internal_id: Option<InternalId>,
-    ) -> Self {
+    pub fn new(handle: Option<Handle>, internal_id: Option<InternalId>) -> Self {
         Self {
-            array_buffer_remote_value_type,
             handle,
             internal_id,
         }
@@ -894,8 +808,6 @@ impl ArrayBufferRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NodeListRemoteValue {
-    #[serde(rename = "type")]
-    pub node_list_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -906,13 +818,11 @@ pub struct NodeListRemoteValue {
 
 impl NodeListRemoteValue {
     pub fn new(
-        node_list_remote_value_type: String,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
         value: Option<ListRemoteValue>,
     ) -> Self {
         Self {
-            node_list_remote_value_type,
             handle,
             internal_id,
             value,
@@ -922,8 +832,6 @@ impl NodeListRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HTMLCollectionRemoteValue {
-    #[serde(rename = "type")]
-    pub html_collection_remote_value_type: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
     #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
@@ -934,13 +842,11 @@ pub struct HTMLCollectionRemoteValue {
 
 impl HTMLCollectionRemoteValue {
     pub fn new(
-        html_collection_remote_value_type: String,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
         value: Option<ListRemoteValue>,
     ) -> Self {
         Self {
-            html_collection_remote_value_type,
             handle,
             internal_id,
             value,
@@ -950,8 +856,6 @@ impl HTMLCollectionRemoteValue {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NodeRemoteValue {
-    #[serde(rename = "type")]
-    pub node_remote_value_type: String,
     #[serde(rename = "sharedId", skip_serializing_if = "Option::is_none")]
     pub shared_id: Option<SharedId>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -964,14 +868,12 @@ pub struct NodeRemoteValue {
 
 impl NodeRemoteValue {
     pub fn new(
-        node_remote_value_type: String,
         shared_id: Option<SharedId>,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
         value: Option<NodeProperties>,
     ) -> Self {
         Self {
-            node_remote_value_type,
             shared_id,
             handle,
             internal_id,
@@ -999,10 +901,9 @@ pub struct NodeProperties {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "nodeValue")]
     pub node_value: Option<String>,
-    // Recursive type
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // #[serde(rename = "shadowRoot")]
-    // pub shadow_root: Option<Option<NodeRemoteValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "shadowRoot")]
+    pub shadow_root: Option<Box<NodeRemoteValue>>,
 }
 
 impl NodeProperties {
@@ -1015,6 +916,7 @@ impl NodeProperties {
         mode: Option<NodePropertiesMode>,
         namespace_uri: Option<String>,
         node_value: Option<String>,
+        shadow_root: Option<Box<NodeRemoteValue>>,
     ) -> Self {
         Self {
             node_type,
@@ -1025,6 +927,7 @@ impl NodeProperties {
             mode,
             namespace_uri,
             node_value,
+            shadow_root,
         }
     }
 }
@@ -1038,24 +941,20 @@ pub enum NodePropertiesMode {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WindowProxyRemoteValue {
-    #[serde(rename = "type")]
-    pub window_proxy_remote_value_type: String,
     pub value: WindowProxyProperties,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<Handle>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "internalId", skip_serializing_if = "Option::is_none")]
     pub internal_id: Option<InternalId>,
 }
 
 impl WindowProxyRemoteValue {
     pub fn new(
-        window_proxy_remote_value_type: String,
         value: WindowProxyProperties,
         handle: Option<Handle>,
         internal_id: Option<InternalId>,
     ) -> Self {
         Self {
-            window_proxy_remote_value_type,
             value,
             handle,
             internal_id,
@@ -1079,6 +978,8 @@ impl WindowProxyProperties {
 pub enum ResultOwnership {
     Root,
     None,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1103,6 +1004,38 @@ impl SerializationOptions {
             include_shadow_tree,
         }
     }
+
+    pub fn builder() -> SerializationOptionsBuilder {
+        SerializationOptionsBuilder {
+            inner: Self::new(None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SerializationOptionsBuilder {
+    inner: SerializationOptions,
+}
+
+impl SerializationOptionsBuilder {
+    pub fn max_dom_depth(mut self, max_dom_depth: JsUint) -> Self {
+        self.inner.max_dom_depth = Some(max_dom_depth);
+        self
+    }
+
+    pub fn max_object_depth(mut self, max_object_depth: JsUint) -> Self {
+        self.inner.max_object_depth = Some(max_object_depth);
+        self
+    }
+
+    pub fn include_shadow_tree(mut self, include_shadow_tree: IncludeShadowTree) -> Self {
+        self.inner.include_shadow_tree = Some(include_shadow_tree);
+        self
+    }
+
+    pub fn build(self) -> SerializationOptions {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1111,9 +1044,11 @@ pub enum IncludeShadowTree {
     None,
     Open,
     All,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-pub type SharedId = String;
+pub use crate::models::ids::SharedId;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StackFrame {
@@ -1194,7 +1129,7 @@ pub struct AddPreloadScript {
 impl AddPreloadScript {
     pub fn new(params: AddPreloadScriptParameters) -> Self {
         Self {
-            method: "script.addPreloadScript".to_string(),
+            method: AddPreloadScriptParameters::METHOD.to_string(),
             params,
         }
     }
@@ -1230,6 +1165,48 @@ impl AddPreloadScriptParameters {
             sandbox,
         }
     }
+
+    pub fn builder(function_declaration: String) -> AddPreloadScriptParametersBuilder {
+        AddPreloadScriptParametersBuilder {
+            inner: Self::new(function_declaration, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AddPreloadScriptParametersBuilder {
+    inner: AddPreloadScriptParameters,
+}
+
+impl AddPreloadScriptParametersBuilder {
+    pub fn arguments(mut self, arguments: Vec<ChannelValue>) -> Self {
+        self.inner.arguments = Some(arguments);
+        self
+    }
+
+    pub fn contexts(mut self, contexts: Vec<BrowsingContext>) -> Self {
+        self.inner.contexts = Some(contexts);
+        self
+    }
+
+    pub fn user_contexts(mut self, user_contexts: Vec<UserContext>) -> Self {
+        self.inner.user_contexts = Some(user_contexts);
+        self
+    }
+
+    pub fn sandbox(mut self, sandbox: String) -> Self {
+        self.inner.sandbox = Some(sandbox);
+        self
+    }
+
+    pub fn build(self) -> AddPreloadScriptParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for AddPreloadScriptParameters {
+    const METHOD: &'static str = "script.addPreloadScript";
+    type Result = local::script::AddPreloadScriptResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1241,7 +1218,7 @@ pub struct Disown {
 impl Disown {
     pub fn new(params: DisownParameters) -> Self {
         Self {
-            method: "script.disown".to_string(),
+            method: DisownParameters::METHOD.to_string(),
             params,
         }
     }
@@ -1259,6 +1236,11 @@ impl DisownParameters {
     }
 }
 
+impl BidiCommand for DisownParameters {
+    const METHOD: &'static str = "script.disown";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CallFunction {
     pub method: String,
@@ -1268,7 +1250,7 @@ pub struct CallFunction {
 impl CallFunction {
     pub fn new(params: CallFunctionParameters) -> Self {
         Self {
-            method: "script.callFunction".to_string(),
+            method: CallFunctionParameters::METHOD.to_string(),
             params,
         }
     }
@@ -1317,6 +1299,66 @@ impl CallFunctionParameters {
             user_activation,
         }
     }
+
+    pub fn builder(
+        function_declaration: String,
+        await_promise: bool,
+        target: Target,
+    ) -> CallFunctionParametersBuilder {
+        CallFunctionParametersBuilder {
+            inner: Self::new(
+                function_declaration,
+                await_promise,
+                target,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CallFunctionParametersBuilder {
+    inner: CallFunctionParameters,
+}
+
+impl CallFunctionParametersBuilder {
+    pub fn arguments(mut self, arguments: Vec<LocalValue>) -> Self {
+        self.inner.arguments = Some(arguments);
+        self
+    }
+
+    pub fn result_ownership(mut self, result_ownership: ResultOwnership) -> Self {
+        self.inner.result_ownership = Some(result_ownership);
+        self
+    }
+
+    pub fn serialization_options(mut self, serialization_options: SerializationOptions) -> Self {
+        self.inner.serialization_options = Some(serialization_options);
+        self
+    }
+
+    pub fn this(mut self, this: LocalValue) -> Self {
+        self.inner.this = Some(this);
+        self
+    }
+
+    pub fn user_activation(mut self, user_activation: bool) -> Self {
+        self.inner.user_activation = Some(user_activation);
+        self
+    }
+
+    pub fn build(self) -> CallFunctionParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for CallFunctionParameters {
+    const METHOD: &'static str = "script.callFunction";
+    type Result = local::script::EvaluateResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1328,7 +1370,7 @@ pub struct Evaluate {
 impl Evaluate {
     pub fn new(params: EvaluateParameters) -> Self {
         Self {
-            method: "script.evaluate".to_string(),
+            method: EvaluateParameters::METHOD.to_string(),
             params,
         }
     }
@@ -1368,6 +1410,47 @@ impl EvaluateParameters {
             user_activation,
         }
     }
+
+    pub fn builder(
+        expression: String,
+        target: Target,
+        await_promise: bool,
+    ) -> EvaluateParametersBuilder {
+        EvaluateParametersBuilder {
+            inner: Self::new(expression, target, await_promise, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EvaluateParametersBuilder {
+    inner: EvaluateParameters,
+}
+
+impl EvaluateParametersBuilder {
+    pub fn result_ownership(mut self, result_ownership: ResultOwnership) -> Self {
+        self.inner.result_ownership = Some(result_ownership);
+        self
+    }
+
+    pub fn serialization_options(mut self, serialization_options: SerializationOptions) -> Self {
+        self.inner.serialization_options = Some(serialization_options);
+        self
+    }
+
+    pub fn user_activation(mut self, user_activation: bool) -> Self {
+        self.inner.user_activation = Some(user_activation);
+        self
+    }
+
+    pub fn build(self) -> EvaluateParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for EvaluateParameters {
+    const METHOD: &'static str = "script.evaluate";
+    type Result = local::script::EvaluateResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1379,7 +1462,7 @@ pub struct GetRealms {
 impl GetRealms {
     pub fn new(params: GetRealmsParameters) -> Self {
         Self {
-            method: "script.getRealms".to_string(),
+            method: GetRealmsParameters::METHOD.to_string(),
             params,
         }
     }
@@ -1401,6 +1484,38 @@ impl GetRealmsParameters {
             realm_type,
         }
     }
+
+    pub fn builder() -> GetRealmsParametersBuilder {
+        GetRealmsParametersBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetRealmsParametersBuilder {
+    inner: GetRealmsParameters,
+}
+
+impl GetRealmsParametersBuilder {
+    pub fn context(mut self, context: BrowsingContext) -> Self {
+        self.inner.context = Some(context);
+        self
+    }
+
+    pub fn realm_type(mut self, realm_type: RealmType) -> Self {
+        self.inner.realm_type = Some(realm_type);
+        self
+    }
+
+    pub fn build(self) -> GetRealmsParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for GetRealmsParameters {
+    const METHOD: &'static str = "script.getRealms";
+    type Result = local::script::GetRealmsResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1412,7 +1527,7 @@ pub struct RemovePreloadScript {
 impl RemovePreloadScript {
     pub fn new(params: RemovePreloadScriptParameters) -> Self {
         Self {
-            method: "script.removePreloadScript".to_string(),
+            method: RemovePreloadScriptParameters::METHOD.to_string(),
             params,
         }
     }
@@ -1428,3 +1543,8 @@ impl RemovePreloadScriptParameters {
         Self { script }
     }
 }
+
+impl BidiCommand for RemovePreloadScriptParameters {
+    const METHOD: &'static str = "script.removePreloadScript";
+    type Result = local::result_data::EmptyResult;
+}
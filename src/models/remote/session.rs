@@ -1,8 +1,10 @@
 #![allow(clippy::all)]
 
+use crate::local;
 use crate::remote::browser::UserContext;
 use crate::remote::browsing_context::BrowsingContext;
-use crate::remote::{EmptyParams, Extensible};
+use crate::remote::command::BidiCommand;
+use crate::remote::Extensible;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,6 +35,33 @@ impl CapabilitiesRequest {
             first_match,
         }
     }
+
+    pub fn builder() -> CapabilitiesRequestBuilder {
+        CapabilitiesRequestBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CapabilitiesRequestBuilder {
+    inner: CapabilitiesRequest,
+}
+
+impl CapabilitiesRequestBuilder {
+    pub fn always_match(mut self, always_match: CapabilityRequest) -> Self {
+        self.inner.always_match = Some(always_match);
+        self
+    }
+
+    pub fn first_match(mut self, first_match: Vec<CapabilityRequest>) -> Self {
+        self.inner.first_match = Some(first_match);
+        self
+    }
+
+    pub fn build(self) -> CapabilitiesRequest {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -55,6 +84,7 @@ pub struct CapabilityRequest {
         skip_serializing_if = "Option::is_none"
     )]
     pub unhandled_prompt_behavior: Option<UserPromptHandler>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
@@ -78,73 +108,120 @@ impl CapabilityRequest {
             extensible,
         }
     }
+
+    pub fn builder() -> CapabilityRequestBuilder {
+        CapabilityRequestBuilder {
+            inner: Self::new(None, None, None, None, None, None, Extensible::new()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CapabilityRequestBuilder {
+    inner: CapabilityRequest,
+}
+
+impl CapabilityRequestBuilder {
+    pub fn accept_insecure_certs(mut self, accept_insecure_certs: bool) -> Self {
+        self.inner.accept_insecure_certs = Some(accept_insecure_certs);
+        self
+    }
+
+    pub fn browser_name(mut self, browser_name: String) -> Self {
+        self.inner.browser_name = Some(browser_name);
+        self
+    }
+
+    pub fn browser_version(mut self, browser_version: String) -> Self {
+        self.inner.browser_version = Some(browser_version);
+        self
+    }
+
+    pub fn platform_name(mut self, platform_name: String) -> Self {
+        self.inner.platform_name = Some(platform_name);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: ProxyConfiguration) -> Self {
+        self.inner.proxy = Some(proxy);
+        self
+    }
+
+    pub fn unhandled_prompt_behavior(
+        mut self,
+        unhandled_prompt_behavior: UserPromptHandler,
+    ) -> Self {
+        self.inner.unhandled_prompt_behavior = Some(unhandled_prompt_behavior);
+        self
+    }
+
+    pub fn extensible(mut self, extensible: Extensible) -> Self {
+        self.inner.extensible = extensible;
+        self
+    }
+
+    pub fn build(self) -> CapabilityRequest {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "proxyType")]
 pub enum ProxyConfiguration {
+    #[serde(rename = "autodetect")]
     AutodetectProxyConfiguration(AutodetectProxyConfiguration),
+    #[serde(rename = "direct")]
     DirectProxyConfiguration(DirectProxyConfiguration),
+    #[serde(rename = "manual")]
     ManualProxyConfiguration(ManualProxyConfiguration),
+    #[serde(rename = "pac")]
     PacProxyConfiguration(PacProxyConfiguration),
+    #[serde(rename = "system")]
     SystemProxyConfiguration(SystemProxyConfiguration),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AutodetectProxyConfiguration {
-    #[serde(rename = "proxyType")]
-    pub proxy_type: String,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
 impl AutodetectProxyConfiguration {
-    pub fn new(proxy_type: String, extensible: Extensible) -> Self {
-        Self {
-            proxy_type,
-            extensible,
-        }
+    pub fn new(extensible: Extensible) -> Self {
+        Self { extensible }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DirectProxyConfiguration {
-    #[serde(rename = "proxyType")]
-    pub proxy_type: String,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
 impl DirectProxyConfiguration {
-    pub fn new(proxy_type: String, extensible: Extensible) -> Self {
-        Self {
-            proxy_type,
-            extensible,
-        }
+    pub fn new(extensible: Extensible) -> Self {
+        Self { extensible }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ManualProxyConfiguration {
-    #[serde(rename = "proxyType")]
-    pub proxy_type: String,
     #[serde(rename = "ftpProxy", skip_serializing_if = "Option::is_none")]
     pub ftp_proxy: Option<String>,
     #[serde(rename = "httpProxy", skip_serializing_if = "Option::is_none")]
     pub http_proxy: Option<String>,
     #[serde(rename = "sslProxy", skip_serializing_if = "Option::is_none")]
     pub ssl_proxy: Option<String>,
-    #[serde(
-        rename = "socksProxyConfiguration",
-        skip_serializing_if = "Option::is_none"
-    )]
+    #[serde(flatten)]
     pub socks_proxy_configuration: Option<SocksProxyConfiguration>,
     #[serde(rename = "noProxy", skip_serializing_if = "Option::is_none")]
     pub no_proxy: Option<Vec<String>>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
 impl ManualProxyConfiguration {
     pub fn new(
-        proxy_type: String,
         ftp_proxy: Option<String>,
         http_proxy: Option<String>,
         ssl_proxy: Option<String>,
@@ -153,7 +230,6 @@ impl ManualProxyConfiguration {
         extensible: Extensible,
     ) -> Self {
         Self {
-            proxy_type,
             ftp_proxy,
             http_proxy,
             ssl_proxy,
@@ -162,6 +238,56 @@ impl ManualProxyConfiguration {
             extensible,
         }
     }
+
+    pub fn builder() -> ManualProxyConfigurationBuilder {
+        ManualProxyConfigurationBuilder {
+            inner: Self::new(None, None, None, None, None, Extensible::new()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ManualProxyConfigurationBuilder {
+    inner: ManualProxyConfiguration,
+}
+
+impl ManualProxyConfigurationBuilder {
+    pub fn ftp_proxy(mut self, ftp_proxy: String) -> Self {
+        self.inner.ftp_proxy = Some(ftp_proxy);
+        self
+    }
+
+    pub fn http_proxy(mut self, http_proxy: String) -> Self {
+        self.inner.http_proxy = Some(http_proxy);
+        self
+    }
+
+    pub fn ssl_proxy(mut self, ssl_proxy: String) -> Self {
+        self.inner.ssl_proxy = Some(ssl_proxy);
+        self
+    }
+
+    pub fn socks_proxy_configuration(
+        mut self,
+        socks_proxy_configuration: SocksProxyConfiguration,
+    ) -> Self {
+        self.inner.socks_proxy_configuration = Some(socks_proxy_configuration);
+        self
+    }
+
+    pub fn no_proxy(mut self, no_proxy: Vec<String>) -> Self {
+        self.inner.no_proxy = Some(no_proxy);
+        self
+    }
+
+    pub fn extensible(mut self, extensible: Extensible) -> Self {
+        self.inner.extensible = extensible;
+        self
+    }
+
+    pub fn build(self) -> ManualProxyConfiguration {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -183,17 +309,15 @@ impl SocksProxyConfiguration {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PacProxyConfiguration {
-    #[serde(rename = "proxyType")]
-    pub proxy_type: String,
     #[serde(rename = "proxyAutoconfigUrl")]
     pub proxy_autoconfig_url: String,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
 impl PacProxyConfiguration {
-    pub fn new(proxy_type: String, proxy_autoconfig_url: String, extensible: Extensible) -> Self {
+    pub fn new(proxy_autoconfig_url: String, extensible: Extensible) -> Self {
         Self {
-            proxy_type,
             proxy_autoconfig_url,
             extensible,
         }
@@ -202,17 +326,13 @@ impl PacProxyConfiguration {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SystemProxyConfiguration {
-    #[serde(rename = "proxyType")]
-    pub proxy_type: String,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
 impl SystemProxyConfiguration {
-    pub fn new(proxy_type: String, extensible: Extensible) -> Self {
-        Self {
-            proxy_type,
-            extensible,
-        }
+    pub fn new(extensible: Extensible) -> Self {
+        Self { extensible }
     }
 }
 
@@ -227,6 +347,8 @@ pub struct UserPromptHandler {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<UserPromptHandlerType>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<UserPromptHandlerType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt: Option<UserPromptHandlerType>,
 }
 
@@ -236,6 +358,7 @@ impl UserPromptHandler {
         before_unload: Option<UserPromptHandlerType>,
         confirm: Option<UserPromptHandlerType>,
         default: Option<UserPromptHandlerType>,
+        file: Option<UserPromptHandlerType>,
         prompt: Option<UserPromptHandlerType>,
     ) -> Self {
         Self {
@@ -243,9 +366,57 @@ impl UserPromptHandler {
             before_unload,
             confirm,
             default,
+            file,
             prompt,
         }
     }
+
+    pub fn builder() -> UserPromptHandlerBuilder {
+        UserPromptHandlerBuilder {
+            inner: Self::new(None, None, None, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UserPromptHandlerBuilder {
+    inner: UserPromptHandler,
+}
+
+impl UserPromptHandlerBuilder {
+    pub fn alert(mut self, alert: UserPromptHandlerType) -> Self {
+        self.inner.alert = Some(alert);
+        self
+    }
+
+    pub fn before_unload(mut self, before_unload: UserPromptHandlerType) -> Self {
+        self.inner.before_unload = Some(before_unload);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: UserPromptHandlerType) -> Self {
+        self.inner.confirm = Some(confirm);
+        self
+    }
+
+    pub fn default(mut self, default: UserPromptHandlerType) -> Self {
+        self.inner.default = Some(default);
+        self
+    }
+
+    pub fn file(mut self, file: UserPromptHandlerType) -> Self {
+        self.inner.file = Some(file);
+        self
+    }
+
+    pub fn prompt(mut self, prompt: UserPromptHandlerType) -> Self {
+        self.inner.prompt = Some(prompt);
+        self
+    }
+
+    pub fn build(self) -> UserPromptHandler {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -254,6 +425,8 @@ pub enum UserPromptHandlerType {
     Accept,
     Dismiss,
     Ignore,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 pub type Subscription = String;
@@ -279,6 +452,38 @@ impl SubscriptionRequest {
             user_contexts,
         }
     }
+
+    pub fn builder(events: Vec<String>) -> SubscriptionRequestBuilder {
+        SubscriptionRequestBuilder {
+            inner: Self::new(events, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SubscriptionRequestBuilder {
+    inner: SubscriptionRequest,
+}
+
+impl SubscriptionRequestBuilder {
+    pub fn contexts(mut self, contexts: Vec<BrowsingContext>) -> Self {
+        self.inner.contexts = Some(contexts);
+        self
+    }
+
+    pub fn user_contexts(mut self, user_contexts: Vec<UserContext>) -> Self {
+        self.inner.user_contexts = Some(user_contexts);
+        self
+    }
+
+    pub fn build(self) -> SubscriptionRequest {
+        self.inner
+    }
+}
+
+impl BidiCommand for SubscriptionRequest {
+    const METHOD: &'static str = "session.subscribe";
+    type Result = local::session::SubscribeResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -302,18 +507,32 @@ pub struct UnsubscribeByAttributesRequest {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Status {
     pub method: String,
-    pub params: EmptyParams,
+    pub params: StatusParameters,
 }
 
 impl Status {
-    pub fn new(params: EmptyParams) -> Self {
+    pub fn new(params: StatusParameters) -> Self {
         Self {
-            method: "session.status".to_string(),
+            method: StatusParameters::METHOD.to_string(),
             params,
         }
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct StatusParameters {}
+
+impl StatusParameters {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BidiCommand for StatusParameters {
+    const METHOD: &'static str = "session.status";
+    type Result = local::session::StatusResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct New {
     pub method: String,
@@ -323,7 +542,7 @@ pub struct New {
 impl New {
     pub fn new(params: NewParameters) -> Self {
         Self {
-            method: "session.new".to_string(),
+            method: NewParameters::METHOD.to_string(),
             params,
         }
     }
@@ -340,21 +559,40 @@ impl NewParameters {
     }
 }
 
+impl BidiCommand for NewParameters {
+    const METHOD: &'static str = "session.new";
+    type Result = local::session::NewResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct End {
     pub method: String,
-    pub params: EmptyParams,
+    pub params: EndParameters,
 }
 
 impl End {
-    pub fn new(params: EmptyParams) -> Self {
+    pub fn new(params: EndParameters) -> Self {
         Self {
-            method: "session.end".to_string(),
+            method: EndParameters::METHOD.to_string(),
             params,
         }
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EndParameters {}
+
+impl EndParameters {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BidiCommand for EndParameters {
+    const METHOD: &'static str = "session.end";
+    type Result = local::result_data::EmptyResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Subscribe {
     pub method: String,
@@ -364,7 +602,7 @@ pub struct Subscribe {
 impl Subscribe {
     pub fn new(params: SubscriptionRequest) -> Self {
         Self {
-            method: "session.subscribe".to_string(),
+            method: SubscriptionRequest::METHOD.to_string(),
             params,
         }
     }
@@ -379,7 +617,7 @@ pub struct Unsubscribe {
 impl Unsubscribe {
     pub fn new(params: UnsubscribeParameters) -> Self {
         Self {
-            method: "session.unsubscribe".to_string(),
+            method: UnsubscribeParameters::METHOD.to_string(),
             params,
         }
     }
@@ -391,3 +629,8 @@ pub enum UnsubscribeParameters {
     UnsubscribeByAttributesRequest(UnsubscribeByAttributesRequest),
     UnsubscribeByIDRequest(UnsubscribeByIDRequest),
 }
+
+impl BidiCommand for UnsubscribeParameters {
+    const METHOD: &'static str = "session.unsubscribe";
+    type Result = local::result_data::EmptyResult;
+}
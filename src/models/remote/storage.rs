@@ -1,6 +1,9 @@
 #![allow(clippy::all)]
 
+use crate::local;
+use crate::remote::browser::UserContext;
 use crate::remote::browsing_context;
+use crate::remote::command::BidiCommand;
 use crate::remote::network::{BytesValue, SameSite};
 use crate::remote::{Extensible, JsUint};
 use serde::{Deserialize, Serialize};
@@ -16,20 +19,48 @@ pub enum StorageCommand {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PartionKey {
     #[serde(rename = "userContext", skip_serializing_if = "Option::is_none")]
-    pub user_context: Option<String>,
+    pub user_context: Option<UserContext>,
     #[serde(rename = "sourceOrigin", skip_serializing_if = "Option::is_none")]
     pub source_origin: Option<String>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
 impl PartionKey {
-    pub fn new(user_context: Option<String>, source_origin: Option<String>) -> Self {
+    pub fn new(user_context: Option<UserContext>, source_origin: Option<String>) -> Self {
         Self {
             user_context,
             source_origin,
             extensible: Extensible::new(),
         }
     }
+
+    pub fn builder() -> PartionKeyBuilder {
+        PartionKeyBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartionKeyBuilder {
+    inner: PartionKey,
+}
+
+impl PartionKeyBuilder {
+    pub fn user_context(mut self, user_context: UserContext) -> Self {
+        self.inner.user_context = Some(user_context);
+        self
+    }
+
+    pub fn source_origin(mut self, source_origin: String) -> Self {
+        self.inner.source_origin = Some(source_origin);
+        self
+    }
+
+    pub fn build(self) -> PartionKey {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -41,7 +72,7 @@ pub struct GetCookies {
 impl GetCookies {
     pub fn new(params: GetCookiesParameters) -> Self {
         Self {
-            method: "storage.getCookies".to_string(),
+            method: GetCookiesParameters::METHOD.to_string(),
             params,
         }
     }
@@ -67,6 +98,7 @@ pub struct CookieFilter {
     pub same_site: Option<SameSite>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expiry: Option<JsUint>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
@@ -96,50 +128,150 @@ impl CookieFilter {
             extensible,
         }
     }
+
+    pub fn builder() -> CookieFilterBuilder {
+        CookieFilterBuilder {
+            inner: Self::new(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Extensible::new(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CookieFilterBuilder {
+    inner: CookieFilter,
+}
+
+impl CookieFilterBuilder {
+    pub fn name(mut self, name: String) -> Self {
+        self.inner.name = Some(name);
+        self
+    }
+
+    pub fn value(mut self, value: BytesValue) -> Self {
+        self.inner.value = Some(value);
+        self
+    }
+
+    pub fn domain(mut self, domain: String) -> Self {
+        self.inner.domain = Some(domain);
+        self
+    }
+
+    pub fn path(mut self, path: String) -> Self {
+        self.inner.path = Some(path);
+        self
+    }
+
+    pub fn size(mut self, size: JsUint) -> Self {
+        self.inner.size = Some(size);
+        self
+    }
+
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.inner.http_only = Some(http_only);
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.inner.secure = Some(secure);
+        self
+    }
+
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.inner.same_site = Some(same_site);
+        self
+    }
+
+    pub fn expiry(mut self, expiry: JsUint) -> Self {
+        self.inner.expiry = Some(expiry);
+        self
+    }
+
+    pub fn extensible(mut self, extensible: Extensible) -> Self {
+        self.inner.extensible = extensible;
+        self
+    }
+
+    pub fn build(self) -> CookieFilter {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BrowsingContextPartitionDescriptor {
-    #[serde(rename = "type")]
-    pub browsing_context_partition_descriptor_type: String,
     pub context: browsing_context::BrowsingContext,
 }
 
 impl BrowsingContextPartitionDescriptor {
     pub fn new(context: browsing_context::BrowsingContext) -> Self {
-        Self {
-            browsing_context_partition_descriptor_type: "context".to_string(),
-            context,
-        }
+        Self { context }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct StorageKeyPartitionDescriptor {
-    #[serde(rename = "type")]
-    pub storage_key_partition_descriptor_type: String,
     #[serde(rename = "userContext", skip_serializing_if = "Option::is_none")]
-    pub user_context: Option<String>,
+    pub user_context: Option<UserContext>,
     #[serde(rename = "sourceOrigin", skip_serializing_if = "Option::is_none")]
     pub source_origin: Option<String>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
 impl StorageKeyPartitionDescriptor {
-    pub fn new(user_context: Option<String>, source_origin: Option<String>) -> Self {
+    pub fn new(user_context: Option<UserContext>, source_origin: Option<String>) -> Self {
         Self {
-            storage_key_partition_descriptor_type: "storageKey".to_string(),
             user_context,
             source_origin,
             extensible: Extensible::new(),
         }
     }
+
+    pub fn builder() -> StorageKeyPartitionDescriptorBuilder {
+        StorageKeyPartitionDescriptorBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StorageKeyPartitionDescriptorBuilder {
+    inner: StorageKeyPartitionDescriptor,
+}
+
+impl StorageKeyPartitionDescriptorBuilder {
+    pub fn user_context(mut self, user_context: UserContext) -> Self {
+        self.inner.user_context = Some(user_context);
+        self
+    }
+
+    pub fn source_origin(mut self, source_origin: String) -> Self {
+        self.inner.source_origin = Some(source_origin);
+        self
+    }
+
+    pub fn build(self) -> StorageKeyPartitionDescriptor {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum PartitionDescriptor {
+    #[serde(rename = "context")]
     BrowsingContextPartitionDescriptor(BrowsingContextPartitionDescriptor),
+    #[serde(rename = "storageKey")]
     StorageKeyPartitionDescriptor(StorageKeyPartitionDescriptor),
 }
 
@@ -155,6 +287,38 @@ impl GetCookiesParameters {
     pub fn new(filter: Option<CookieFilter>, partition: Option<PartitionDescriptor>) -> Self {
         Self { filter, partition }
     }
+
+    pub fn builder() -> GetCookiesParametersBuilder {
+        GetCookiesParametersBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GetCookiesParametersBuilder {
+    inner: GetCookiesParameters,
+}
+
+impl GetCookiesParametersBuilder {
+    pub fn filter(mut self, filter: CookieFilter) -> Self {
+        self.inner.filter = Some(filter);
+        self
+    }
+
+    pub fn partition(mut self, partition: PartitionDescriptor) -> Self {
+        self.inner.partition = Some(partition);
+        self
+    }
+
+    pub fn build(self) -> GetCookiesParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for GetCookiesParameters {
+    const METHOD: &'static str = "storage.getCookies";
+    type Result = local::storage::GetCookiesResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -166,7 +330,7 @@ pub struct SetCookie {
 impl SetCookie {
     pub fn new(params: SetCookieParameters) -> Self {
         Self {
-            method: "storage.setCookie".to_string(),
+            method: SetCookieParameters::METHOD.to_string(),
             params,
         }
     }
@@ -187,6 +351,7 @@ pub struct PartialCookie {
     pub same_site: Option<SameSite>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expiry: Option<JsUint>,
+    #[serde(flatten)]
     pub extensible: Extensible,
 }
 
@@ -214,6 +379,63 @@ impl PartialCookie {
             extensible,
         }
     }
+
+    pub fn builder(name: String, value: BytesValue, domain: String) -> PartialCookieBuilder {
+        PartialCookieBuilder {
+            inner: Self::new(
+                name,
+                value,
+                domain,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Extensible::new(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartialCookieBuilder {
+    inner: PartialCookie,
+}
+
+impl PartialCookieBuilder {
+    pub fn path(mut self, path: String) -> Self {
+        self.inner.path = Some(path);
+        self
+    }
+
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.inner.http_only = Some(http_only);
+        self
+    }
+
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.inner.secure = Some(secure);
+        self
+    }
+
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.inner.same_site = Some(same_site);
+        self
+    }
+
+    pub fn expiry(mut self, expiry: JsUint) -> Self {
+        self.inner.expiry = Some(expiry);
+        self
+    }
+
+    pub fn extensible(mut self, extensible: Extensible) -> Self {
+        self.inner.extensible = extensible;
+        self
+    }
+
+    pub fn build(self) -> PartialCookie {
+        self.inner
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -227,6 +449,33 @@ impl SetCookieParameters {
     pub fn new(cookie: PartialCookie, partition: Option<PartitionDescriptor>) -> Self {
         Self { cookie, partition }
     }
+
+    pub fn builder(cookie: PartialCookie) -> SetCookieParametersBuilder {
+        SetCookieParametersBuilder {
+            inner: Self::new(cookie, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SetCookieParametersBuilder {
+    inner: SetCookieParameters,
+}
+
+impl SetCookieParametersBuilder {
+    pub fn partition(mut self, partition: PartitionDescriptor) -> Self {
+        self.inner.partition = Some(partition);
+        self
+    }
+
+    pub fn build(self) -> SetCookieParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SetCookieParameters {
+    const METHOD: &'static str = "storage.setCookie";
+    type Result = local::storage::SetCookieResult;
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -238,7 +487,7 @@ pub struct DeleteCookies {
 impl DeleteCookies {
     pub fn new(params: DeleteCookiesParameters) -> Self {
         Self {
-            method: "storage.deleteCookies".to_string(),
+            method: DeleteCookiesParameters::METHOD.to_string(),
             params,
         }
     }
@@ -256,4 +505,36 @@ impl DeleteCookiesParameters {
     pub fn new(filter: Option<CookieFilter>, partition: Option<PartitionDescriptor>) -> Self {
         Self { filter, partition }
     }
+
+    pub fn builder() -> DeleteCookiesParametersBuilder {
+        DeleteCookiesParametersBuilder {
+            inner: Self::new(None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteCookiesParametersBuilder {
+    inner: DeleteCookiesParameters,
+}
+
+impl DeleteCookiesParametersBuilder {
+    pub fn filter(mut self, filter: CookieFilter) -> Self {
+        self.inner.filter = Some(filter);
+        self
+    }
+
+    pub fn partition(mut self, partition: PartitionDescriptor) -> Self {
+        self.inner.partition = Some(partition);
+        self
+    }
+
+    pub fn build(self) -> DeleteCookiesParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for DeleteCookiesParameters {
+    const METHOD: &'static str = "storage.deleteCookies";
+    type Result = local::storage::DeleteCookiesResult;
 }
@@ -0,0 +1,628 @@
+#![allow(clippy::all)]
+
+use crate::local;
+use crate::remote::browsing_context::BrowsingContext;
+use crate::remote::command::BidiCommand;
+use crate::remote::JsUint;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WebAuthnCommand {
+    AddVirtualAuthenticator(AddVirtualAuthenticator),
+    RemoveVirtualAuthenticator(RemoveVirtualAuthenticator),
+    AddCredential(AddCredential),
+    GetCredentials(GetCredentials),
+    RemoveCredential(RemoveCredential),
+    RemoveAllCredentials(RemoveAllCredentials),
+    SetUserVerified(SetUserVerified),
+    SetCredentialProperties(SetCredentialProperties),
+}
+
+pub type AuthenticatorId = String;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum AuthenticatorProtocol {
+    #[serde(rename = "ctap1/u2f")]
+    Ctap1U2f,
+    #[serde(rename = "ctap2")]
+    Ctap2,
+    #[serde(rename = "ctap2_1")]
+    Ctap21,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuthenticatorTransport {
+    Usb,
+    Nfc,
+    Ble,
+    SmartCard,
+    Hybrid,
+    Internal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthenticatorConfiguration {
+    pub protocol: AuthenticatorProtocol,
+    pub transport: AuthenticatorTransport,
+    #[serde(rename = "hasResidentKey", skip_serializing_if = "Option::is_none")]
+    pub has_resident_key: Option<bool>,
+    #[serde(
+        rename = "hasUserVerification",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub has_user_verification: Option<bool>,
+    #[serde(rename = "isUserConsenting", skip_serializing_if = "Option::is_none")]
+    pub is_user_consenting: Option<bool>,
+    #[serde(rename = "isUserVerified", skip_serializing_if = "Option::is_none")]
+    pub is_user_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Vec<String>>,
+    #[serde(
+        rename = "defaultBackupEligibility",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_backup_eligibility: Option<bool>,
+    #[serde(rename = "defaultBackupState", skip_serializing_if = "Option::is_none")]
+    pub default_backup_state: Option<bool>,
+}
+
+impl AuthenticatorConfiguration {
+    pub fn new(
+        protocol: AuthenticatorProtocol,
+        transport: AuthenticatorTransport,
+        has_resident_key: Option<bool>,
+        has_user_verification: Option<bool>,
+        is_user_consenting: Option<bool>,
+        is_user_verified: Option<bool>,
+        extensions: Option<Vec<String>>,
+        default_backup_eligibility: Option<bool>,
+        default_backup_state: Option<bool>,
+    ) -> Self {
+        Self {
+            protocol,
+            transport,
+            has_resident_key,
+            has_user_verification,
+            is_user_consenting,
+            is_user_verified,
+            extensions,
+            default_backup_eligibility,
+            default_backup_state,
+        }
+    }
+
+    pub fn builder(
+        protocol: AuthenticatorProtocol,
+        transport: AuthenticatorTransport,
+    ) -> AuthenticatorConfigurationBuilder {
+        AuthenticatorConfigurationBuilder {
+            inner: Self::new(
+                protocol, transport, None, None, None, None, None, None, None,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct AuthenticatorConfigurationBuilder {
+    inner: AuthenticatorConfiguration,
+}
+
+impl AuthenticatorConfigurationBuilder {
+    pub fn has_resident_key(mut self, has_resident_key: bool) -> Self {
+        self.inner.has_resident_key = Some(has_resident_key);
+        self
+    }
+
+    pub fn has_user_verification(mut self, has_user_verification: bool) -> Self {
+        self.inner.has_user_verification = Some(has_user_verification);
+        self
+    }
+
+    pub fn is_user_consenting(mut self, is_user_consenting: bool) -> Self {
+        self.inner.is_user_consenting = Some(is_user_consenting);
+        self
+    }
+
+    pub fn is_user_verified(mut self, is_user_verified: bool) -> Self {
+        self.inner.is_user_verified = Some(is_user_verified);
+        self
+    }
+
+    pub fn extensions(mut self, extensions: Vec<String>) -> Self {
+        self.inner.extensions = Some(extensions);
+        self
+    }
+
+    pub fn default_backup_eligibility(mut self, default_backup_eligibility: bool) -> Self {
+        self.inner.default_backup_eligibility = Some(default_backup_eligibility);
+        self
+    }
+
+    pub fn default_backup_state(mut self, default_backup_state: bool) -> Self {
+        self.inner.default_backup_state = Some(default_backup_state);
+        self
+    }
+
+    pub fn build(self) -> AuthenticatorConfiguration {
+        self.inner
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Credential {
+    #[serde(rename = "credentialId")]
+    pub credential_id: String,
+    #[serde(rename = "isResidentCredential")]
+    pub is_resident_credential: bool,
+    #[serde(rename = "rpId")]
+    pub rp_id: String,
+    #[serde(rename = "privateKey")]
+    pub private_key: String,
+    #[serde(rename = "userHandle", skip_serializing_if = "Option::is_none")]
+    pub user_handle: Option<String>,
+    #[serde(rename = "signCount")]
+    pub sign_count: JsUint,
+    #[serde(rename = "largeBlob", skip_serializing_if = "Option::is_none")]
+    pub large_blob: Option<String>,
+    #[serde(rename = "backupEligibility", skip_serializing_if = "Option::is_none")]
+    pub backup_eligibility: Option<bool>,
+    #[serde(rename = "backupState", skip_serializing_if = "Option::is_none")]
+    pub backup_state: Option<bool>,
+    #[serde(rename = "userName", skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(rename = "userDisplayName", skip_serializing_if = "Option::is_none")]
+    pub user_display_name: Option<String>,
+}
+
+impl Credential {
+    pub fn new(
+        credential_id: String,
+        is_resident_credential: bool,
+        rp_id: String,
+        private_key: String,
+        user_handle: Option<String>,
+        sign_count: JsUint,
+        large_blob: Option<String>,
+        backup_eligibility: Option<bool>,
+        backup_state: Option<bool>,
+        user_name: Option<String>,
+        user_display_name: Option<String>,
+    ) -> Self {
+        Self {
+            credential_id,
+            is_resident_credential,
+            rp_id,
+            private_key,
+            user_handle,
+            sign_count,
+            large_blob,
+            backup_eligibility,
+            backup_state,
+            user_name,
+            user_display_name,
+        }
+    }
+
+    pub fn builder(
+        credential_id: String,
+        is_resident_credential: bool,
+        rp_id: String,
+        private_key: String,
+        sign_count: JsUint,
+    ) -> CredentialBuilder {
+        CredentialBuilder {
+            inner: Self::new(
+                credential_id,
+                is_resident_credential,
+                rp_id,
+                private_key,
+                None,
+                sign_count,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CredentialBuilder {
+    inner: Credential,
+}
+
+impl CredentialBuilder {
+    pub fn user_handle(mut self, user_handle: String) -> Self {
+        self.inner.user_handle = Some(user_handle);
+        self
+    }
+
+    pub fn large_blob(mut self, large_blob: String) -> Self {
+        self.inner.large_blob = Some(large_blob);
+        self
+    }
+
+    pub fn backup_eligibility(mut self, backup_eligibility: bool) -> Self {
+        self.inner.backup_eligibility = Some(backup_eligibility);
+        self
+    }
+
+    pub fn backup_state(mut self, backup_state: bool) -> Self {
+        self.inner.backup_state = Some(backup_state);
+        self
+    }
+
+    pub fn user_name(mut self, user_name: String) -> Self {
+        self.inner.user_name = Some(user_name);
+        self
+    }
+
+    pub fn user_display_name(mut self, user_display_name: String) -> Self {
+        self.inner.user_display_name = Some(user_display_name);
+        self
+    }
+
+    pub fn build(self) -> Credential {
+        self.inner
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddVirtualAuthenticator {
+    pub method: String,
+    pub params: AddVirtualAuthenticatorParameters,
+}
+
+impl AddVirtualAuthenticator {
+    pub fn new(params: AddVirtualAuthenticatorParameters) -> Self {
+        Self {
+            method: AddVirtualAuthenticatorParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddVirtualAuthenticatorParameters {
+    pub context: BrowsingContext,
+    #[serde(flatten)]
+    pub configuration: AuthenticatorConfiguration,
+}
+
+impl AddVirtualAuthenticatorParameters {
+    pub fn new(context: BrowsingContext, configuration: AuthenticatorConfiguration) -> Self {
+        Self {
+            context,
+            configuration,
+        }
+    }
+}
+
+impl BidiCommand for AddVirtualAuthenticatorParameters {
+    const METHOD: &'static str = "webAuthn.addVirtualAuthenticator";
+    type Result = local::web_authn::AddVirtualAuthenticatorResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveVirtualAuthenticator {
+    pub method: String,
+    pub params: RemoveVirtualAuthenticatorParameters,
+}
+
+impl RemoveVirtualAuthenticator {
+    pub fn new(params: RemoveVirtualAuthenticatorParameters) -> Self {
+        Self {
+            method: RemoveVirtualAuthenticatorParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveVirtualAuthenticatorParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+}
+
+impl RemoveVirtualAuthenticatorParameters {
+    pub fn new(context: BrowsingContext, authenticator_id: AuthenticatorId) -> Self {
+        Self {
+            context,
+            authenticator_id,
+        }
+    }
+}
+
+impl BidiCommand for RemoveVirtualAuthenticatorParameters {
+    const METHOD: &'static str = "webAuthn.removeVirtualAuthenticator";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddCredential {
+    pub method: String,
+    pub params: AddCredentialParameters,
+}
+
+impl AddCredential {
+    pub fn new(params: AddCredentialParameters) -> Self {
+        Self {
+            method: AddCredentialParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddCredentialParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+    #[serde(flatten)]
+    pub credential: Credential,
+}
+
+impl AddCredentialParameters {
+    pub fn new(
+        context: BrowsingContext,
+        authenticator_id: AuthenticatorId,
+        credential: Credential,
+    ) -> Self {
+        Self {
+            context,
+            authenticator_id,
+            credential,
+        }
+    }
+}
+
+impl BidiCommand for AddCredentialParameters {
+    const METHOD: &'static str = "webAuthn.addCredential";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCredentials {
+    pub method: String,
+    pub params: GetCredentialsParameters,
+}
+
+impl GetCredentials {
+    pub fn new(params: GetCredentialsParameters) -> Self {
+        Self {
+            method: GetCredentialsParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetCredentialsParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+}
+
+impl GetCredentialsParameters {
+    pub fn new(context: BrowsingContext, authenticator_id: AuthenticatorId) -> Self {
+        Self {
+            context,
+            authenticator_id,
+        }
+    }
+}
+
+impl BidiCommand for GetCredentialsParameters {
+    const METHOD: &'static str = "webAuthn.getCredentials";
+    type Result = local::web_authn::GetCredentialsResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveCredential {
+    pub method: String,
+    pub params: RemoveCredentialParameters,
+}
+
+impl RemoveCredential {
+    pub fn new(params: RemoveCredentialParameters) -> Self {
+        Self {
+            method: RemoveCredentialParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveCredentialParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+    #[serde(rename = "credentialId")]
+    pub credential_id: String,
+}
+
+impl RemoveCredentialParameters {
+    pub fn new(
+        context: BrowsingContext,
+        authenticator_id: AuthenticatorId,
+        credential_id: String,
+    ) -> Self {
+        Self {
+            context,
+            authenticator_id,
+            credential_id,
+        }
+    }
+}
+
+impl BidiCommand for RemoveCredentialParameters {
+    const METHOD: &'static str = "webAuthn.removeCredential";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveAllCredentials {
+    pub method: String,
+    pub params: RemoveAllCredentialsParameters,
+}
+
+impl RemoveAllCredentials {
+    pub fn new(params: RemoveAllCredentialsParameters) -> Self {
+        Self {
+            method: RemoveAllCredentialsParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveAllCredentialsParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+}
+
+impl RemoveAllCredentialsParameters {
+    pub fn new(context: BrowsingContext, authenticator_id: AuthenticatorId) -> Self {
+        Self {
+            context,
+            authenticator_id,
+        }
+    }
+}
+
+impl BidiCommand for RemoveAllCredentialsParameters {
+    const METHOD: &'static str = "webAuthn.removeAllCredentials";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetUserVerified {
+    pub method: String,
+    pub params: SetUserVerifiedParameters,
+}
+
+impl SetUserVerified {
+    pub fn new(params: SetUserVerifiedParameters) -> Self {
+        Self {
+            method: SetUserVerifiedParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetUserVerifiedParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+    #[serde(rename = "isUserVerified")]
+    pub is_user_verified: bool,
+}
+
+impl SetUserVerifiedParameters {
+    pub fn new(
+        context: BrowsingContext,
+        authenticator_id: AuthenticatorId,
+        is_user_verified: bool,
+    ) -> Self {
+        Self {
+            context,
+            authenticator_id,
+            is_user_verified,
+        }
+    }
+}
+
+impl BidiCommand for SetUserVerifiedParameters {
+    const METHOD: &'static str = "webAuthn.setUserVerified";
+    type Result = local::result_data::EmptyResult;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetCredentialProperties {
+    pub method: String,
+    pub params: SetCredentialPropertiesParameters,
+}
+
+impl SetCredentialProperties {
+    pub fn new(params: SetCredentialPropertiesParameters) -> Self {
+        Self {
+            method: SetCredentialPropertiesParameters::METHOD.to_string(),
+            params,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetCredentialPropertiesParameters {
+    pub context: BrowsingContext,
+    #[serde(rename = "authenticatorId")]
+    pub authenticator_id: AuthenticatorId,
+    #[serde(rename = "credentialId")]
+    pub credential_id: String,
+    #[serde(rename = "backupEligibility", skip_serializing_if = "Option::is_none")]
+    pub backup_eligibility: Option<bool>,
+    #[serde(rename = "backupState", skip_serializing_if = "Option::is_none")]
+    pub backup_state: Option<bool>,
+}
+
+impl SetCredentialPropertiesParameters {
+    pub fn new(
+        context: BrowsingContext,
+        authenticator_id: AuthenticatorId,
+        credential_id: String,
+        backup_eligibility: Option<bool>,
+        backup_state: Option<bool>,
+    ) -> Self {
+        Self {
+            context,
+            authenticator_id,
+            credential_id,
+            backup_eligibility,
+            backup_state,
+        }
+    }
+
+    pub fn builder(
+        context: BrowsingContext,
+        authenticator_id: AuthenticatorId,
+        credential_id: String,
+    ) -> SetCredentialPropertiesParametersBuilder {
+        SetCredentialPropertiesParametersBuilder {
+            inner: Self::new(context, authenticator_id, credential_id, None, None),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SetCredentialPropertiesParametersBuilder {
+    inner: SetCredentialPropertiesParameters,
+}
+
+impl SetCredentialPropertiesParametersBuilder {
+    pub fn backup_eligibility(mut self, backup_eligibility: bool) -> Self {
+        self.inner.backup_eligibility = Some(backup_eligibility);
+        self
+    }
+
+    pub fn backup_state(mut self, backup_state: bool) -> Self {
+        self.inner.backup_state = Some(backup_state);
+        self
+    }
+
+    pub fn build(self) -> SetCredentialPropertiesParameters {
+        self.inner
+    }
+}
+
+impl BidiCommand for SetCredentialPropertiesParameters {
+    const METHOD: &'static str = "webAuthn.setCredentialProperties";
+    type Result = local::result_data::EmptyResult;
+}
@@ -1,3 +1,5 @@
+use crate::local;
+use crate::remote::command::BidiCommand;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,7 +20,7 @@ pub struct Install {
 impl Install {
     pub fn new(params: InstallParameters) -> Self {
         Self {
-            method: "webExtension.install".to_string(),
+            method: InstallParameters::METHOD.to_string(),
             params,
         }
     }
@@ -36,59 +38,52 @@ impl InstallParameters {
     }
 }
 
+impl BidiCommand for InstallParameters {
+    const METHOD: &'static str = "webExtension.install";
+    type Result = local::web_extension::InstallResult;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(tag = "type")]
 pub enum ExtensionData {
+    #[serde(rename = "archivePath")]
     ExtensionArchivePath(ExtensionArchivePath),
+    #[serde(rename = "base64")]
     ExtensionBase64Encoded(ExtensionBase64Encoded),
+    #[serde(rename = "path")]
     ExtensionPath(ExtensionPath),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExtensionPath {
-    #[serde(rename = "type")]
-    pub extension_path_type: String,
     pub path: String,
 }
 
 impl ExtensionPath {
     pub fn new(path: String) -> Self {
-        Self {
-            extension_path_type: "path".to_string(),
-            path,
-        }
+        Self { path }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExtensionArchivePath {
-    #[serde(rename = "type")]
-    pub extension_archive_path_type: String,
     pub path: String,
 }
 
 impl ExtensionArchivePath {
     pub fn new(path: String) -> Self {
-        Self {
-            extension_archive_path_type: "archivePath".to_string(),
-            path,
-        }
+        Self { path }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExtensionBase64Encoded {
-    #[serde(rename = "type")]
-    pub extension_base64_encoded_type: String,
     pub value: String,
 }
 
 impl ExtensionBase64Encoded {
     pub fn new(value: String) -> Self {
-        Self {
-            extension_base64_encoded_type: "base64".to_string(),
-            value,
-        }
+        Self { value }
     }
 }
 
@@ -101,7 +96,7 @@ pub struct Uninstall {
 impl Uninstall {
     pub fn new(params: UninstallParameters) -> Self {
         Self {
-            method: "webExtension.uninstall".to_string(),
+            method: UninstallParameters::METHOD.to_string(),
             params,
         }
     }
@@ -117,3 +112,8 @@ impl UninstallParameters {
         Self { extension }
     }
 }
+
+impl BidiCommand for UninstallParameters {
+    const METHOD: &'static str = "webExtension.uninstall";
+    type Result = local::result_data::EmptyResult;
+}
@@ -0,0 +1,551 @@
+//! Building `remote::script::LocalValue` arguments from Rust values.
+//!
+//! [`LocalValue::from_serialize`] turns any `T: Serialize` into a `LocalValue`, the same
+//! way `serde_json::to_value` turns it into JSON. Structs and maps with string keys
+//! become objects, maps with other keys become `Map`s, sequences become arrays, and
+//! `None` and `()` become `null`. Non-finite floats and `-0.0` are sent as special
+//! numbers. `i128` and `u128` are sent as BigInts, and any other integer beyond
+//! `Number.MAX_SAFE_INTEGER` is an error rather than a rounded number.
+
+use serde::ser::{self, Serialize};
+
+use crate::error::ValueError;
+use crate::remote::script::{
+    ArrayLocalValue, BigIntValue, BooleanValue, LocalValue, LocalValueOrText, MapLocalValue,
+    NullValue, NumberOrSpecialNumber, NumberValue, ObjectLocalValue, PrimitiveProtocolValue,
+    SpecialNumber, StringValue,
+};
+
+// Integers beyond this are not represented exactly by a JavaScript number.
+const MAX_SAFE_INTEGER: u128 = 9007199254740991;
+
+// --------------------------------------------------
+
+impl LocalValue {
+    /// Serializes `value` into a `LocalValue`.
+    ///
+    /// ```
+    /// # use webdriverbidi::remote::script::LocalValue;
+    /// #[derive(serde::Serialize)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// let argument = LocalValue::from_serialize(&Point { x: 1, y: 2 }).unwrap();
+    /// assert!(matches!(argument, LocalValue::ObjectLocalValue(_)));
+    /// ```
+    pub fn from_serialize<T: Serialize + ?Sized>(value: &T) -> Result<Self, ValueError> {
+        value.serialize(LocalValueSerializer)
+    }
+}
+
+fn primitive(value: PrimitiveProtocolValue) -> LocalValue {
+    LocalValue::PrimitiveProtocolValue(value)
+}
+
+fn null() -> LocalValue {
+    primitive(PrimitiveProtocolValue::NullValue(NullValue::new()))
+}
+
+fn string(value: String) -> LocalValue {
+    primitive(PrimitiveProtocolValue::StringValue(StringValue::new(value)))
+}
+
+fn float(value: f64) -> LocalValue {
+    let number = if value.is_nan() {
+        NumberOrSpecialNumber::SpecialNumber(SpecialNumber::NaN)
+    } else if value == f64::INFINITY {
+        NumberOrSpecialNumber::SpecialNumber(SpecialNumber::Infinity)
+    } else if value == f64::NEG_INFINITY {
+        NumberOrSpecialNumber::SpecialNumber(SpecialNumber::NegativeInfinity)
+    } else if value == 0.0 && value.is_sign_negative() {
+        NumberOrSpecialNumber::SpecialNumber(SpecialNumber::NegativeZero)
+    } else {
+        NumberOrSpecialNumber::Number(value)
+    };
+    primitive(PrimitiveProtocolValue::NumberValue(NumberValue::new(
+        number,
+    )))
+}
+
+fn int(value: i64) -> Result<LocalValue, ValueError> {
+    if u128::from(value.unsigned_abs()) <= MAX_SAFE_INTEGER {
+        Ok(float(value as f64))
+    } else {
+        Err(unsafe_integer(value))
+    }
+}
+
+fn uint(value: u64) -> Result<LocalValue, ValueError> {
+    if u128::from(value) <= MAX_SAFE_INTEGER {
+        Ok(float(value as f64))
+    } else {
+        Err(unsafe_integer(value))
+    }
+}
+
+fn unsafe_integer(value: impl std::fmt::Display) -> ValueError {
+    ValueError(format!(
+        "{} is beyond Number.MAX_SAFE_INTEGER, use an i128 or u128 to send it as a BigInt",
+        value
+    ))
+}
+
+fn bigint(value: impl ToString) -> LocalValue {
+    primitive(PrimitiveProtocolValue::BigIntValue(BigIntValue::new(
+        value.to_string(),
+    )))
+}
+
+fn object(entries: Vec<(String, LocalValue)>) -> LocalValue {
+    LocalValue::ObjectLocalValue(ObjectLocalValue::new(
+        entries
+            .into_iter()
+            .map(|(key, value)| (LocalValueOrText::Text(key), value))
+            .collect(),
+    ))
+}
+
+// Externally tagged enum variants: `{ "Variant": value }`.
+fn tagged(name: &'static str, value: LocalValue) -> LocalValue {
+    object(vec![(name.to_string(), value)])
+}
+
+// --------------------------------------------------
+
+/// A serde `Serializer` whose output is a `remote::script::LocalValue`.
+struct LocalValueSerializer;
+
+impl ser::Serializer for LocalValueSerializer {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    type SerializeSeq = SerializeSeq;
+    type SerializeTuple = SerializeSeq;
+    type SerializeTupleStruct = SerializeSeq;
+    type SerializeTupleVariant = SerializeSeq;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeStruct;
+    type SerializeStructVariant = SerializeStruct;
+
+    fn serialize_bool(self, v: bool) -> Result<LocalValue, ValueError> {
+        Ok(primitive(PrimitiveProtocolValue::BooleanValue(
+            BooleanValue::new(v),
+        )))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<LocalValue, ValueError> {
+        int(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<LocalValue, ValueError> {
+        int(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<LocalValue, ValueError> {
+        int(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<LocalValue, ValueError> {
+        int(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<LocalValue, ValueError> {
+        Ok(bigint(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<LocalValue, ValueError> {
+        uint(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<LocalValue, ValueError> {
+        uint(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<LocalValue, ValueError> {
+        uint(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<LocalValue, ValueError> {
+        uint(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<LocalValue, ValueError> {
+        Ok(bigint(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<LocalValue, ValueError> {
+        Ok(float(v.into()))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<LocalValue, ValueError> {
+        Ok(float(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<LocalValue, ValueError> {
+        Ok(string(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<LocalValue, ValueError> {
+        Ok(string(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<LocalValue, ValueError> {
+        Ok(LocalValue::ArrayLocalValue(ArrayLocalValue::new(
+            v.iter().map(|&b| float(b.into())).collect(),
+        )))
+    }
+
+    fn serialize_none(self) -> Result<LocalValue, ValueError> {
+        Ok(null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<LocalValue, ValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<LocalValue, ValueError> {
+        Ok(null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<LocalValue, ValueError> {
+        Ok(null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<LocalValue, ValueError> {
+        Ok(string(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<LocalValue, ValueError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<LocalValue, ValueError> {
+        Ok(tagged(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeSeq, ValueError> {
+        Ok(SerializeSeq {
+            variant: None,
+            items: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeSeq, ValueError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, ValueError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, ValueError> {
+        Ok(SerializeSeq {
+            variant: Some(variant),
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, ValueError> {
+        Ok(SerializeMap {
+            entries: Vec::with_capacity(len.unwrap_or_default()),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeStruct, ValueError> {
+        Ok(SerializeStruct {
+            variant: None,
+            fields: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeStruct, ValueError> {
+        Ok(SerializeStruct {
+            variant: Some(variant),
+            fields: Vec::with_capacity(len),
+        })
+    }
+}
+
+// --------------------------------------------------
+
+struct SerializeSeq {
+    variant: Option<&'static str>,
+    items: Vec<LocalValue>,
+}
+
+impl SerializeSeq {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.items.push(value.serialize(LocalValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<LocalValue, ValueError> {
+        let array = LocalValue::ArrayLocalValue(ArrayLocalValue::new(self.items));
+        Ok(match self.variant {
+            Some(name) => tagged(name, array),
+            None => array,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SerializeSeq {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<LocalValue, ValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeSeq {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<LocalValue, ValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeSeq {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<LocalValue, ValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeSeq {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<LocalValue, ValueError> {
+        self.finish()
+    }
+}
+
+// --------------------------------------------------
+
+struct SerializeMap {
+    entries: Vec<(LocalValue, LocalValue)>,
+    next_key: Option<LocalValue>,
+}
+
+fn as_string(value: &LocalValue) -> Option<&str> {
+    match value {
+        LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(s)) => {
+            Some(&s.value)
+        }
+        _ => None,
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ValueError> {
+        self.next_key = Some(key.serialize(LocalValueSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ValueError> {
+        let key = self
+            .next_key
+            .take()
+            .ok_or_else(|| ValueError("map value serialized before its key".to_string()))?;
+        self.entries
+            .push((key, value.serialize(LocalValueSerializer)?));
+        Ok(())
+    }
+
+    // Maps keyed by strings are sent as plain objects; any other key type needs a `Map`.
+    fn end(self) -> Result<LocalValue, ValueError> {
+        if self.entries.iter().all(|(key, _)| as_string(key).is_some()) {
+            let entries = self
+                .entries
+                .into_iter()
+                .map(|(key, value)| (as_string(&key).unwrap_or_default().to_string(), value))
+                .collect();
+            return Ok(object(entries));
+        }
+        Ok(LocalValue::MapLocalValue(MapLocalValue::new(
+            self.entries
+                .into_iter()
+                .map(|(key, value)| (LocalValueOrText::LocalValue(key), value))
+                .collect(),
+        )))
+    }
+}
+
+// --------------------------------------------------
+
+struct SerializeStruct {
+    variant: Option<&'static str>,
+    fields: Vec<(String, LocalValue)>,
+}
+
+impl SerializeStruct {
+    fn push<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ValueError> {
+        self.fields
+            .push((key.to_string(), value.serialize(LocalValueSerializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<LocalValue, ValueError> {
+        let object = object(self.fields);
+        Ok(match self.variant {
+            Some(name) => tagged(name, object),
+            None => object,
+        })
+    }
+}
+
+impl ser::SerializeStruct for SerializeStruct {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ValueError> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<LocalValue, ValueError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeStruct {
+    type Ok = LocalValue;
+    type Error = ValueError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ValueError> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<LocalValue, ValueError> {
+        self.finish()
+    }
+}
+
+// --------------------------------------------------
+
+macro_rules! from_primitive {
+    ($($ty:ty => $convert:expr),* $(,)?) => {
+        $(
+            impl From<$ty> for LocalValue {
+                fn from(value: $ty) -> Self {
+                    $convert(value)
+                }
+            }
+        )*
+    };
+}
+
+from_primitive!(
+    bool => |v| primitive(PrimitiveProtocolValue::BooleanValue(BooleanValue::new(v))),
+    i8 => |v: i8| float(v.into()),
+    i16 => |v: i16| float(v.into()),
+    i32 => |v: i32| float(v.into()),
+    i128 => bigint,
+    u8 => |v: u8| float(v.into()),
+    u16 => |v: u16| float(v.into()),
+    u32 => |v: u32| float(v.into()),
+    u128 => bigint,
+    f32 => |v: f32| float(v.into()),
+    f64 => float,
+    char => |v: char| string(v.to_string()),
+    &str => |v: &str| string(v.to_string()),
+    String => string,
+);
+
+/// Fails when the value is beyond `Number.MAX_SAFE_INTEGER`. Convert it to an `i128`
+/// to send it as a BigInt.
+impl TryFrom<i64> for LocalValue {
+    type Error = ValueError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        int(value)
+    }
+}
+
+/// Fails when the value is beyond `Number.MAX_SAFE_INTEGER`. Convert it to a `u128`
+/// to send it as a BigInt.
+impl TryFrom<u64> for LocalValue {
+    type Error = ValueError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        uint(value)
+    }
+}
+
+impl<T: Into<LocalValue>> From<Option<T>> for LocalValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or_else(null, Into::into)
+    }
+}
@@ -16,29 +16,40 @@ use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
 
 // --------------------------------------------------
 
+use crate::binding;
+use crate::channel::{ChannelRouter, ChannelStream};
 use crate::command_sender;
 use crate::commands;
-use crate::error::{CommandError, SessionError};
+use crate::error::{CommandError, ScriptError, SessionError, ValueError};
 use crate::events::EventType;
+use crate::js_handle::{DisownQueue, JsHandle};
 use crate::local::browser::ClientWindowInfo;
 use crate::local::browser::*;
 use crate::local::browsing_context::*;
 use crate::local::network::*;
+use crate::local::script as local_script;
 use crate::local::script::EvaluateResult;
 use crate::local::script::*;
 use crate::local::session::*;
 use crate::local::storage::*;
+use crate::local::web_authn::*;
 use crate::local::web_extension::*;
 use crate::message_handler;
 use crate::models::local::result_data::EmptyResult;
+use crate::remote::bluetooth::*;
 use crate::remote::browser::*;
+use crate::remote::browsing_context::CloseParameters;
+use crate::remote::command::BidiCommand;
 use crate::remote::input::*;
 use crate::remote::network::*;
+use crate::remote::permissions::*;
+use crate::remote::script as remote_script;
 use crate::remote::script::*;
 use crate::remote::session::*;
 use crate::remote::storage::*;
+use crate::remote::web_authn::*;
 use crate::remote::web_extension::*;
-use crate::remote::{browsing_context::*, EmptyParams};
+use crate::remote::{browser, browsing_context::*, Extensible};
 use crate::webdriver::capabilities::CapabilitiesRequest;
 use crate::webdriver::session;
 
@@ -66,6 +77,8 @@ pub type EventHandler =
 /// * `websocket_stream` - The WebSocket stream for communication protected by an `Arc` wrapped `Mutex`.
 /// * `pending_commands` - A map of pending commands awaiting responses protected by an `Arc` wrapped `Mutex`.
 /// * `event_handlers` - A map of events and their handlers protected by an `Arc` wrapped `Mutex`.
+/// * `disown_queue` - The handles of dropped `JsHandle`s awaiting `script.disown`, per realm.
+/// * `channels` - The channels opened by `add_preload_script_with_channel` and `expose_function`.
 #[derive(Clone)]
 pub struct WebDriverBiDiSession {
     pub host: String,
@@ -77,6 +90,8 @@ pub struct WebDriverBiDiSession {
     pub websocket_stream: Option<Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>>,
     pub pending_commands: Arc<Mutex<HashMap<u64, oneshot::Sender<Value>>>>,
     event_handlers: Arc<Mutex<HashMap<EventType, EventHandler>>>,
+    disown_queue: DisownQueue,
+    channels: ChannelRouter,
 }
 
 impl WebDriverBiDiSession {
@@ -100,6 +115,8 @@ impl WebDriverBiDiSession {
             websocket_stream: None,
             pending_commands: Arc::new(Mutex::new(HashMap::new())),
             event_handlers: Arc::new(Mutex::new(HashMap::new())),
+            disown_queue: DisownQueue::default(),
+            channels: ChannelRouter::default(),
         }
     }
 
@@ -124,10 +141,16 @@ impl WebDriverBiDiSession {
 
         let pending_commands = self.pending_commands.clone();
         let event_handlers = self.event_handlers.clone();
+        let channels = self.channels.clone();
 
         debug!("Starting the incoming messages management loop");
         // Spawn a background task to manage incoming messages
-        self.spawn_message_handler_task(websocket_stream, pending_commands, event_handlers);
+        self.spawn_message_handler_task(
+            websocket_stream,
+            pending_commands,
+            event_handlers,
+            channels,
+        );
 
         Ok(())
     }
@@ -165,6 +188,395 @@ impl WebDriverBiDiSession {
         }
     }
 
+    /// Sends a command by method name, allocating its ID.
+    ///
+    /// This is meant for commands without a dedicated method on the session,
+    /// such as vendor extensions (e.g. `goog:cdp.sendCommand`) or commands
+    /// recently added to the specification.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method name of the command, e.g. `browsingContext.getTree`.
+    /// * `params` - The command parameters as a JSON value.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `result` field of the response as a JSON value,
+    /// or a `CommandError`.
+    pub async fn call(&mut self, method: &str, params: Value) -> Result<Value, CommandError> {
+        commands::raw::call(self, method, params).await
+    }
+
+    /// Sends a command by method name with typed parameters and result,
+    /// allocating its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The method name of the command, e.g. `browsingContext.getTree`.
+    /// * `params` - The command parameters, of a type that implements `Serialize`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the response of type `R` that implements the `DeserializeOwned` trait,
+    /// or a `CommandError`.
+    pub async fn call_typed<P, R>(&mut self, method: &str, params: P) -> Result<R, CommandError>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        commands::raw::call(self, method, params).await
+    }
+
+    /// Sends a command, inferring its method name and result type from its parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters of the command, of a type that implements `BidiCommand`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the command's `BidiCommand::Result` or a `CommandError`.
+    pub async fn execute<P: BidiCommand>(&mut self, params: P) -> Result<P::Result, CommandError> {
+        commands::raw::call(self, P::METHOD, params).await
+    }
+
+    /// Evaluates an expression in a browsing context and deserializes its value.
+    ///
+    /// Promises are awaited. A JavaScript exception is returned as
+    /// `ScriptError::Exception`.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The browsing context to evaluate the expression in.
+    /// * `expression` - The JavaScript expression, e.g. `document.title`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the value of the expression as a `T`, or a `ScriptError`.
+    pub async fn eval<T: DeserializeOwned>(
+        &mut self,
+        context: impl Into<BrowsingContext>,
+        expression: &str,
+    ) -> Result<T, ScriptError> {
+        let params = EvaluateParameters::new(
+            expression.to_string(),
+            context_target(context.into()),
+            true,
+            None,
+            None,
+            None,
+        );
+        let result = self.script_evaluate(params).await?;
+        evaluate_result_into(result)
+    }
+
+    /// Calls a function in a browsing context and deserializes its return value.
+    ///
+    /// Promises are awaited. A JavaScript exception is returned as
+    /// `ScriptError::Exception`.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The browsing context to call the function in.
+    /// * `function_declaration` - The function to call, e.g. `(a, b) => a + b`.
+    /// * `arguments` - The arguments of the function, see `LocalValue::from_serialize`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the return value of the function as a `T`, or a `ScriptError`.
+    pub async fn call_function<T: DeserializeOwned>(
+        &mut self,
+        context: impl Into<BrowsingContext>,
+        function_declaration: &str,
+        arguments: Vec<remote_script::LocalValue>,
+    ) -> Result<T, ScriptError> {
+        let params = CallFunctionParameters::builder(
+            function_declaration.to_string(),
+            true,
+            context_target(context.into()),
+        )
+        .arguments(arguments)
+        .build();
+        let result = self.script_call_function(params).await?;
+        evaluate_result_into(result)
+    }
+
+    /// Evaluates an expression in a browsing context and keeps its value alive behind a handle.
+    ///
+    /// Promises are awaited. The handle is disowned when the returned `JsHandle` is
+    /// dropped, see `flush_disowns`.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The browsing context to evaluate the expression in.
+    /// * `expression` - The JavaScript expression, which must evaluate to an object.
+    ///
+    /// # Returns
+    ///
+    /// A result containing a `JsHandle` to the value, or a `ScriptError`.
+    pub async fn eval_handle(
+        &mut self,
+        context: impl Into<BrowsingContext>,
+        expression: &str,
+    ) -> Result<JsHandle, ScriptError> {
+        let params = EvaluateParameters::new(
+            expression.to_string(),
+            context_target(context.into()),
+            true,
+            Some(remote_script::ResultOwnership::Root),
+            None,
+            None,
+        );
+        let result = self.script_evaluate(params).await?;
+        let success = evaluate_result_success(result)?;
+        Ok(JsHandle::new(self.clone(), success.realm, success.result)?)
+    }
+
+    /// Adds a preload script that can post messages back to Rust.
+    ///
+    /// The function receives a channel as its first argument: calling it with a value
+    /// posts a `script.message` event, which is deserialized as a `T` and yielded by the
+    /// returned stream. The session subscribes to `script.message` with its first channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `function_declaration` - The preload script, e.g. `(send) => send(location.href)`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `ChannelStream` of the messages, or a `CommandError`.
+    /// Removing its preload script with `script_remove_preload_script` ends the stream.
+    pub async fn add_preload_script_with_channel<T: DeserializeOwned>(
+        &mut self,
+        function_declaration: &str,
+    ) -> Result<ChannelStream<T>, CommandError> {
+        let (channel, script, receiver) = self
+            .add_channel_preload_script(function_declaration)
+            .await?;
+        Ok(ChannelStream::new(
+            channel,
+            script,
+            receiver,
+            self.channels.clone(),
+        ))
+    }
+
+    /// Exposes an async Rust function to page JavaScript as `window[name]`.
+    ///
+    /// Calling the function from the page returns a Promise that resolves with the value
+    /// returned by `function`, or rejects with an `Error` carrying its error message. The
+    /// arguments of the call are always passed as an array, so `A` is a tuple such as
+    /// `(T,)` for a single argument or `(T, U)` for two, or a `Vec<T>`.
+    /// The function is defined in every document, including iframes and documents loaded
+    /// after a navigation, and in the documents that are already loaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the global function.
+    /// * `function` - The function to run for each call.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the preload script defining the function, or a `CommandError`.
+    /// Removing it with `script_remove_preload_script` stops answering calls.
+    pub async fn expose_function<A, R, E, F, Fut>(
+        &mut self,
+        name: &str,
+        function: F,
+    ) -> Result<PreloadScript, CommandError>
+    where
+        A: DeserializeOwned + Send + 'static,
+        R: Serialize + Send + 'static,
+        E: std::fmt::Display + Send + 'static,
+        F: Fn(A) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+    {
+        let declaration = binding::preload_function(name);
+        let (channel, script, receiver) = self.add_channel_preload_script(&declaration).await?;
+        binding::spawn_dispatcher(self.clone(), name.to_string(), receiver, function);
+
+        // Preload scripts only run in new documents.
+        let realms = self
+            .script_get_realms(remote_script::GetRealmsParameters::new(
+                None,
+                Some(remote_script::RealmType::Window),
+            ))
+            .await?;
+        for realm in realms.realms {
+            let RealmInfo::WindowRealmInfo(info) = realm else {
+                continue;
+            };
+            if info.sandbox.is_some() {
+                continue;
+            }
+            let params = CallFunctionParameters::builder(
+                declaration.clone(),
+                false,
+                Target::RealmTarget(remote_script::RealmTarget::new(info.base.realm)),
+            )
+            .arguments(vec![remote_script::LocalValue::ChannelValue(
+                channel_value(&channel),
+            )])
+            .build();
+            if let Err(e) = self.script_call_function(params).await {
+                debug!("Failed to define {} in an existing realm: {}", name, e);
+            }
+        }
+        Ok(script)
+    }
+
+    /// Adds a preload script taking a new channel as its only argument, and subscribes
+    /// to the `script.message` events the channel posts.
+    async fn add_channel_preload_script(
+        &mut self,
+        function_declaration: &str,
+    ) -> Result<
+        (
+            remote_script::Channel,
+            PreloadScript,
+            tokio::sync::mpsc::UnboundedReceiver<MessageParameters>,
+        ),
+        CommandError,
+    > {
+        let (channel, receiver) = self.channels.open();
+        let params = AddPreloadScriptParameters::builder(function_declaration.to_string())
+            .arguments(vec![channel_value(&channel)])
+            .build();
+        let result = match self.script_add_preload_script(params).await {
+            Ok(result) => result,
+            Err(e) => {
+                self.channels.close(&channel);
+                return Err(e);
+            }
+        };
+        self.channels.bind(&channel, result.script.clone());
+        // One subscription serves every channel of the session.
+        if self.channels.claim_subscription() {
+            let subscription =
+                SubscriptionRequest::new(vec!["script.message".to_string()], None, None);
+            if let Err(e) = self.session_subscribe(subscription).await {
+                self.channels.release_subscription();
+                return Err(e);
+            }
+        }
+        Ok((channel, result.script, receiver))
+    }
+
+    /// Disowns the handles of every `JsHandle` dropped so far, with one `script.disown`
+    /// command per realm.
+    ///
+    /// Dropping a `JsHandle` inside a Tokio runtime already schedules this in the
+    /// background; calling it directly guarantees the handles are released, e.g.
+    /// before closing the session.
+    ///
+    /// # Returns
+    ///
+    /// A result containing `()` or the first `CommandError` encountered.
+    pub async fn flush_disowns(&mut self) -> Result<(), CommandError> {
+        let queued = std::mem::take(&mut *self.disown_queue.lock().unwrap());
+        let mut result = Ok(());
+        for (realm, handles) in queued {
+            let params = DisownParameters::new(
+                handles,
+                Target::RealmTarget(remote_script::RealmTarget::new(realm)),
+            );
+            if let Err(e) = self.script_disown(params).await {
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    /// Queues a handle to be disowned, scheduling a flush if none is pending.
+    pub(crate) fn queue_disown(&self, realm: Realm, handle: Handle) {
+        let was_empty = {
+            let mut queue = self.disown_queue.lock().unwrap();
+            let was_empty = queue.is_empty();
+            queue.entry(realm).or_default().push(handle);
+            was_empty
+        };
+        // Handles dropped during the same tick are batched into the flush scheduled by
+        // the first one. Outside of a runtime they wait for the next `flush_disowns`.
+        if was_empty && tokio::runtime::Handle::try_current().is_ok() {
+            let mut session = self.clone();
+            task::spawn(async move {
+                task::yield_now().await;
+                if let Err(e) = session.flush_disowns().await {
+                    debug!("Failed to disown dropped handles: {}", e);
+                }
+            });
+        }
+    }
+
+    /// Reads the bytes of a typed array, `DataView` or `ArrayBuffer` in the page.
+    ///
+    /// The value must carry a handle, i.e. it must have been returned with
+    /// `remote_script::ResultOwnership::Root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The browsing context the value belongs to.
+    /// * `value` - The typed array, `DataView` or `ArrayBuffer`.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the bytes, or a `ScriptError`.
+    pub async fn read_bytes(
+        &mut self,
+        context: impl Into<BrowsingContext>,
+        value: &local_script::RemoteValue,
+    ) -> Result<Vec<u8>, ScriptError> {
+        let handle = value.handle().ok_or_else(|| {
+            ValueError(
+                "the value has no handle, request it with remote_script::ResultOwnership::Root"
+                    .into(),
+            )
+        })?;
+        let reference =
+            remote_script::RemoteObjectReference::new(handle.clone(), None, Extensible::new());
+        let arguments = vec![remote_script::LocalValue::RemoteReference(
+            remote_script::RemoteReference::RemoteObjectReference(reference),
+        )];
+        let binary: String = self
+            .call_function(context, READ_BYTES_FUNCTION, arguments)
+            .await?;
+        binary_string_to_bytes(&binary)
+    }
+
+    /// Copies bytes into a new `Uint8Array` in the page.
+    ///
+    /// Pass the array as an argument to `call_function` with `JsHandle::to_local_value`.
+    /// The array is disowned when the returned `JsHandle` is dropped, see `flush_disowns`.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The browsing context to create the array in.
+    /// * `bytes` - The contents of the array.
+    ///
+    /// # Returns
+    ///
+    /// A result containing a `JsHandle` to the array, or a `ScriptError`.
+    pub async fn create_bytes(
+        &mut self,
+        context: impl Into<BrowsingContext>,
+        bytes: &[u8],
+    ) -> Result<JsHandle, ScriptError> {
+        let binary: String = bytes.iter().map(|&b| char::from(b)).collect();
+        let params = CallFunctionParameters::builder(
+            CREATE_BYTES_FUNCTION.to_string(),
+            false,
+            context_target(context.into()),
+        )
+        .arguments(vec![binary.into()])
+        .result_ownership(remote_script::ResultOwnership::Root)
+        .build();
+        let result = self.script_call_function(params).await?;
+        let success = evaluate_result_success(result)?;
+        Ok(JsHandle::new(self.clone(), success.realm, success.result)?)
+    }
+
     /// Spawns a background task to manage incoming WebSocket messages.
     ///
     /// This method creates a new asynchronous task that continuously listens for
@@ -174,11 +586,13 @@ impl WebDriverBiDiSession {
         websocket_stream: Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>,
         pending_commands: Arc<Mutex<HashMap<u64, oneshot::Sender<Value>>>>,
         event_handlers: Arc<Mutex<HashMap<EventType, EventHandler>>>,
+        channels: ChannelRouter,
     ) {
         task::spawn(message_handler::handle_messages(
             websocket_stream,
             pending_commands,
             event_handlers,
+            channels,
         ));
     }
 
@@ -209,6 +623,65 @@ impl WebDriverBiDiSession {
     }
 }
 
+// Bytes cross the protocol as "binary strings", one char code in 0..=255 per byte, which
+// is far more compact than an array of numbers.
+const READ_BYTES_FUNCTION: &str = r#"(value) => {
+    const bytes = ArrayBuffer.isView(value)
+        ? new Uint8Array(value.buffer, value.byteOffset, value.byteLength)
+        : new Uint8Array(value);
+    let binary = "";
+    for (let i = 0; i < bytes.length; i += 0x8000) {
+        binary += String.fromCharCode(...bytes.subarray(i, i + 0x8000));
+    }
+    return binary;
+}"#;
+
+const CREATE_BYTES_FUNCTION: &str = "(binary) => Uint8Array.from(binary, (c) => c.charCodeAt(0))";
+
+fn binary_string_to_bytes(binary: &str) -> Result<Vec<u8>, ScriptError> {
+    binary
+        .chars()
+        .map(|c| {
+            u8::try_from(c).map_err(|_| ValueError(format!("unexpected char {:?} in bytes", c)))
+        })
+        .collect::<Result<_, _>>()
+        .map_err(ScriptError::from)
+}
+
+fn channel_value(channel: &str) -> remote_script::ChannelValue {
+    remote_script::ChannelValue::new(remote_script::ChannelProperties::new(
+        channel.to_string(),
+        None,
+        None,
+    ))
+}
+
+fn context_target(context: BrowsingContext) -> Target {
+    Target::ContextTarget(ContextTarget::new(context, None))
+}
+
+fn evaluate_result_into<T: DeserializeOwned>(result: EvaluateResult) -> Result<T, ScriptError> {
+    Ok(evaluate_result_value(result)?.into()?)
+}
+
+fn evaluate_result_value(result: EvaluateResult) -> Result<local_script::RemoteValue, ScriptError> {
+    Ok(evaluate_result_success(result)?.result)
+}
+
+pub(crate) fn evaluate_result_success(
+    result: EvaluateResult,
+) -> Result<local_script::EvaluateResultSuccess, ScriptError> {
+    match result {
+        EvaluateResult::EvaluateResultSuccess(success) => Ok(success),
+        EvaluateResult::EvaluateResultException(exception) => Err(ScriptError::Exception(
+            Box::new(exception.exception_details),
+        )),
+        EvaluateResult::EmptyResult(_) => Err(ScriptError::ValueError(ValueError(
+            "the command returned an empty result".to_string(),
+        ))),
+    }
+}
+
 // Browsing context commands
 impl WebDriverBiDiSession {
     // https://w3c.github.io/webdriver-bidi/#command-browsingContext-activate
@@ -452,7 +925,7 @@ impl WebDriverBiDiSession {
     /// A result containing the `SessionStatus` or a `CommandError`.
     pub async fn session_status(
         &mut self,
-        params: EmptyParams,
+        params: StatusParameters,
     ) -> Result<StatusResult, CommandError> {
         commands::session::status(self, params).await
     }
@@ -478,12 +951,15 @@ impl WebDriverBiDiSession {
     ///
     /// # Arguments
     ///
-    /// * `params` - The parameters as an `EmptyParams` instance.
+    /// * `params` - The parameters as an `EndParameters` instance.
     ///
     /// # Returns
     ///
     /// A result containing the `EmptyResult` or a `CommandError`.
-    pub async fn session_end(&mut self, params: EmptyParams) -> Result<EmptyResult, CommandError> {
+    pub async fn session_end(
+        &mut self,
+        params: EndParameters,
+    ) -> Result<EmptyResult, CommandError> {
         commands::session::end(self, params).await
     }
 
@@ -534,32 +1010,33 @@ impl WebDriverBiDiSession {
     ///
     /// # Arguments
     ///
-    /// * `params` - The parameters as an `EmptyParams` instance.
+    /// * `params` - The parameters as a `browser::CloseParameters` instance.
     ///
     /// # Returns
     ///
     /// A result containing the `EmptyResult` or a `CommandError`.
     pub async fn browser_close(
         &mut self,
-        params: EmptyParams,
+        params: browser::CloseParameters,
     ) -> Result<EmptyResult, CommandError> {
         commands::browser::close(self, params).await
     }
 
     // https://w3c.github.io/webdriver-bidi/#command-browser-createUserContext
 
-    /// Creates a new user context.
+    /// Creates a new user context, optionally with its own proxy, certificate
+    /// and user prompt settings.
     ///
     /// # Arguments
     ///
-    /// * `params` - The parameters as an `EmptyParams` instance.
+    /// * `params` - The parameters as a `CreateUserContextParameters` instance.
     ///
     /// # Returns
     ///
     /// A result containing the `CreateUserContextResult` or a `CommandError`.
     pub async fn browser_create_user_context(
         &mut self,
-        params: EmptyParams,
+        params: CreateUserContextParameters,
     ) -> Result<CreateUserContextResult, CommandError> {
         commands::browser::create_user_context(self, params).await
     }
@@ -570,14 +1047,14 @@ impl WebDriverBiDiSession {
     ///
     /// # Arguments
     ///
-    /// * `params` - The parameters as an `EmptyParams` instance.
+    /// * `params` - The parameters as a `GetClientWindowsParameters` instance.
     ///
     /// # Returns
     ///
     /// A result containing the `GetClientWindowsResult` or a `CommandError`.
     pub async fn browser_get_client_windows(
         &mut self,
-        params: EmptyParams,
+        params: GetClientWindowsParameters,
     ) -> Result<GetClientWindowsResult, CommandError> {
         commands::browser::get_client_windows(self, params).await
     }
@@ -588,14 +1065,14 @@ impl WebDriverBiDiSession {
     ///
     /// # Arguments
     ///
-    /// * `params` - The parameters as an `EmptyParams` instance.
+    /// * `params` - The parameters as a `GetUserContextsParameters` instance.
     ///
     /// # Returns
     ///
     /// A result containing the `GetUserContextsResult` or a `CommandError`.
     pub async fn browser_get_user_contexts(
         &mut self,
-        params: EmptyParams,
+        params: GetUserContextsParameters,
     ) -> Result<GetUserContextsResult, CommandError> {
         commands::browser::get_user_contexts(self, params).await
     }
@@ -896,7 +1373,10 @@ impl WebDriverBiDiSession {
         &mut self,
         params: RemovePreloadScriptParameters,
     ) -> Result<EmptyResult, CommandError> {
-        commands::script::remove_preload_script(self, params).await
+        let script = params.script.clone();
+        let result = commands::script::remove_preload_script(self, params).await?;
+        self.channels.close_script(&script);
+        Ok(result)
     }
 }
 
@@ -1059,3 +1539,396 @@ impl WebDriverBiDiSession {
         commands::web_extension::uninstall(self, params).await
     }
 }
+
+// --------------------------------------------------
+
+// Permissions commands
+impl WebDriverBiDiSession {
+    // https://w3c.github.io/permissions/#webdriver-bidi-command-permissions-setPermission
+
+    /// Sets the state of a permission for the given origin, optionally scoped to a user context.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SetPermissionParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn permissions_set_permission(
+        &mut self,
+        params: SetPermissionParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::permissions::set_permission(self, params).await
+    }
+}
+
+// --------------------------------------------------
+
+// Bluetooth commands
+impl WebDriverBiDiSession {
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-handlerequestdeviceprompt-command
+
+    /// Accepts or cancels a pending device request prompt.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `HandleRequestDevicePromptParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_handle_request_device_prompt(
+        &mut self,
+        params: HandleRequestDevicePromptParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::handle_request_device_prompt(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateAdapter-command
+
+    /// Creates or updates a simulated Bluetooth adapter.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateAdapterParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_adapter(
+        &mut self,
+        params: SimulateAdapterParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_adapter(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-disableSimulation-command
+
+    /// Disables the Bluetooth simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `DisableSimulationParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_disable_simulation(
+        &mut self,
+        params: DisableSimulationParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::disable_simulation(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateconnectedperipheral-command
+
+    /// Creates a simulated Bluetooth peripheral that is already connected to the system.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulatePreconnectedPeripheralParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_preconnected_peripheral(
+        &mut self,
+        params: SimulatePreconnectedPeripheralParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_preconnected_peripheral(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateadvertisement-command
+
+    /// Simulates an advertisement packet received from a Bluetooth peripheral.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateAdvertisementParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_advertisement(
+        &mut self,
+        params: SimulateAdvertisementParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_advertisement(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateGattConnectionResponse-command
+
+    /// Simulates the response to a GATT connection attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateGattConnectionResponseParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_gatt_connection_response(
+        &mut self,
+        params: SimulateGattConnectionResponseParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_gatt_connection_response(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateGattDisconnection-command
+
+    /// Simulates the disconnection of a GATT server.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateGattDisconnectionParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_gatt_disconnection(
+        &mut self,
+        params: SimulateGattDisconnectionParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_gatt_disconnection(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateService-command
+
+    /// Adds or removes a simulated GATT service.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateServiceParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_service(
+        &mut self,
+        params: SimulateServiceParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_service(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateCharacteristic-command
+
+    /// Adds or removes a simulated GATT characteristic.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateCharacteristicParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_characteristic(
+        &mut self,
+        params: SimulateCharacteristicParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_characteristic(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateCharacteristicResponse-command
+
+    /// Simulates the response to a GATT characteristic operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateCharacteristicResponseParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_characteristic_response(
+        &mut self,
+        params: SimulateCharacteristicResponseParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_characteristic_response(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateDescriptor-command
+
+    /// Adds or removes a simulated GATT descriptor.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateDescriptorParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_descriptor(
+        &mut self,
+        params: SimulateDescriptorParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_descriptor(self, params).await
+    }
+
+    // https://webbluetoothcg.github.io/web-bluetooth/#bluetooth-simulateDescriptorResponse-command
+
+    /// Simulates the response to a GATT descriptor operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SimulateDescriptorResponseParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn bluetooth_simulate_descriptor_response(
+        &mut self,
+        params: SimulateDescriptorResponseParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::bluetooth::simulate_descriptor_response(self, params).await
+    }
+}
+
+// --------------------------------------------------
+
+// WebAuthn commands
+impl WebDriverBiDiSession {
+    // https://w3c.github.io/webauthn/#sctn-automation-add-virtual-authenticator
+
+    /// Creates a virtual authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as an `AddVirtualAuthenticatorParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `AddVirtualAuthenticatorResult` or a `CommandError`.
+    pub async fn web_authn_add_virtual_authenticator(
+        &mut self,
+        params: AddVirtualAuthenticatorParameters,
+    ) -> Result<AddVirtualAuthenticatorResult, CommandError> {
+        commands::web_authn::add_virtual_authenticator(self, params).await
+    }
+
+    // https://w3c.github.io/webauthn/#sctn-automation-remove-virtual-authenticator
+
+    /// Removes a previously created virtual authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `RemoveVirtualAuthenticatorParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn web_authn_remove_virtual_authenticator(
+        &mut self,
+        params: RemoveVirtualAuthenticatorParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::web_authn::remove_virtual_authenticator(self, params).await
+    }
+
+    // https://w3c.github.io/webauthn/#sctn-automation-add-credential
+
+    /// Injects a public key credential source into a virtual authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as an `AddCredentialParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn web_authn_add_credential(
+        &mut self,
+        params: AddCredentialParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::web_authn::add_credential(self, params).await
+    }
+
+    // https://w3c.github.io/webauthn/#sctn-automation-get-credentials
+
+    /// Retrieves every public key credential source stored on a virtual authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `GetCredentialsParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `GetCredentialsResult` or a `CommandError`.
+    pub async fn web_authn_get_credentials(
+        &mut self,
+        params: GetCredentialsParameters,
+    ) -> Result<GetCredentialsResult, CommandError> {
+        commands::web_authn::get_credentials(self, params).await
+    }
+
+    // https://w3c.github.io/webauthn/#sctn-automation-remove-credential
+
+    /// Removes a public key credential source stored on a virtual authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `RemoveCredentialParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn web_authn_remove_credential(
+        &mut self,
+        params: RemoveCredentialParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::web_authn::remove_credential(self, params).await
+    }
+
+    // https://w3c.github.io/webauthn/#sctn-automation-remove-all-credentials
+
+    /// Removes all public key credential sources stored on a virtual authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `RemoveAllCredentialsParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn web_authn_remove_all_credentials(
+        &mut self,
+        params: RemoveAllCredentialsParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::web_authn::remove_all_credentials(self, params).await
+    }
+
+    // https://w3c.github.io/webauthn/#sctn-automation-set-user-verified
+
+    /// Sets whether user verification succeeds on a virtual authenticator.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SetUserVerifiedParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn web_authn_set_user_verified(
+        &mut self,
+        params: SetUserVerifiedParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::web_authn::set_user_verified(self, params).await
+    }
+
+    // https://w3c.github.io/webauthn/#sctn-automation-set-credential-properties
+
+    /// Sets the backup eligibility and backup state of a credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters as a `SetCredentialPropertiesParameters` instance.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the `EmptyResult` or a `CommandError`.
+    pub async fn web_authn_set_credential_properties(
+        &mut self,
+        params: SetCredentialPropertiesParameters,
+    ) -> Result<EmptyResult, CommandError> {
+        commands::web_authn::set_credential_properties(self, params).await
+    }
+}
@@ -4,7 +4,8 @@ use anyhow::Result;
 use log::debug;
 use tokio::sync::Mutex;
 use webdriverbidi::events::EventType;
-use webdriverbidi::remote::browser::RemoveUserContextParameters;
+use webdriverbidi::ids::BrowsingContext;
+use webdriverbidi::remote::browser::{CreateUserContextParameters, RemoveUserContextParameters};
 use webdriverbidi::remote::browsing_context::{
     ActivateParameters, CloseParameters, GetTreeParameters,
 };
@@ -30,7 +31,7 @@ mod install_extension {
             )))
             .await
             .unwrap_err();
-        assert!(matches!(err, CommandError::Error(_)));
+        assert!(matches!(err, CommandError::Error { .. }));
 
         utils::session::close(&mut bidi_session).await?;
 
@@ -38,6 +39,110 @@ mod install_extension {
     }
 }
 
+mod call {
+    use serde_json::json;
+    use webdriverbidi::error::CommandError;
+    use webdriverbidi::local::browser::GetUserContextsResult;
+    use webdriverbidi::local::ErrorCode;
+    use webdriverbidi::remote::command::BidiCommand;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_call_by_method_name() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+
+        let rslt = bidi_session
+            .call("browser.getUserContexts", json!({}))
+            .await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        let user_contexts = rslt["userContexts"].as_array().cloned().unwrap_or_default();
+        assert!(user_contexts
+            .iter()
+            .any(|info| info["userContext"] == DEFAULT_USER_CONTEXT));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_call_typed() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+
+        let rslt: GetUserContextsResult = bidi_session
+            .call_typed("browser.getUserContexts", json!({}))
+            .await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert!(rslt
+            .user_contexts
+            .iter()
+            .any(|info| info.user_context == DEFAULT_USER_CONTEXT));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+
+        let user_context = bidi_session
+            .execute(CreateUserContextParameters::new(None, None, None))
+            .await?
+            .user_context;
+        let ids = utils::browser::get_user_context_ids(&mut bidi_session).await?;
+
+        bidi_session
+            .execute(RemoveUserContextParameters::new(user_context.clone()))
+            .await?;
+        let ids_after_removal = utils::browser::get_user_context_ids(&mut bidi_session).await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert!(ids.contains(&user_context));
+        assert!(!ids_after_removal.contains(&user_context));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_extensions() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+
+        let extensible = [("test:flag".to_string(), json!(true))]
+            .into_iter()
+            .collect();
+        let rslt = bidi_session
+            .execute(GetTreeParameters::new(Some(0), None).with_extensions(extensible))
+            .await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert!(!rslt.contexts.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_command() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+
+        let err = bidi_session
+            .call("browser.doesNotExist", json!({}))
+            .await
+            .unwrap_err();
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert!(matches!(err, CommandError::Error { .. }));
+        assert_eq!(err.error_code(), Some(&ErrorCode::UnknownCommand));
+
+        Ok(())
+    }
+}
+
 const DEFAULT_USER_CONTEXT: &str = "default";
 
 // --------------------------------------------------
@@ -45,6 +150,11 @@ const DEFAULT_USER_CONTEXT: &str = "default";
 // https://github.com/web-platform-tests/wpt/tree/master/webdriver/tests/bidi/browser/create_user_context
 mod create_user_context {
     use super::*;
+    use std::time::Duration;
+    use webdriverbidi::local::browsing_context::UserPromptOpened;
+    use webdriverbidi::local::session::UserPromptHandlerType as LocalUserPromptHandlerType;
+    use webdriverbidi::remote::script::{ContextTarget, EvaluateParameters, Target};
+    use webdriverbidi::remote::session::{UserPromptHandler, UserPromptHandlerType};
 
     const TEST_STORAGE_ISOLATION_HTML: &str = "test_storage_isolation.html";
 
@@ -79,6 +189,79 @@ mod create_user_context {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_params_unhandled_prompt_behavior() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        bidi_session
+            .register_event_handler(
+                EventType::BrowsingContextUserPromptOpened,
+                move |event: serde_json::Value| {
+                    let sender = sender.clone();
+                    async move {
+                        debug!(
+                            "Received browsingContext.userPromptOpened event: {:?}",
+                            event
+                        );
+                        let _ = sender.send(event);
+                    }
+                },
+            )
+            .await;
+
+        bidi_session
+            .session_subscribe(SubscriptionRequest::new(
+                vec![String::from("browsingContext.userPromptOpened")],
+                None,
+                None,
+            ))
+            .await?;
+
+        let user_context = bidi_session
+            .browser_create_user_context(
+                CreateUserContextParameters::builder()
+                    .accept_insecure_certs(true)
+                    .unhandled_prompt_behavior(
+                        UserPromptHandler::builder()
+                            .alert(UserPromptHandlerType::Dismiss)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .await?
+            .user_context;
+        let context =
+            utils::browsing_context::new_tab_in_user_context(&mut bidi_session, user_context)
+                .await?;
+
+        // The alert blocks the script until it is handled, so this only returns if the
+        // user context dismissed it.
+        bidi_session
+            .script_evaluate(EvaluateParameters::new(
+                "alert('hello')".to_string(),
+                Target::ContextTarget(ContextTarget::new(context.clone(), None)),
+                false,
+                None,
+                None,
+                None,
+            ))
+            .await?;
+
+        let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv()).await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        let event: UserPromptOpened = serde_json::from_value(event.unwrap())?;
+        assert_eq!(event.params.context, context);
+        assert!(matches!(
+            event.params.handler,
+            LocalUserPromptHandlerType::Dismiss
+        ));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_storage_isolation() -> Result<()> {
         let (url, server_handle) =
@@ -208,7 +391,7 @@ mod get_client_windows {
             .ok_or_else(|| anyhow::anyhow!("initial second window not found"))?;
 
         bidi_session
-            .browsing_context_activate(ActivateParameters::new(initial_context_id.to_string()))
+            .browsing_context_activate(ActivateParameters::new(initial_context_id.clone()))
             .await?;
 
         let final_all_windows = utils::browser::get_client_windows(&mut bidi_session).await?;
@@ -261,7 +444,7 @@ mod get_user_contexts {
         utils::session::close(&mut bidi_session).await?;
 
         assert!(!user_context_ids.is_empty());
-        assert!(user_context_ids.contains(&DEFAULT_USER_CONTEXT.to_string()));
+        assert!(user_context_ids.contains(&DEFAULT_USER_CONTEXT.into()));
 
         Ok(())
     }
@@ -288,14 +471,14 @@ mod get_user_contexts {
         assert!(user_context_ids_1.len() >= 3);
         assert!(user_context_ids_1.contains(&user_context_1));
         assert!(user_context_ids_1.contains(&user_context_2));
-        assert!(user_context_ids_1.contains(&DEFAULT_USER_CONTEXT.to_string()));
+        assert!(user_context_ids_1.contains(&DEFAULT_USER_CONTEXT.into()));
 
         assert!(!user_context_ids_2.contains(&user_context_1));
         assert!(user_context_ids_2.contains(&user_context_2));
-        assert!(user_context_ids_2.contains(&DEFAULT_USER_CONTEXT.to_string()));
+        assert!(user_context_ids_2.contains(&DEFAULT_USER_CONTEXT.into()));
 
         assert!(!user_context_ids_3.contains(&user_context_2));
-        assert!(user_context_ids_3.contains(&DEFAULT_USER_CONTEXT.to_string()));
+        assert!(user_context_ids_3.contains(&DEFAULT_USER_CONTEXT.into()));
 
         Ok(())
     }
@@ -328,7 +511,7 @@ mod remove_user_context {
         assert!(initial_user_context_ids.contains(&user_context));
 
         assert!(!final_user_context_ids.contains(&user_context));
-        assert!(final_user_context_ids.contains(&DEFAULT_USER_CONTEXT.to_string()));
+        assert!(final_user_context_ids.contains(&DEFAULT_USER_CONTEXT.into()));
 
         Ok(())
     }
@@ -404,7 +587,11 @@ mod remove_user_context {
             .lock()
             .await
             .iter()
-            .filter_map(|event| event["parasm"]["context"].as_str().map(String::from))
+            .filter_map(|event| {
+                event["parasm"]["context"]
+                    .as_str()
+                    .map(BrowsingContext::from)
+            })
             .collect::<Vec<_>>();
 
         bidi_session
@@ -419,7 +606,11 @@ mod remove_user_context {
             .lock()
             .await
             .iter()
-            .filter_map(|event| event["parasm"]["context"].as_str().map(String::from))
+            .filter_map(|event| {
+                event["parasm"]["context"]
+                    .as_str()
+                    .map(BrowsingContext::from)
+            })
             .collect::<Vec<_>>();
 
         assert!(initial_events_len == 2);
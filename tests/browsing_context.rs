@@ -1,6 +1,8 @@
 // use webdriverbidi::remote::browsing_context::ActivateParameters;
 // use webdriverbidi::remote::script::{ContextTarget, EvaluateParameters, Target};
 
+// Only some of the shared helpers are used by this test crate.
+#[allow(dead_code)]
 mod utils;
 // use utils::common::*;
 
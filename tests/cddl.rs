@@ -0,0 +1,329 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use webdriverbidi::cddl::Schema;
+use webdriverbidi::ids::{BrowsingContext, SharedId};
+use webdriverbidi::remote::browser::{
+    ClientWindowNamedOrRectState, ClientWindowNamedState, ClientWindowRectState, ClientWindowState,
+    CloseParameters as BrowserCloseParameters, CreateUserContextParameters,
+    GetClientWindowsParameters, GetUserContextsParameters, RemoveUserContextParameters,
+    SetClientWindowStateParameters,
+};
+use webdriverbidi::remote::browsing_context::{
+    ActivateParameters, CaptureScreenshotParameters, CloseParameters, CreateParameters, CreateType,
+    CssLocator, GetTreeParameters, HandleUserPromptParameters, LocateNodesParameters, Locator,
+    NavigateParameters, PrintParameters, ReadinessState, ReloadParameters,
+    TraverseHistoryParameters,
+};
+use webdriverbidi::remote::command::BidiCommand;
+use webdriverbidi::remote::input::{
+    Origin, PerformActionsParameters, PointerCommonProperties, PointerMoveAction,
+    PointerSourceAction, PointerSourceActions, ReleaseActionsParameters, SourceActions,
+};
+use webdriverbidi::remote::network::{
+    AddInterceptParameters, AuthCredentials, ContinueWithAuthCredentials, ContinueWithAuthOption,
+    ContinueWithAuthParameters, InterceptPhase,
+};
+use webdriverbidi::remote::script::{
+    AddPreloadScriptParameters, CallFunctionParameters, ChannelProperties, ChannelValue,
+    ContextTarget, EvaluateParameters, GetRealmsParameters, LocalValue, NumberOrSpecialNumber,
+    NumberValue, PrimitiveProtocolValue, RealmType, ResultOwnership, SharedReference, StringValue,
+    Target,
+};
+use webdriverbidi::remote::session::{
+    EndParameters, StatusParameters, SubscriptionRequest, UserPromptHandler, UserPromptHandlerType,
+};
+use webdriverbidi::remote::storage::GetCookiesParameters;
+use webdriverbidi::remote::Extensible;
+
+// --------------------------------------------------
+
+fn command<P: BidiCommand>(params: P) -> Value {
+    json!({ "id": 1, "method": P::METHOD, "params": params })
+}
+
+fn assert_conforms<P: BidiCommand>(params: P) {
+    let command = command(params);
+    if let Err(err) = Schema::remote().validate_command(&command) {
+        panic!("{} does not conform: {}\n{}", P::METHOD, err, command);
+    }
+}
+
+fn to_value(value: impl Serialize) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+fn context() -> BrowsingContext {
+    BrowsingContext::new("context")
+}
+
+fn target() -> Target {
+    Target::ContextTarget(ContextTarget::new(context(), None))
+}
+
+// --------------------------------------------------
+
+mod commands {
+    use super::*;
+
+    #[test]
+    fn test_browser() {
+        assert_conforms(BrowserCloseParameters::new());
+        assert_conforms(CreateUserContextParameters::new(Some(true), None, None));
+        assert_conforms(CreateUserContextParameters::new(
+            None,
+            None,
+            Some(
+                UserPromptHandler::builder()
+                    .alert(UserPromptHandlerType::Dismiss)
+                    .file(UserPromptHandlerType::Ignore)
+                    .build(),
+            ),
+        ));
+        assert_conforms(GetClientWindowsParameters::new());
+        assert_conforms(GetUserContextsParameters::new());
+        assert_conforms(RemoveUserContextParameters::new("user-context".into()));
+        assert_conforms(SetClientWindowStateParameters::new(
+            "window".to_string(),
+            ClientWindowNamedOrRectState::ClientWindowNamedState(ClientWindowNamedState::new(
+                ClientWindowState::Maximized,
+            )),
+        ));
+        assert_conforms(SetClientWindowStateParameters::new(
+            "window".to_string(),
+            ClientWindowNamedOrRectState::ClientWindowRectState(
+                ClientWindowRectState::builder()
+                    .width(800)
+                    .height(600)
+                    .build(),
+            ),
+        ));
+    }
+
+    #[test]
+    fn test_session() {
+        assert_conforms(StatusParameters::new());
+        assert_conforms(EndParameters::new());
+    }
+
+    #[test]
+    fn test_browsing_context() {
+        assert_conforms(ActivateParameters::new(context()));
+        assert_conforms(CaptureScreenshotParameters::builder(context()).build());
+        assert_conforms(CloseParameters::new(context(), Some(true)));
+        assert_conforms(CreateParameters::new(CreateType::Tab, None, None, None));
+        assert_conforms(GetTreeParameters::new(Some(0), Some(context())));
+        assert_conforms(HandleUserPromptParameters::new(context(), Some(true), None));
+        assert_conforms(
+            LocateNodesParameters::builder(
+                context(),
+                Locator::CssLocator(CssLocator::new("div".to_string())),
+            )
+            .max_node_count(1)
+            .build(),
+        );
+        assert_conforms(NavigateParameters::new(
+            context(),
+            "https://example.com".to_string(),
+            Some(ReadinessState::Complete),
+        ));
+        assert_conforms(PrintParameters::builder(context()).build());
+        assert_conforms(ReloadParameters::new(context(), None, None));
+        assert_conforms(TraverseHistoryParameters::new(context(), -1));
+    }
+
+    #[test]
+    fn test_input() {
+        let pointer_move = PointerSourceAction::PointerMoveAction(PointerMoveAction::new(
+            10,
+            20,
+            None,
+            Some(Origin::Viewport),
+            PointerCommonProperties::builder().build(),
+        ));
+        assert_conforms(PerformActionsParameters::new(
+            context(),
+            vec![SourceActions::PointerSourceActions(
+                PointerSourceActions::new("mouse".to_string(), None, vec![pointer_move]),
+            )],
+        ));
+        assert_conforms(ReleaseActionsParameters::new(context()));
+    }
+
+    #[test]
+    fn test_network() {
+        assert_conforms(
+            AddInterceptParameters::builder(vec![InterceptPhase::BeforeRequestSent]).build(),
+        );
+        assert_conforms(ContinueWithAuthParameters::new(
+            "request".into(),
+            Some(ContinueWithAuthOption::Credentials(
+                ContinueWithAuthCredentials::new(AuthCredentials::new(
+                    "user".to_string(),
+                    "password".to_string(),
+                )),
+            )),
+        ));
+    }
+
+    #[test]
+    fn test_script() {
+        let arguments = vec![
+            LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(
+                StringValue::new("text".to_string()),
+            )),
+            LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::NumberValue(
+                NumberValue::new(NumberOrSpecialNumber::Number(1.0)),
+            )),
+            LocalValue::ChannelValue(ChannelValue::new(ChannelProperties::new(
+                "channel".to_string(),
+                None,
+                None,
+            ))),
+        ];
+        assert_conforms(
+            CallFunctionParameters::builder("(a) => a".to_string(), true, target())
+                .arguments(arguments)
+                .result_ownership(ResultOwnership::Root)
+                .build(),
+        );
+        assert_conforms(EvaluateParameters::new(
+            "1 + 1".to_string(),
+            target(),
+            false,
+            None,
+            None,
+            None,
+        ));
+        assert_conforms(GetRealmsParameters::new(None, Some(RealmType::Window)));
+        assert_conforms(
+            AddPreloadScriptParameters::builder("() => {}".to_string())
+                .arguments(vec![ChannelValue::new(ChannelProperties::new(
+                    "channel".to_string(),
+                    None,
+                    None,
+                ))])
+                .build(),
+        );
+    }
+
+    #[test]
+    fn test_storage() {
+        assert_conforms(GetCookiesParameters::new(None, None));
+    }
+
+    #[test]
+    fn test_command_extensions_are_accepted() {
+        let mut command = command(GetTreeParameters::new(None, None));
+        command["goog:flag"] = json!({ "nested": true });
+        Schema::remote().validate_command(&command).unwrap();
+
+        let extensible = [("goog:flag".to_string(), json!(true))]
+            .into_iter()
+            .collect();
+        assert_conforms(ActivateParameters::new(context()).with_extensions(extensible));
+
+        let mut print = super::command(PrintParameters::builder(context()).build());
+        print["params"]["page"] = json!({ "width": 20, "moz:unit": "cm" });
+        Schema::remote().validate_command(&print).unwrap();
+    }
+}
+
+mod values {
+    use super::*;
+
+    #[test]
+    fn test_subscription_request() {
+        let request = SubscriptionRequest::new(vec!["log.entryAdded".to_string()], None, None);
+        Schema::remote()
+            .validate("session.SubscriptionRequest", &to_value(request))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_shared_reference() {
+        let reference = SharedReference::new(SharedId::new("id"), None, Extensible::new());
+        Schema::remote()
+            .validate("script.SharedReference", &to_value(reference))
+            .unwrap();
+    }
+}
+
+mod errors {
+    use super::*;
+
+    #[test]
+    fn test_unknown_key() {
+        let command = json!({
+            "id": 1,
+            "method": "browsingContext.activate",
+            "params": { "context": "context", "focus": true },
+        });
+        let err = Schema::remote().validate_command(&command).unwrap_err();
+        assert_eq!(err.path, "params.focus");
+    }
+
+    #[test]
+    fn test_wrong_type() {
+        let command = json!({
+            "id": 1,
+            "method": "browsingContext.getTree",
+            "params": { "maxDepth": -1 },
+        });
+        let err = Schema::remote().validate_command(&command).unwrap_err();
+        assert_eq!(err.path, "params.maxDepth");
+    }
+
+    #[test]
+    fn test_missing_key() {
+        let command = json!({ "id": 1, "method": "browsingContext.navigate", "params": {} });
+        let err = Schema::remote().validate_command(&command).unwrap_err();
+        assert_eq!(err.to_string(), "params: missing key `context`");
+    }
+
+    #[test]
+    fn test_unknown_method_is_not_checked() {
+        let command = json!({ "id": 1, "method": "vendor:doesNotExist", "params": [] });
+        assert!(Schema::remote().validate_command(&command).is_ok());
+    }
+}
+
+mod messages {
+    use super::*;
+
+    #[test]
+    fn test_event() {
+        let message = json!({
+            "type": "event",
+            "method": "browsingContext.load",
+            "params": {
+                "context": "context",
+                "navigation": null,
+                "timestamp": 0,
+                "url": "about:blank",
+            },
+        });
+        Schema::local().validate_message(&message).unwrap();
+    }
+
+    #[test]
+    fn test_success() {
+        let message = json!({ "type": "success", "id": 1, "result": {} });
+        Schema::local().validate_message(&message).unwrap();
+    }
+
+    #[test]
+    fn test_error() {
+        let message = json!({
+            "type": "error",
+            "id": 1,
+            "error": "unknown command",
+            "message": "",
+        });
+        Schema::local().validate_message(&message).unwrap();
+
+        let message =
+            json!({ "type": "error", "id": 1, "error": "not an error code", "message": "" });
+        assert!(Schema::local().validate_message(&message).is_err());
+    }
+}
@@ -0,0 +1,771 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+use webdriverbidi::events::EventType;
+use webdriverbidi::ids::BrowsingContext;
+use webdriverbidi::remote::command::BidiCommand;
+
+// --------------------------------------------------
+
+fn context() -> BrowsingContext {
+    BrowsingContext::new("context")
+}
+
+/// Checks that `value` serializes to `expected` and deserializes back to the same JSON.
+fn assert_round_trip<T: Serialize + DeserializeOwned>(value: T, expected: Value) {
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(json, expected);
+    let parsed: T = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(parsed).unwrap(), json);
+}
+
+/// Checks that an event parses as `T` and that its method maps to `event_type`.
+fn parse_event<T: DeserializeOwned>(event: Value, event_type: EventType) -> T {
+    let method = event["method"].as_str().unwrap();
+    assert_eq!(EventType::from_str(method).unwrap(), event_type);
+    serde_json::from_value(event).unwrap()
+}
+
+// --------------------------------------------------
+
+mod bluetooth {
+    use super::*;
+    use webdriverbidi::local::bluetooth::{
+        CharacteristicEventGenerated, CharacteristicEventType, DescriptorEventGenerated,
+        DescriptorEventType, GattConnectionAttempted, RequestDevicePromptUpdated,
+    };
+    use webdriverbidi::remote::bluetooth::{
+        BluetoothManufacturerData, CharacteristicProperties, CharacteristicResponseType,
+        HandleRequestDevicePromptAcceptOrCancelParameters,
+        HandleRequestDevicePromptAcceptParameters, HandleRequestDevicePromptCancelParameters,
+        HandleRequestDevicePromptParameters, ScanRecord, SimulateAdapterParameters,
+        SimulateAdapterState, SimulateAdvertisementParameters,
+        SimulateAdvertisementScanEntryParameters, SimulateCharacteristicParameters,
+        SimulateCharacteristicResponseParameters, SimulatePreconnectedPeripheralParameters,
+        SimulationType,
+    };
+
+    #[test]
+    fn test_handle_request_device_prompt() {
+        assert_eq!(
+            HandleRequestDevicePromptParameters::METHOD,
+            "bluetooth.handleRequestDevicePrompt"
+        );
+        assert_round_trip(
+            HandleRequestDevicePromptParameters::new(
+                context(),
+                "prompt".to_string(),
+                HandleRequestDevicePromptAcceptOrCancelParameters::HandleRequestDevicePromptAcceptParameters(
+                    HandleRequestDevicePromptAcceptParameters::new("device".to_string()),
+                ),
+            ),
+            json!({ "context": "context", "prompt": "prompt", "accept": true, "device": "device" }),
+        );
+        assert_round_trip(
+            HandleRequestDevicePromptParameters::new(
+                context(),
+                "prompt".to_string(),
+                HandleRequestDevicePromptAcceptOrCancelParameters::HandleRequestDevicePromptCancelParameters(
+                    HandleRequestDevicePromptCancelParameters::new(),
+                ),
+            ),
+            json!({ "context": "context", "prompt": "prompt", "accept": false }),
+        );
+    }
+
+    #[test]
+    fn test_simulate_adapter_and_peripherals() {
+        assert_round_trip(
+            SimulateAdapterParameters::builder(context(), SimulateAdapterState::PoweredOn)
+                .le_supported(true)
+                .build(),
+            json!({ "context": "context", "leSupported": true, "state": "powered-on" }),
+        );
+        assert_round_trip(
+            SimulatePreconnectedPeripheralParameters::new(
+                context(),
+                "09:09:09:09:09:09".to_string(),
+                "Heart Rate".to_string(),
+                vec![BluetoothManufacturerData::new(17, "AP8BhIU=".to_string())],
+                vec!["12345678-1234-5678-9abc-def123456789".to_string()],
+            ),
+            json!({
+                "context": "context",
+                "address": "09:09:09:09:09:09",
+                "name": "Heart Rate",
+                "manufacturerData": [{ "key": 17, "data": "AP8BhIU=" }],
+                "knownServiceUuids": ["12345678-1234-5678-9abc-def123456789"],
+            }),
+        );
+        assert_round_trip(
+            SimulateAdvertisementParameters::new(
+                context(),
+                SimulateAdvertisementScanEntryParameters::new(
+                    "09:09:09:09:09:09".to_string(),
+                    -10.0,
+                    ScanRecord::builder().name("Heart Rate".to_string()).build(),
+                ),
+            ),
+            json!({
+                "context": "context",
+                "scanEntry": {
+                    "deviceAddress": "09:09:09:09:09:09",
+                    "rssi": -10.0,
+                    "scanRecord": { "name": "Heart Rate" },
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn test_simulate_gatt() {
+        assert_round_trip(
+            SimulateCharacteristicParameters::builder(
+                context(),
+                "09:09:09:09:09:09".to_string(),
+                "service".to_string(),
+                "characteristic".to_string(),
+                SimulationType::Add,
+            )
+            .characteristic_properties(CharacteristicProperties::builder().read(true).build())
+            .build(),
+            json!({
+                "context": "context",
+                "address": "09:09:09:09:09:09",
+                "serviceUuid": "service",
+                "characteristicUuid": "characteristic",
+                "characteristicProperties": { "read": true },
+                "type": "add",
+            }),
+        );
+        assert_round_trip(
+            SimulateCharacteristicResponseParameters::builder(
+                context(),
+                "09:09:09:09:09:09".to_string(),
+                "service".to_string(),
+                "characteristic".to_string(),
+                CharacteristicResponseType::SubscribeToNotifications,
+                0,
+            )
+            .data(vec![1, 2])
+            .build(),
+            json!({
+                "context": "context",
+                "address": "09:09:09:09:09:09",
+                "serviceUuid": "service",
+                "characteristicUuid": "characteristic",
+                "type": "subscribe-to-notifications",
+                "code": 0,
+                "data": [1, 2],
+            }),
+        );
+    }
+
+    #[test]
+    fn test_events() {
+        let event: RequestDevicePromptUpdated = parse_event(
+            json!({
+                "method": "bluetooth.requestDevicePromptUpdated",
+                "params": {
+                    "context": "context",
+                    "prompt": "prompt",
+                    "devices": [{ "id": "device", "name": null }],
+                },
+            }),
+            EventType::BluetoothRequestDevicePromptUpdated,
+        );
+        assert_eq!(event.params.devices[0].id, "device");
+        assert!(event.params.devices[0].name.is_none());
+
+        let event: GattConnectionAttempted = parse_event(
+            json!({
+                "method": "bluetooth.gattConnectionAttempted",
+                "params": { "context": "context", "address": "09:09:09:09:09:09" },
+            }),
+            EventType::BluetoothGattConnectionAttempted,
+        );
+        assert_eq!(event.params.address, "09:09:09:09:09:09");
+
+        let event: CharacteristicEventGenerated = parse_event(
+            json!({
+                "method": "bluetooth.characteristicEventGenerated",
+                "params": {
+                    "context": "context",
+                    "address": "09:09:09:09:09:09",
+                    "serviceUuid": "service",
+                    "characteristicUuid": "characteristic",
+                    "type": "write-with-response",
+                    "data": [1],
+                },
+            }),
+            EventType::BluetoothCharacteristicEventGenerated,
+        );
+        assert!(matches!(
+            event.params.event_type,
+            CharacteristicEventType::WriteWithResponse
+        ));
+        assert_eq!(event.params.data, Some(vec![1]));
+
+        let event: DescriptorEventGenerated = parse_event(
+            json!({
+                "method": "bluetooth.descriptorEventGenerated",
+                "params": {
+                    "context": "context",
+                    "address": "09:09:09:09:09:09",
+                    "serviceUuid": "service",
+                    "characteristicUuid": "characteristic",
+                    "descriptorUuid": "descriptor",
+                    "type": "future-type",
+                },
+            }),
+            EventType::BluetoothDescriptorEventGenerated,
+        );
+        assert!(matches!(
+            event.params.event_type,
+            DescriptorEventType::Unknown(ref other) if other == "future-type"
+        ));
+    }
+}
+
+// --------------------------------------------------
+
+mod web_authn {
+    use super::*;
+    use webdriverbidi::local::web_authn::{AddVirtualAuthenticatorResult, GetCredentialsResult};
+    use webdriverbidi::remote::web_authn::{
+        AddCredentialParameters, AddVirtualAuthenticatorParameters, AuthenticatorConfiguration,
+        AuthenticatorProtocol, AuthenticatorTransport, Credential, GetCredentialsParameters,
+        RemoveAllCredentialsParameters, RemoveCredentialParameters,
+        RemoveVirtualAuthenticatorParameters, SetCredentialPropertiesParameters,
+        SetUserVerifiedParameters,
+    };
+
+    fn credential() -> Credential {
+        Credential::builder(
+            "cred".to_string(),
+            true,
+            "example.com".to_string(),
+            "key".to_string(),
+            0,
+        )
+        .user_handle("user".to_string())
+        .build()
+    }
+
+    #[test]
+    fn test_virtual_authenticator() {
+        assert_eq!(
+            AddVirtualAuthenticatorParameters::METHOD,
+            "webAuthn.addVirtualAuthenticator"
+        );
+        assert_round_trip(
+            AddVirtualAuthenticatorParameters::new(
+                context(),
+                AuthenticatorConfiguration::builder(
+                    AuthenticatorProtocol::Ctap21,
+                    AuthenticatorTransport::SmartCard,
+                )
+                .has_resident_key(true)
+                .extensions(vec!["largeBlob".to_string()])
+                .build(),
+            ),
+            json!({
+                "context": "context",
+                "protocol": "ctap2_1",
+                "transport": "smart-card",
+                "hasResidentKey": true,
+                "extensions": ["largeBlob"],
+            }),
+        );
+        assert_round_trip(
+            RemoveVirtualAuthenticatorParameters::new(context(), "auth".to_string()),
+            json!({ "context": "context", "authenticatorId": "auth" }),
+        );
+        assert_round_trip(
+            SetUserVerifiedParameters::new(context(), "auth".to_string(), true),
+            json!({ "context": "context", "authenticatorId": "auth", "isUserVerified": true }),
+        );
+
+        let result: AddVirtualAuthenticatorResult =
+            serde_json::from_value(json!({ "authenticatorId": "auth" })).unwrap();
+        assert_eq!(result.authenticator_id, "auth");
+    }
+
+    #[test]
+    fn test_credentials() {
+        assert_round_trip(
+            AddCredentialParameters::new(context(), "auth".to_string(), credential()),
+            json!({
+                "context": "context",
+                "authenticatorId": "auth",
+                "credentialId": "cred",
+                "isResidentCredential": true,
+                "rpId": "example.com",
+                "privateKey": "key",
+                "userHandle": "user",
+                "signCount": 0,
+            }),
+        );
+        assert_round_trip(
+            GetCredentialsParameters::new(context(), "auth".to_string()),
+            json!({ "context": "context", "authenticatorId": "auth" }),
+        );
+        assert_round_trip(
+            RemoveCredentialParameters::new(context(), "auth".to_string(), "cred".to_string()),
+            json!({ "context": "context", "authenticatorId": "auth", "credentialId": "cred" }),
+        );
+        assert_round_trip(
+            RemoveAllCredentialsParameters::new(context(), "auth".to_string()),
+            json!({ "context": "context", "authenticatorId": "auth" }),
+        );
+        assert_round_trip(
+            SetCredentialPropertiesParameters::builder(
+                context(),
+                "auth".to_string(),
+                "cred".to_string(),
+            )
+            .backup_state(false)
+            .build(),
+            json!({
+                "context": "context",
+                "authenticatorId": "auth",
+                "credentialId": "cred",
+                "backupState": false,
+            }),
+        );
+
+        // The credentials in a result can be added back as they are.
+        let result: GetCredentialsResult = serde_json::from_value(json!({
+            "credentials": [serde_json::to_value(credential()).unwrap()],
+        }))
+        .unwrap();
+        let credential = result.credentials.into_iter().next().unwrap();
+        assert_eq!(credential.user_handle.as_deref(), Some("user"));
+        AddCredentialParameters::new(context(), "auth".to_string(), credential);
+    }
+}
+
+// --------------------------------------------------
+
+mod browser {
+    use super::*;
+    use webdriverbidi::local::session::Capabilities;
+    use webdriverbidi::remote::browser::{
+        ClientWindowNamedOrRectState, ClientWindowNamedState, ClientWindowRectState,
+        ClientWindowState, CreateUserContextParameters, SetClientWindowStateParameters,
+    };
+
+    #[test]
+    fn test_set_client_window_state() {
+        assert_round_trip(
+            SetClientWindowStateParameters::new(
+                "window".to_string(),
+                ClientWindowNamedOrRectState::ClientWindowRectState(
+                    ClientWindowRectState::builder().width(800).x(0).build(),
+                ),
+            ),
+            json!({ "clientWindow": "window", "state": "normal", "width": 800, "x": 0 }),
+        );
+        assert_round_trip(
+            SetClientWindowStateParameters::new(
+                "window".to_string(),
+                ClientWindowNamedOrRectState::ClientWindowNamedState(ClientWindowNamedState::new(
+                    ClientWindowState::Maximized,
+                )),
+            ),
+            json!({ "clientWindow": "window", "state": "maximized" }),
+        );
+    }
+
+    #[test]
+    fn test_create_user_context_from_capabilities() {
+        assert_eq!(
+            CreateUserContextParameters::METHOD,
+            "browser.createUserContext"
+        );
+        let capabilities: Capabilities = serde_json::from_value(json!({
+            "acceptInsecureCerts": true,
+            "browserName": "firefox",
+            "browserVersion": "140.0",
+            "platformName": "linux",
+            "setWindowRect": true,
+            "userAgent": "Mozilla/5.0",
+            "proxy": { "proxyType": "manual", "httpProxy": "127.0.0.1:8080" },
+            "unhandledPromptBehavior": { "alert": "dismiss", "file": "future-behavior" },
+        }))
+        .unwrap();
+        assert_round_trip(
+            CreateUserContextParameters::from(capabilities),
+            json!({
+                "acceptInsecureCerts": true,
+                "proxy": { "proxyType": "manual", "httpProxy": "127.0.0.1:8080" },
+                "unhandledPromptBehavior": { "alert": "dismiss", "file": "future-behavior" },
+            }),
+        );
+    }
+}
+
+// --------------------------------------------------
+
+mod input {
+    use super::*;
+    use webdriverbidi::local::event::EventData;
+    use webdriverbidi::local::input::{FileDialogOpened, InputEvent};
+    use webdriverbidi::remote::input::SetFilesParameters;
+    use webdriverbidi::remote::session::{UserPromptHandler, UserPromptHandlerType};
+
+    fn file_dialog_opened(element: Value) -> Value {
+        json!({
+            "type": "event",
+            "method": "input.fileDialogOpened",
+            "params": { "context": "context", "element": element, "multiple": true },
+        })
+    }
+
+    #[test]
+    fn test_file_dialog_opened() {
+        let event = file_dialog_opened(json!({ "sharedId": "input", "handle": "handle" }));
+        let data: EventData = serde_json::from_value(event.clone()).unwrap();
+        assert!(matches!(
+            data,
+            EventData::InputEvent(InputEvent::FileDialogOpened(_))
+        ));
+
+        let event: FileDialogOpened = parse_event(event, EventType::InputFileDialogOpened);
+        assert_eq!(event.params.context, context());
+        assert!(event.params.multiple);
+
+        assert_eq!(SetFilesParameters::METHOD, "input.setFiles");
+        assert_round_trip(
+            SetFilesParameters::from_file_dialog_info(event.params, vec!["/tmp/a.txt".into()])
+                .unwrap(),
+            json!({
+                "context": "context",
+                "element": { "sharedId": "input", "handle": "handle" },
+                "files": ["/tmp/a.txt"],
+            }),
+        );
+    }
+
+    #[test]
+    fn test_file_dialog_opened_without_element() {
+        let mut event = file_dialog_opened(Value::Null);
+        event["params"].as_object_mut().unwrap().remove("element");
+        let event: FileDialogOpened = parse_event(event, EventType::InputFileDialogOpened);
+        assert!(event.params.element.is_none());
+        assert!(SetFilesParameters::from_file_dialog_info(event.params, vec![]).is_none());
+    }
+
+    #[test]
+    fn test_file_prompt_handler() {
+        assert_round_trip(
+            UserPromptHandler::builder()
+                .default(UserPromptHandlerType::Accept)
+                .file(UserPromptHandlerType::Dismiss)
+                .build(),
+            json!({ "default": "accept", "file": "dismiss" }),
+        );
+        assert_round_trip(
+            UserPromptHandler::new(
+                Some(UserPromptHandlerType::Ignore),
+                None,
+                None,
+                None,
+                Some(UserPromptHandlerType::Accept),
+                None,
+            ),
+            json!({ "alert": "ignore", "file": "accept" }),
+        );
+    }
+}
+
+// --------------------------------------------------
+
+mod empty_params {
+    use super::*;
+    use webdriverbidi::remote::browser::{
+        CloseParameters, GetClientWindowsParameters, GetUserContextsParameters,
+    };
+    use webdriverbidi::remote::session::{EndParameters, StatusParameters};
+
+    #[test]
+    fn test_commands_without_parameters() {
+        assert_eq!(CloseParameters::METHOD, "browser.close");
+        assert_eq!(
+            GetClientWindowsParameters::METHOD,
+            "browser.getClientWindows"
+        );
+        assert_eq!(GetUserContextsParameters::METHOD, "browser.getUserContexts");
+        assert_eq!(StatusParameters::METHOD, "session.status");
+        assert_eq!(EndParameters::METHOD, "session.end");
+
+        assert_round_trip(CloseParameters::new(), json!({}));
+        assert_round_trip(GetClientWindowsParameters::new(), json!({}));
+        assert_round_trip(GetUserContextsParameters::new(), json!({}));
+        assert_round_trip(StatusParameters::new(), json!({}));
+        assert_round_trip(EndParameters::new(), json!({}));
+
+        let extensible = [("goog:flag".to_string(), json!(true))]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            serde_json::to_value(StatusParameters::new().with_extensions(extensible)).unwrap(),
+            json!({ "goog:flag": true })
+        );
+    }
+}
+
+// --------------------------------------------------
+
+mod forward_compatibility {
+    use super::*;
+    use webdriverbidi::local::command_response::CommandResponse;
+    use webdriverbidi::local::log::{Entry, EntryAdded, Level};
+    use webdriverbidi::local::result_data::EmptyResult;
+    use webdriverbidi::local::script::{RealmCreated, RealmInfo, RemoteValue};
+    use webdriverbidi::local::session::{Capabilities, ProxyConfiguration};
+    use webdriverbidi::remote::browser::CreateUserContextParameters;
+
+    #[test]
+    fn test_unknown_enum_value_in_event() {
+        let event: EntryAdded = parse_event(
+            json!({
+                "method": "log.entryAdded",
+                "params": {
+                    "type": "console",
+                    "level": "trace",
+                    "source": { "realm": "realm" },
+                    "text": "hello",
+                    "timestamp": 0,
+                    "method": "trace",
+                    "args": [{ "type": "temporal", "value": "P1D" }],
+                },
+            }),
+            EventType::LogEntryAdded,
+        );
+        let Entry::ConsoleLogEntry(entry) = event.params else {
+            panic!("expected a console entry, got {:?}", event.params);
+        };
+        assert!(matches!(entry.base.level, Level::Unknown(ref other) if other == "trace"));
+        assert!(matches!(
+            &entry.args[0],
+            RemoteValue::Unknown { value_type, .. } if value_type == "temporal"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_fields_are_kept() {
+        let response: CommandResponse = serde_json::from_value(json!({
+            "type": "success",
+            "id": 1,
+            "result": {},
+            "moz:extra": "value",
+        }))
+        .unwrap();
+        assert_eq!(response.extensible["moz:extra"], "value");
+        assert_eq!(
+            serde_json::to_value(&response).unwrap()["moz:extra"],
+            "value"
+        );
+
+        let result: EmptyResult = serde_json::from_value(json!({ "goog:detail": 1 })).unwrap();
+        assert_eq!(result.extensible["goog:detail"], 1);
+
+        let capabilities: Capabilities = serde_json::from_value(json!({
+            "acceptInsecureCerts": false,
+            "browserName": "chrome",
+            "browserVersion": "140.0",
+            "platformName": "linux",
+            "setWindowRect": true,
+            "userAgent": "Mozilla/5.0",
+            "goog:chromeOptions": { "debuggerAddress": "localhost:9222" },
+        }))
+        .unwrap();
+        assert_eq!(
+            capabilities.extensible["goog:chromeOptions"]["debuggerAddress"],
+            "localhost:9222"
+        );
+    }
+
+    #[test]
+    fn test_unknown_realm_type() {
+        let event: RealmCreated = parse_event(
+            json!({
+                "method": "script.realmCreated",
+                "params": { "realm": "realm", "origin": "null", "type": "shadow-realm" },
+            }),
+            EventType::ScriptRealmCreated,
+        );
+        assert!(matches!(
+            &event.params,
+            RealmInfo::Unknown { realm_type, .. } if realm_type == "shadow-realm"
+        ));
+        assert_eq!(
+            serde_json::to_value(&event.params).unwrap(),
+            json!({ "realm": "realm", "origin": "null", "type": "shadow-realm" })
+        );
+
+        let event: RealmCreated = parse_event(
+            json!({
+                "method": "script.realmCreated",
+                "params": { "realm": "realm", "origin": "null", "type": "worker" },
+            }),
+            EventType::ScriptRealmCreated,
+        );
+        assert!(matches!(event.params, RealmInfo::WorkerRealmInfo(_)));
+    }
+
+    #[test]
+    fn test_unknown_proxy_type() {
+        let capabilities: Capabilities = serde_json::from_value(json!({
+            "acceptInsecureCerts": true,
+            "browserName": "firefox",
+            "browserVersion": "140.0",
+            "platformName": "linux",
+            "setWindowRect": true,
+            "userAgent": "Mozilla/5.0",
+            "proxy": { "proxyType": "tunnel", "tunnelUrl": "https://example.com/" },
+        }))
+        .unwrap();
+        assert!(matches!(
+            &capabilities.proxy,
+            Some(ProxyConfiguration::Unknown { proxy_type, .. }) if proxy_type == "tunnel"
+        ));
+        assert_eq!(
+            serde_json::to_value(&capabilities).unwrap()["proxy"],
+            json!({ "proxyType": "tunnel", "tunnelUrl": "https://example.com/" })
+        );
+
+        let parameters = CreateUserContextParameters::from(capabilities);
+        assert!(parameters.proxy.is_none());
+    }
+}
+
+// --------------------------------------------------
+
+mod wire_names {
+    use super::*;
+    use webdriverbidi::local::network::BeforeRequestSent;
+    use webdriverbidi::local::session::NewResult;
+    use webdriverbidi::remote::browsing_context::HandleUserPromptParameters;
+    use webdriverbidi::remote::session::{
+        ManualProxyConfiguration, ProxyConfiguration, SocksProxyConfiguration,
+    };
+
+    #[test]
+    fn test_before_request_sent() {
+        let timings: serde_json::Map<String, Value> = [
+            "timeOrigin",
+            "requestTime",
+            "redirectStart",
+            "redirectEnd",
+            "fetchStart",
+            "dnsStart",
+            "dnsEnd",
+            "connectStart",
+            "connectEnd",
+            "tlsStart",
+            "requestStart",
+            "responseStart",
+            "responseEnd",
+        ]
+        .into_iter()
+        .map(|name| (name.to_string(), json!(0.0)))
+        .collect();
+        let event: BeforeRequestSent = parse_event(
+            json!({
+                "type": "event",
+                "method": "network.beforeRequestSent",
+                "params": {
+                    "context": "context",
+                    "isBlocked": true,
+                    "navigation": null,
+                    "redirectCount": 2,
+                    "request": {
+                        "request": "request",
+                        "url": "https://example.com/",
+                        "method": "GET",
+                        "headers": [],
+                        "cookies": [],
+                        "headersSize": 0,
+                        "bodySize": null,
+                        "destination": "",
+                        "initiatorType": null,
+                        "timings": timings,
+                    },
+                    "timestamp": 0,
+                    "intercepts": ["intercept"],
+                },
+            }),
+            EventType::NetworkBeforeRequestSent,
+        );
+        assert!(event.params.base.is_blocked);
+        assert_eq!(event.params.base.redirect_count, 2);
+    }
+
+    #[test]
+    fn test_new_result() {
+        let result: NewResult = serde_json::from_value(json!({
+            "sessionId": "session",
+            "capabilities": {
+                "acceptInsecureCerts": false,
+                "browserName": "firefox",
+                "browserVersion": "140.0",
+                "platformName": "linux",
+                "setWindowRect": true,
+                "userAgent": "Mozilla/5.0",
+            },
+        }))
+        .unwrap();
+        assert_eq!(result.session_id, "session");
+    }
+
+    #[test]
+    fn test_handle_user_prompt() {
+        assert_round_trip(
+            HandleUserPromptParameters::new(context(), Some(true), Some("text".to_string())),
+            json!({ "context": "context", "accept": true, "userText": "text" }),
+        );
+    }
+
+    #[test]
+    fn test_socks_proxy() {
+        assert_round_trip(
+            ProxyConfiguration::ManualProxyConfiguration(
+                ManualProxyConfiguration::builder()
+                    .socks_proxy_configuration(SocksProxyConfiguration::new(
+                        "127.0.0.1:1080".to_string(),
+                        5,
+                    ))
+                    .build(),
+            ),
+            json!({ "proxyType": "manual", "socksProxy": "127.0.0.1:1080", "socksVersion": 5 }),
+        );
+    }
+}
+
+// --------------------------------------------------
+
+mod storage {
+    use super::*;
+    use webdriverbidi::ids::UserContext;
+    use webdriverbidi::local::storage::PartitionKey;
+    use webdriverbidi::remote::storage::StorageKeyPartitionDescriptor;
+
+    #[test]
+    fn test_partition_user_context() {
+        let user_context = UserContext::new("user-context");
+        assert_round_trip(
+            StorageKeyPartitionDescriptor::builder()
+                .user_context(user_context.clone())
+                .build(),
+            json!({ "userContext": "user-context" }),
+        );
+
+        let key: PartitionKey =
+            serde_json::from_value(json!({ "userContext": "user-context" })).unwrap();
+        assert_eq!(key.user_context, Some(user_context));
+    }
+}
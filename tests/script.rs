@@ -0,0 +1,393 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+use webdriverbidi::error::ScriptError;
+use webdriverbidi::ids::BrowsingContext;
+use webdriverbidi::local::script::{
+    EvaluateResult, NumberOrSpecialNumber, PrimitiveProtocolValue, RemoteValue,
+};
+use webdriverbidi::remote::browsing_context::GetTreeParameters;
+use webdriverbidi::remote::script::{
+    CallFunctionParameters, ContextTarget, EvaluateParameters, LocalValue, ResultOwnership, Target,
+};
+
+// Only some of the shared helpers are used by this test crate.
+#[allow(dead_code)]
+mod utils;
+
+mod value_round_trip {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_evaluate_result_as_call_function_argument() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+
+        let context = bidi_session
+            .browsing_context_get_tree(GetTreeParameters::new(None, None))
+            .await?
+            .contexts
+            .remove(0)
+            .context;
+        let target = || Target::ContextTarget(ContextTarget::new(context.clone(), None));
+
+        let evaluate_result = bidi_session
+            .script_evaluate(EvaluateParameters::new(
+                "({ answer: 42 })".to_string(),
+                target(),
+                false,
+                Some(ResultOwnership::Root),
+                None,
+                None,
+            ))
+            .await?;
+        let EvaluateResult::EvaluateResultSuccess(success) = evaluate_result else {
+            anyhow::bail!("Evaluation failed: {:?}", evaluate_result);
+        };
+        let argument = LocalValue::try_from(success.result)
+            .map_err(|value| anyhow::anyhow!("Unconvertible value: {:?}", value))?;
+
+        let call_result = bidi_session
+            .script_call_function(CallFunctionParameters::new(
+                "(obj) => obj.answer".to_string(),
+                false,
+                target(),
+                Some(vec![argument]),
+                None,
+                None,
+                None,
+                None,
+            ))
+            .await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        let EvaluateResult::EvaluateResultSuccess(success) = call_result else {
+            anyhow::bail!("Call failed: {:?}", call_result);
+        };
+        let RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::NumberValue(number)) =
+            success.result
+        else {
+            anyhow::bail!("Unexpected result: {:?}", success.result);
+        };
+        assert!(matches!(number.value, NumberOrSpecialNumber::Number(n) if n == 42.0));
+
+        Ok(())
+    }
+}
+
+mod typed {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Location {
+        protocol: String,
+        href: String,
+    }
+
+    pub(super) async fn top_level_context(
+        bidi_session: &mut webdriverbidi::session::WebDriverBiDiSession,
+    ) -> Result<BrowsingContext> {
+        let mut tree = bidi_session
+            .browsing_context_get_tree(GetTreeParameters::new(None, None))
+            .await?;
+        Ok(tree.contexts.remove(0).context)
+    }
+
+    #[tokio::test]
+    async fn test_eval_and_call_function() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = top_level_context(&mut bidi_session).await?;
+
+        let sum: i64 = bidi_session.eval(context.clone(), "1 + 2").await?;
+        let location: Location = bidi_session
+            .eval(
+                context.clone(),
+                "({ protocol: location.protocol, href: location.href })",
+            )
+            .await?;
+        let awaited: Vec<String> = bidi_session
+            .eval(context.clone(), "Promise.resolve(['a', 'b'])")
+            .await?;
+        let product: f64 = bidi_session
+            .call_function(
+                context.clone(),
+                "(a, b) => a * b",
+                vec![LocalValue::from(1.5), LocalValue::from(4)],
+            )
+            .await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert_eq!(sum, 3);
+        assert_eq!(
+            location,
+            Location {
+                protocol: "about:".to_string(),
+                href: "about:blank".to_string()
+            }
+        );
+        assert_eq!(awaited, vec!["a", "b"]);
+        assert_eq!(product, 6.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exception_is_a_script_error() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = top_level_context(&mut bidi_session).await?;
+
+        let result = bidi_session
+            .eval::<()>(
+                context,
+                "function boom() { throw new Error('boom'); }\nboom()",
+            )
+            .await;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        let err = result.unwrap_err();
+        assert!(matches!(err, ScriptError::Exception(_)));
+        assert!(err.text().unwrap().contains("boom"));
+        assert!(err.stack_trace().is_some());
+        assert!(err
+            .to_string()
+            .contains("Script threw an exception at line"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bytes_round_trip() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = top_level_context(&mut bidi_session).await?;
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        let array = bidi_session.create_bytes(context.clone(), &bytes).await?;
+        let sum: u32 = bidi_session
+            .call_function(
+                context.clone(),
+                "(array) => array.reduce((a, b) => a + b, 0)",
+                vec![array.to_local_value()],
+            )
+            .await?;
+        let copied = bidi_session
+            .read_bytes(context.clone(), array.value())
+            .await?;
+        drop(array);
+
+        let evaluate_result = bidi_session
+            .script_evaluate(EvaluateParameters::new(
+                "new Uint16Array([1, 256]).buffer".to_string(),
+                Target::ContextTarget(ContextTarget::new(context.clone(), None)),
+                false,
+                Some(ResultOwnership::Root),
+                None,
+                None,
+            ))
+            .await?;
+        let EvaluateResult::EvaluateResultSuccess(success) = evaluate_result else {
+            anyhow::bail!("Evaluation failed: {:?}", evaluate_result);
+        };
+        let read = bidi_session.read_bytes(context, &success.result).await?;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert_eq!(sum, (0..=255).sum::<u32>());
+        assert_eq!(copied, bytes);
+        assert_eq!(read, vec![1, 0, 0, 1]);
+
+        Ok(())
+    }
+}
+
+mod js_handle {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_helpers() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = typed::top_level_context(&mut bidi_session).await?;
+
+        let counter = bidi_session
+            .eval_handle(
+                context.clone(),
+                "({ count: 1, add(n) { this.count += n; return this.count; } })",
+            )
+            .await?;
+        let count: u32 = counter.get_property("count").await?;
+        let added: u32 = counter
+            .call_method("add", vec![LocalValue::from(2)])
+            .await?;
+        let json = counter.json_value().await?;
+
+        let reference = counter.to_local_value();
+        drop(counter);
+        bidi_session.flush_disowns().await?;
+        let disowned = bidi_session
+            .call_function::<u32>(context, "(obj) => obj.count", vec![reference])
+            .await;
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert_eq!(count, 1);
+        assert_eq!(added, 3);
+        assert_eq!(json["count"], 3);
+        assert!(disowned.is_err());
+
+        Ok(())
+    }
+}
+
+mod inspector {
+    use super::*;
+    use webdriverbidi::inspector::Inspector;
+
+    #[tokio::test]
+    async fn test_children_are_fetched_on_demand() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = typed::top_level_context(&mut bidi_session).await?;
+
+        let handle = bidi_session
+            .eval_handle(
+                context,
+                "const a = { deep: { deeper: { value: 1 } } }; a.deep.back = a; a",
+            )
+            .await?;
+        let mut inspector = Inspector::new(handle)?.max_depth(1);
+        let root = inspector.root();
+
+        let deep = inspector.children(root).await?[0];
+        let deep_children = inspector.children(deep).await?;
+        let deeper = deep_children[0];
+        let back = deep_children[1];
+        let value = inspector.children(deeper).await?[0];
+        let rendered = inspector.to_string();
+
+        utils::session::close(&mut bidi_session).await?;
+
+        assert_eq!(inspector.path(value), vec![0, 0, 0]);
+        assert_eq!(inspector.node(value).summary(), "1");
+        assert!(rendered.contains("value: 1"));
+        assert_eq!(inspector.node(back).reference(), Some(root));
+        assert_eq!(inspector.resolve(back), root);
+        assert!(rendered.contains("back: [Circular Object]"));
+
+        Ok(())
+    }
+}
+
+mod preload_channel {
+    use super::*;
+    use futures::StreamExt;
+    use std::time::Duration;
+    use webdriverbidi::remote::script::RemovePreloadScriptParameters;
+
+    #[derive(Debug, Deserialize)]
+    struct Loaded {
+        href: String,
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_streamed_until_removal() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = typed::top_level_context(&mut bidi_session).await?;
+        let (url, server_handle) = utils::axum_utils::serve_static("default.html").await?;
+
+        let mut messages = bidi_session
+            .add_preload_script_with_channel::<Loaded>("(send) => send({ href: location.href })")
+            .await?;
+        utils::browsing_context::navigate(&mut bidi_session, context, url.clone()).await?;
+        let loaded = tokio::time::timeout(Duration::from_secs(5), messages.next()).await?;
+
+        bidi_session
+            .script_remove_preload_script(RemovePreloadScriptParameters::new(
+                messages.preload_script().clone(),
+            ))
+            .await?;
+        let after_removal = tokio::time::timeout(Duration::from_secs(5), messages.next()).await?;
+
+        utils::session::close(&mut bidi_session).await?;
+        server_handle.abort();
+
+        assert_eq!(loaded.map(|loaded| loaded.href), Some(url));
+        assert!(after_removal.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_channels_outlive_dropped_streams() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = typed::top_level_context(&mut bidi_session).await?;
+        let (url, server_handle) = utils::axum_utils::serve_static("default.html").await?;
+
+        let dropped = bidi_session
+            .add_preload_script_with_channel::<Loaded>("(send) => send({ href: location.href })")
+            .await?;
+        let mut kept = bidi_session
+            .add_preload_script_with_channel::<Loaded>("(send) => send({ href: location.href })")
+            .await?;
+        drop(dropped);
+        utils::browsing_context::navigate(&mut bidi_session, context, url.clone()).await?;
+        let loaded = tokio::time::timeout(Duration::from_secs(5), kept.next()).await?;
+
+        utils::session::close(&mut bidi_session).await?;
+        server_handle.abort();
+
+        assert_eq!(loaded.map(|loaded| loaded.href), Some(url));
+
+        Ok(())
+    }
+}
+
+mod expose_function {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_page_calls_rust() -> Result<()> {
+        let mut bidi_session = utils::session::init().await?;
+        let context = typed::top_level_context(&mut bidi_session).await?;
+        let (url, server_handle) = utils::axum_utils::serve_static("default.html").await?;
+
+        bidi_session
+            .expose_function("rustAdd", |(a, b): (i64, i64)| async move {
+                if a < 0 {
+                    return Err(format!("{} is negative", a));
+                }
+                Ok(a + b)
+            })
+            .await?;
+        bidi_session
+            .expose_function("rustGreet", |(name,): (String,)| async move {
+                Ok::<_, String>(format!("Hello, {}!", name))
+            })
+            .await?;
+
+        // Defined in the document that was already loaded...
+        let before: i64 = bidi_session.eval(context.clone(), "rustAdd(1, 2)").await?;
+        // ...and in the ones loaded afterwards.
+        utils::browsing_context::navigate(&mut bidi_session, context.clone(), url).await?;
+        let after: i64 = bidi_session
+            .eval(context.clone(), "window.rustAdd(20, 22)")
+            .await?;
+        let rejected = bidi_session
+            .eval::<i64>(context.clone(), "rustAdd(-1, 0)")
+            .await;
+        let greeting: String = bidi_session.eval(context, "rustGreet('page')").await?;
+
+        utils::session::close(&mut bidi_session).await?;
+        server_handle.abort();
+
+        assert_eq!(before, 3);
+        assert_eq!(after, 42);
+        assert_eq!(greeting, "Hello, page!");
+        assert!(rejected
+            .unwrap_err()
+            .text()
+            .unwrap()
+            .contains("-1 is negative"));
+
+        Ok(())
+    }
+}
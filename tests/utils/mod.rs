@@ -17,12 +17,12 @@ use tokio::task::JoinHandle;
 use tower_http::services::ServeDir;
 // --------------------------------------------------
 
+use webdriverbidi::ids::{BrowsingContext, UserContext};
 use webdriverbidi::local::browser::ClientWindowInfo;
-use webdriverbidi::local::script::{
-    EvaluateResult,
-    RemoteValue, // NumberOrSpecialNumber, NumberValue
+use webdriverbidi::remote::browser::{
+    CreateUserContextParameters, GetClientWindowsParameters, GetUserContextsParameters,
+    RemoveUserContextParameters,
 };
-use webdriverbidi::remote::browser::RemoveUserContextParameters;
 use webdriverbidi::remote::browsing_context::{
     CreateParameters,
     CreateType,
@@ -32,10 +32,7 @@ use webdriverbidi::remote::browsing_context::{
     ReadinessState,
     // TraverseHistoryParameters,
 };
-use webdriverbidi::remote::script::{
-    CallFunctionParameters, ContextTarget, LocalValue, PrimitiveProtocolValue, StringValue, Target,
-};
-use webdriverbidi::remote::EmptyParams;
+use webdriverbidi::remote::script::{CallFunctionParameters, ContextTarget, LocalValue, Target};
 use webdriverbidi::session::WebDriverBiDiSession;
 use webdriverbidi::webdriver::capabilities::CapabilitiesRequest;
 
@@ -76,9 +73,9 @@ pub mod browser {
     /// Return the Ids of the current user contexts.
     pub async fn get_user_context_ids(
         bidi_session: &mut WebDriverBiDiSession,
-    ) -> Result<Vec<String>> {
+    ) -> Result<Vec<UserContext>> {
         let user_contexts = bidi_session
-            .browser_get_user_contexts(EmptyParams::new())
+            .browser_get_user_contexts(GetUserContextsParameters::new())
             .await?
             .user_contexts;
 
@@ -91,9 +88,11 @@ pub mod browser {
     }
 
     /// Create a user context.
-    pub async fn create_user_context(bidi_session: &mut WebDriverBiDiSession) -> Result<String> {
+    pub async fn create_user_context(
+        bidi_session: &mut WebDriverBiDiSession,
+    ) -> Result<UserContext> {
         let user_context = bidi_session
-            .browser_create_user_context(EmptyParams::new())
+            .browser_create_user_context(CreateUserContextParameters::new(None, None, None))
             .await?
             .user_context;
 
@@ -105,7 +104,7 @@ pub mod browser {
         bidi_session: &mut WebDriverBiDiSession,
     ) -> Result<Vec<ClientWindowInfo>> {
         let client_windows = bidi_session
-            .browser_get_client_windows(EmptyParams::new())
+            .browser_get_client_windows(GetClientWindowsParameters::new())
             .await?
             .client_windows;
 
@@ -115,7 +114,7 @@ pub mod browser {
     /// Remove a user context.
     pub async fn remove_user_context(
         bidi_session: &mut WebDriverBiDiSession,
-        user_context: String,
+        user_context: UserContext,
     ) -> Result<()> {
         bidi_session
             .browser_remove_user_context(RemoveUserContextParameters::new(user_context.clone()))
@@ -131,8 +130,8 @@ pub mod browsing_context {
     /// Open a new tab in the specified user context.
     pub async fn new_tab_in_user_context(
         session: &mut WebDriverBiDiSession,
-        user_context: String,
-    ) -> Result<String> {
+        user_context: UserContext,
+    ) -> Result<BrowsingContext> {
         let create_params = CreateParameters::new(CreateType::Tab, None, None, Some(user_context));
         let context = session
             .browsing_context_create(create_params)
@@ -145,7 +144,7 @@ pub mod browsing_context {
     /// Navigate to the specified URL and wait for the document to completely load.
     pub async fn navigate(
         session: &mut WebDriverBiDiSession,
-        context: String,
+        context: BrowsingContext,
         url: String,
     ) -> Result<()> {
         let navigate_params = NavigateParameters::new(context, url, Some(ReadinessState::Complete));
@@ -155,7 +154,7 @@ pub mod browsing_context {
     }
 
     /// Open a new window.
-    pub async fn new_window(session: &mut WebDriverBiDiSession) -> Result<String> {
+    pub async fn new_window(session: &mut WebDriverBiDiSession) -> Result<BrowsingContext> {
         let create_params = CreateParameters::new(CreateType::Window, None, None, None);
         let context = session
             .browsing_context_create(create_params)
@@ -169,13 +168,11 @@ pub mod local_storage {
     use super::*;
 
     fn local_value(str: &str) -> LocalValue {
-        LocalValue::PrimitiveProtocolValue(PrimitiveProtocolValue::StringValue(StringValue::new(
-            str.to_string(),
-        )))
+        LocalValue::from(str)
     }
 
     fn target_context(context: &str) -> Target {
-        Target::ContextTarget(ContextTarget::new(context.to_string(), None))
+        Target::ContextTarget(ContextTarget::new(context.into(), None))
     }
 
     /// Return the value identified by the key from the context's localStorage.
@@ -184,35 +181,15 @@ pub mod local_storage {
         context: &str,
         key: &str,
     ) -> Result<Option<String>> {
-        let function_declaration = "(key) => localStorage.getItem(key)".to_string();
-        let key_local_value = local_value(key);
-        let args = Some(vec![key_local_value]);
-        let params = CallFunctionParameters::new(
-            function_declaration,
-            false,
-            target_context(context),
-            args,
-            None,
-            None,
-            None,
-            None,
-        );
-        let eval_result = bidi_session.script_call_function(params).await?;
-
-        match eval_result {
-            EvaluateResult::EvaluateResultSuccess(eval_rslt_success) => {
-                let remote_value = eval_rslt_success.result;
-                match remote_value {
-                    RemoteValue::PrimitiveProtocolValue(
-                        webdriverbidi::local::script::PrimitiveProtocolValue::StringValue(
-                            string_value,
-                        ),
-                    ) => Ok(Some(string_value.value)),
-                    _ => Ok(None),
-                }
-            }
-            _ => Ok(None),
-        }
+        let value = bidi_session
+            .call_function(
+                context,
+                "(key) => localStorage.getItem(key)",
+                vec![local_value(key)],
+            )
+            .await?;
+
+        Ok(value)
     }
 
     /// Sets the value for the key in the context's localStorage.
@@ -225,17 +202,10 @@ pub mod local_storage {
         let function_declaration = "(key, value) => localStorage.setItem(key, value)".to_string();
         let key_local_value = local_value(key);
         let value_local_value = local_value(value);
-        let args = Some(vec![key_local_value, value_local_value]);
-        let params = CallFunctionParameters::new(
-            function_declaration,
-            false,
-            target_context(context),
-            args,
-            None,
-            None,
-            None,
-            None,
-        );
+        let params =
+            CallFunctionParameters::builder(function_declaration, false, target_context(context))
+                .arguments(vec![key_local_value, value_local_value])
+                .build();
         bidi_session.script_call_function(params).await?;
 
         Ok(())
@@ -0,0 +1,564 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+
+use webdriverbidi::cddl::Schema;
+use webdriverbidi::local::script::{PrimitiveProtocolValue, RemoteValue};
+use webdriverbidi::remote::script::LocalValue;
+
+// --------------------------------------------------
+
+fn remote_value(json: Value) -> RemoteValue {
+    serde_json::from_value(json).unwrap()
+}
+
+fn number(n: impl Into<Value>) -> Value {
+    json!({ "type": "number", "value": n.into() })
+}
+
+fn string(s: &str) -> Value {
+    json!({ "type": "string", "value": s })
+}
+
+// --------------------------------------------------
+
+mod remote_value {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+        label: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Shape {
+        Empty,
+        Circle(f64),
+    }
+
+    #[test]
+    fn test_protocol_types_are_not_confused() {
+        let value = remote_value(json!({ "type": "bigint", "value": "12" }));
+        assert_eq!(value.to_json(), json!(12));
+
+        let value = remote_value(json!({ "type": "date", "value": "2025-01-01T00:00:00.000Z" }));
+        assert!(matches!(value, RemoteValue::DateRemoteValue(_)));
+
+        let value = remote_value(json!({ "type": "object" }));
+        assert!(matches!(value, RemoteValue::ObjectRemoteValue(_)));
+    }
+
+    #[test]
+    fn test_into_struct() {
+        let value = remote_value(json!({
+            "type": "object",
+            "value": [
+                ["x", number(1)],
+                ["y", number(-2)],
+                ["label", { "type": "undefined" }],
+            ],
+        }));
+        let point: Point = value.into().unwrap();
+        assert_eq!(
+            point,
+            Point {
+                x: 1,
+                y: -2,
+                label: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_into_collections() {
+        let value = remote_value(json!({
+            "type": "map",
+            "value": [
+                [string("a"), { "type": "array", "value": [number(1), number(2)] }],
+                [string("b"), { "type": "set", "value": [] }],
+            ],
+        }));
+        let map: HashMap<String, Vec<u8>> = value.into().unwrap();
+        assert_eq!(map["a"], vec![1, 2]);
+        assert!(map["b"].is_empty());
+
+        let value = remote_value(json!({
+            "type": "map",
+            "value": [[number(1), string("one")]],
+        }));
+        let map: HashMap<u32, String> = value.into().unwrap();
+        assert_eq!(map[&1], "one");
+    }
+
+    #[test]
+    fn test_into_enum() {
+        let shape: Shape = remote_value(string("empty")).into().unwrap();
+        assert_eq!(shape, Shape::Empty);
+
+        let value = remote_value(json!({
+            "type": "object",
+            "value": [["circle", number(1.5)]],
+        }));
+        let shape: Shape = value.into().unwrap();
+        assert_eq!(shape, Shape::Circle(1.5));
+    }
+
+    #[test]
+    fn test_into_numbers() {
+        let n: f64 = remote_value(number("NaN")).into().unwrap();
+        assert!(n.is_nan());
+
+        let n: f64 = remote_value(number("-Infinity")).into().unwrap();
+        assert_eq!(n, f64::NEG_INFINITY);
+
+        let n: f64 = remote_value(number("-0")).into().unwrap();
+        assert!(n == 0.0 && n.is_sign_negative());
+
+        let big = json!({ "type": "bigint", "value": "170141183460469231731687303715884105727" });
+        let n: i128 = remote_value(big.clone()).into().unwrap();
+        assert_eq!(n, i128::MAX);
+        let s: String = remote_value(big).into().unwrap();
+        assert_eq!(s, "170141183460469231731687303715884105727");
+    }
+
+    #[test]
+    fn test_into_type_mismatch() {
+        let err = remote_value(string("text")).into::<i32>().unwrap_err();
+        assert!(err.to_string().contains("invalid type: string \"text\""));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let value = remote_value(json!({
+            "type": "object",
+            "value": [
+                ["nan", number("NaN")],
+                ["zero", number("-0")],
+                ["big", { "type": "bigint", "value": "123456789012345678901234567890" }],
+                ["when", { "type": "date", "value": "2025-01-01T00:00:00.000Z" }],
+                ["re", { "type": "regexp", "value": { "pattern": "a+", "flags": "g" } }],
+                ["fn", { "type": "function", "handle": "h1" }],
+                ["nested", { "type": "map", "value": [[number(1), { "type": "null" }]] }],
+            ],
+        }));
+        assert_eq!(
+            value.to_json(),
+            json!({
+                "nan": null,
+                "zero": -0.0,
+                "big": "123456789012345678901234567890",
+                "when": "2025-01-01T00:00:00.000Z",
+                "re": "/a+/g",
+                "fn": { "type": "function", "handle": "h1" },
+                "nested": { "1": null },
+            })
+        );
+    }
+
+    #[test]
+    fn test_node_keeps_shared_id() {
+        let node = json!({
+            "type": "node",
+            "sharedId": "node-1",
+            "value": { "nodeType": 1, "childNodeCount": 0, "localName": "div" },
+        });
+        assert_eq!(remote_value(node.clone()).to_json(), node);
+
+        #[derive(Deserialize)]
+        struct Node {
+            #[serde(rename = "sharedId")]
+            shared_id: String,
+        }
+        let parsed: Node = remote_value(node).into().unwrap();
+        assert_eq!(parsed.shared_id, "node-1");
+    }
+    #[test]
+    fn test_unknown_type() {
+        let temporal = json!({ "type": "temporal", "handle": "h1", "value": "P1D" });
+        let value = remote_value(json!({ "type": "array", "value": [temporal.clone()] }));
+        let RemoteValue::ArrayRemoteValue(array) = &value else {
+            panic!("expected an array, got {:?}", value);
+        };
+        assert!(matches!(
+            &array.value.as_ref().unwrap()[0],
+            RemoteValue::Unknown { value_type, raw } if value_type == "temporal" && *raw == temporal
+        ));
+        assert_eq!(value.to_json(), json!([temporal]));
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            json!({ "type": "array", "value": [temporal] })
+        );
+
+        // An unknown value cannot be passed back to the remote end.
+        assert!(LocalValue::try_from(remote_value(temporal)).is_err());
+    }
+
+    #[test]
+    fn test_unknown_primitive_type() {
+        let raw = json!({ "type": "decimal", "value": "0.1" });
+        let value: PrimitiveProtocolValue = serde_json::from_value(raw.clone()).unwrap();
+        assert!(matches!(
+            &value,
+            PrimitiveProtocolValue::Unknown { value_type, .. } if value_type == "decimal"
+        ));
+        assert_eq!(serde_json::to_value(&value).unwrap(), raw);
+
+        let missing_type = serde_json::from_value::<RemoteValue>(json!({ "value": 1 }));
+        assert!(missing_type.unwrap_err().to_string().contains("type"));
+    }
+}
+
+mod local_value {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Query {
+        selector: &'static str,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        ratio: f64,
+    }
+
+    #[derive(Serialize)]
+    enum Action {
+        Click { x: i32, y: i32 },
+        Scroll(i32),
+        Blur,
+    }
+
+    fn to_json(value: impl Serialize) -> Value {
+        let local_value = LocalValue::from_serialize(&value).unwrap();
+        let json = serde_json::to_value(local_value).unwrap();
+        Schema::remote()
+            .validate("script.LocalValue", &json)
+            .unwrap_or_else(|err| panic!("{}\n{}", err, json));
+        json
+    }
+
+    #[test]
+    fn test_struct() {
+        let query = Query {
+            selector: "div",
+            limit: Some(10),
+            offset: None,
+            ratio: f64::NAN,
+        };
+        assert_eq!(
+            to_json(query),
+            json!({
+                "type": "object",
+                "value": [
+                    ["selector", string("div")],
+                    ["limit", number(10.0)],
+                    ["offset", { "type": "null" }],
+                    ["ratio", number("NaN")],
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(
+            to_json(vec![1.5, f64::INFINITY, -0.0]),
+            json!({
+                "type": "array",
+                "value": [number(1.5), number("Infinity"), number("-0")],
+            })
+        );
+
+        let by_name: BTreeMap<&str, bool> = [("a", true)].into_iter().collect();
+        assert_eq!(
+            to_json(by_name),
+            json!({
+                "type": "object",
+                "value": [["a", { "type": "boolean", "value": true }]],
+            })
+        );
+
+        let by_id: BTreeMap<u8, &str> = [(1, "one")].into_iter().collect();
+        assert_eq!(
+            to_json(by_id),
+            json!({
+                "type": "map",
+                "value": [[number(1.0), string("one")]],
+            })
+        );
+    }
+
+    #[test]
+    fn test_enums() {
+        assert_eq!(to_json(Action::Blur), string("Blur"));
+        assert_eq!(
+            to_json(Action::Scroll(-3)),
+            json!({ "type": "object", "value": [["Scroll", number(-3.0)]] })
+        );
+        assert_eq!(
+            to_json(Action::Click { x: 1, y: 2 }),
+            json!({
+                "type": "object",
+                "value": [[
+                    "Click",
+                    {
+                        "type": "object",
+                        "value": [["x", number(1.0)], ["y", number(2.0)]],
+                    },
+                ]],
+            })
+        );
+    }
+
+    #[test]
+    fn test_large_integers() {
+        assert_eq!(to_json(9007199254740991_i64), number(9007199254740991.0));
+        assert_eq!(to_json(-9007199254740991_i64), number(-9007199254740991.0));
+
+        let err = LocalValue::from_serialize(&9007199254740992_u64).unwrap_err();
+        assert!(err.to_string().contains("MAX_SAFE_INTEGER"), "{}", err);
+        assert!(LocalValue::from_serialize(&vec![1, i64::MIN]).is_err());
+        assert!(LocalValue::try_from(u64::MAX).is_err());
+
+        assert_eq!(
+            to_json(u64::MAX as u128),
+            json!({ "type": "bigint", "value": "18446744073709551615" })
+        );
+        assert_eq!(to_json(1_i128), json!({ "type": "bigint", "value": "1" }));
+        assert_eq!(
+            serde_json::to_value(LocalValue::from(-5_i128)).unwrap(),
+            json!({ "type": "bigint", "value": "-5" })
+        );
+    }
+
+    #[test]
+    fn test_from_primitives() {
+        let json = |value: LocalValue| serde_json::to_value(value).unwrap();
+
+        assert_eq!(json("text".into()), string("text"));
+        assert_eq!(
+            json(true.into()),
+            json!({ "type": "boolean", "value": true })
+        );
+        assert_eq!(json(7_u32.into()), number(7.0));
+        assert_eq!(json(f64::NEG_INFINITY.into()), number("-Infinity"));
+        assert_eq!(json(None::<i32>.into()), json!({ "type": "null" }));
+        assert_eq!(json(Some("text").into()), string("text"));
+    }
+}
+
+mod script_error {
+    use super::*;
+    use webdriverbidi::error::ScriptError;
+    use webdriverbidi::local::script::ExceptionDetails;
+
+    #[test]
+    fn test_display_includes_stack_trace() {
+        let details: ExceptionDetails = serde_json::from_value(json!({
+            "columnNumber": 17,
+            "exception": { "type": "error", "handle": "h1" },
+            "lineNumber": 0,
+            "stackTrace": {
+                "callFrames": [
+                    { "columnNumber": 17, "functionName": "boom", "lineNumber": 0, "url": "" },
+                    { "columnNumber": 0, "functionName": "", "lineNumber": 1, "url": "" },
+                ],
+            },
+            "text": "Error: boom",
+        }))
+        .unwrap();
+        let err = ScriptError::Exception(Box::new(details));
+
+        assert_eq!(err.text(), Some("Error: boom"));
+        assert!(matches!(
+            err.exception(),
+            Some(RemoteValue::ErrorRemoteValue(_))
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Script threw an exception at line 1, column 18: Error: boom\n    at boom (:1:18)\n    at <anonymous> (:2:1)"
+        );
+    }
+}
+
+mod inspector {
+    use super::*;
+    use webdriverbidi::ids::Realm;
+    use webdriverbidi::inspector::{Inspector, NodeKey};
+    use webdriverbidi::js_handle::JsHandle;
+    use webdriverbidi::session::WebDriverBiDiSession;
+    use webdriverbidi::webdriver::capabilities::CapabilitiesRequest;
+
+    // The session is never started: building the tree from a serialization does not
+    // send commands, and dropped handles wait in the disown queue.
+    fn handle(json: Value) -> JsHandle {
+        let session =
+            WebDriverBiDiSession::new("localhost".into(), 0, CapabilitiesRequest::default());
+        JsHandle::new(session, Realm::new("realm"), remote_value(json)).unwrap()
+    }
+
+    fn inspector(json: Value) -> Inspector {
+        Inspector::new(handle(json)).unwrap()
+    }
+
+    #[test]
+    fn test_back_references_are_resolved() {
+        let inspector = inspector(json!({
+            "type": "object",
+            "handle": "h1",
+            "internalId": "1",
+            "value": [
+                ["name", string("root")],
+                ["self", { "type": "object", "internalId": "1" }],
+                ["items", {
+                    "type": "array",
+                    "value": [
+                        { "type": "object", "internalId": "2", "value": [] },
+                        { "type": "object", "internalId": "2" },
+                    ],
+                }],
+                ["more", { "type": "map" }],
+            ],
+        }));
+
+        let root = inspector.root();
+        let children = inspector.node(root).children().unwrap();
+        let self_node = inspector.node(children[1]);
+        assert!(matches!(self_node.key(), Some(NodeKey::Name(name)) if name == "self"));
+        assert_eq!(self_node.reference(), Some(root));
+        assert_eq!(inspector.resolve(children[1]), root);
+
+        let items = inspector.node(children[2]).children().unwrap();
+        assert_eq!(inspector.node(items[1]).reference(), Some(items[0]));
+        assert_eq!(inspector.path(items[1]), vec![2, 1]);
+
+        assert!(inspector.node(children[3]).children().is_none());
+        assert_eq!(
+            inspector.to_string(),
+            "Object\n  name: \"root\"\n  self: [Circular Object]\n  items: Array\n    0: Object\n    1: [Ref Object]\n  more: Map …\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_cycle_is_circular() {
+        // const a = { deep: { deeper: { value: 1 } } }; a.deep.back = a;
+        let inspector = inspector(json!({
+            "type": "object",
+            "handle": "h1",
+            "internalId": "1",
+            "value": [
+                ["deep", {
+                    "type": "object",
+                    "value": [
+                        ["deeper", { "type": "object", "value": [["value", number(1.0)]] }],
+                        ["back", { "type": "object", "internalId": "1" }],
+                    ],
+                }],
+            ],
+        }));
+
+        let root = inspector.root();
+        let deep = inspector.node(root).children().unwrap()[0];
+        let back = inspector.node(deep).children().unwrap()[1];
+        assert!(matches!(inspector.node(back).key(), Some(NodeKey::Name(name)) if name == "back"));
+        assert_eq!(inspector.node(back).reference(), Some(root));
+        assert!(inspector.node(back).children().is_none());
+        assert_eq!(inspector.resolve(back), root);
+        assert_eq!(inspector.path(back), vec![0, 1]);
+        assert_eq!(
+            inspector.to_string(),
+            "Object\n  deep: Object\n    deeper: Object\n      value: 1\n    back: [Circular Object]\n"
+        );
+    }
+
+    #[test]
+    fn test_only_objects_maps_and_nodes() {
+        let array = handle(json!({ "type": "array", "handle": "h1", "value": [] }));
+        assert!(Inspector::new(array).is_err());
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_dates {
+    use super::*;
+    use chrono::{FixedOffset, TimeZone, Utc};
+    use webdriverbidi::local::script::DateRemoteValue;
+
+    #[test]
+    fn test_round_trip() {
+        let RemoteValue::DateRemoteValue(date) =
+            remote_value(json!({ "type": "date", "value": "2025-03-04T05:06:07.089Z" }))
+        else {
+            panic!("not a date");
+        };
+        let parsed = date.to_chrono().unwrap();
+        assert_eq!(
+            parsed,
+            Utc.with_ymd_and_hms(2025, 3, 4, 5, 6, 7).unwrap() + chrono::Duration::milliseconds(89)
+        );
+
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let local_value = LocalValue::from(parsed.with_timezone(&offset));
+        assert_eq!(
+            serde_json::to_value(local_value).unwrap(),
+            json!({ "type": "date", "value": "2025-03-04T05:06:07.089Z" })
+        );
+    }
+
+    #[test]
+    fn test_invalid_date() {
+        let date: DateRemoteValue =
+            serde_json::from_value(json!({ "type": "date", "value": "Invalid Date" })).unwrap();
+        assert!(date.to_chrono().is_err());
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_dates {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_round_trip() {
+        let RemoteValue::DateRemoteValue(date) =
+            remote_value(json!({ "type": "date", "value": "2025-03-04T05:06:07.089Z" }))
+        else {
+            panic!("not a date");
+        };
+        assert_eq!(
+            date.to_time().unwrap(),
+            datetime!(2025-03-04 05:06:07.089 UTC)
+        );
+
+        let local_value = LocalValue::try_from(datetime!(2025-03-04 06:06:07.089123 +1)).unwrap();
+        assert_eq!(
+            serde_json::to_value(local_value).unwrap(),
+            json!({ "type": "date", "value": "2025-03-04T05:06:07.089Z" })
+        );
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+mod bigints {
+    use super::*;
+    use num_bigint::BigInt;
+    use webdriverbidi::local::script::PrimitiveProtocolValue;
+
+    #[test]
+    fn test_round_trip() {
+        let digits = "-123456789012345678901234567890";
+        let RemoteValue::PrimitiveProtocolValue(PrimitiveProtocolValue::BigIntValue(value)) =
+            remote_value(json!({ "type": "bigint", "value": digits }))
+        else {
+            panic!("not a BigInt");
+        };
+        let n = value.to_bigint().unwrap();
+        assert_eq!(n, digits.parse::<BigInt>().unwrap());
+
+        assert_eq!(
+            serde_json::to_value(LocalValue::from(n)).unwrap(),
+            json!({ "type": "bigint", "value": digits })
+        );
+    }
+}